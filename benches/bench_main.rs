@@ -169,5 +169,59 @@ create_bench!(bench_sub, "sub", |l, r| l - r);
 create_bench!(bench_mul, "mul", |l, r| l * r);
 create_bench!(bench_div, "div", |l, r| l / r);
 
-criterion_group!(benches, bench_add, bench_sub, bench_mul, bench_div);
+fn bench_from_str_hex(c: &mut Criterion) {
+    let mut rng: Xoshiro256Plus = Xoshiro256Plus::seed_from_u64(SEED);
+    let dataset: Vec<String> = (0..DATASET_SIZE)
+        .map(|_| format!("0.{:032x}", rng.gen::<u128>()))
+        .collect();
+    let mut group = c.benchmark_group("from_str_hex");
+    group.throughput(Throughput::Elements(DATASET_SIZE.try_into().unwrap()));
+    group.bench_function("U0F128", |b| {
+        b.iter(|| {
+            for s in &dataset {
+                black_box(U0F128::from_str_hex(s).unwrap());
+            }
+        });
+    });
+    group.finish();
+}
+
+fn bench_from_str_dec(c: &mut Criterion) {
+    let mut rng: Xoshiro256Plus = Xoshiro256Plus::seed_from_u64(SEED);
+    // 60 digits is longer than the 54 digits handled exactly for u128
+    let dataset: Vec<String> = (0..DATASET_SIZE)
+        .map(|_| {
+            let digits: String = (0..60)
+                .map(|_| char::from(b'0' + rng.gen_range(0, 10)))
+                .collect();
+            format!("0.{}", digits)
+        })
+        .collect();
+    let mut group = c.benchmark_group("from_str_dec");
+    group.throughput(Throughput::Elements(DATASET_SIZE.try_into().unwrap()));
+    macro_rules! bench_type {
+        ($($Fix:ident),*) => { $(
+            group.bench_function(stringify!($Fix), |b| {
+                b.iter(|| {
+                    for s in &dataset {
+                        // values close to 1 can overflow when rounded
+                        let _ = black_box(s.parse::<$Fix>());
+                    }
+                });
+            });
+        )* };
+    }
+    bench_type! { U0F8, U0F16, U0F32, U0F64, U0F128 }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_add,
+    bench_sub,
+    bench_mul,
+    bench_div,
+    bench_from_str_hex,
+    bench_from_str_dec
+);
 criterion_main!(benches);
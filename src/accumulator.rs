@@ -0,0 +1,303 @@
+// Copyright © 2018–2019 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{traits::Fixed, wide_div::WideDivRem};
+use core::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    marker::PhantomData,
+};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Running statistics over a stream of fixed-point numbers.
+///
+/// The accumulator keeps the count, the minimum, the maximum, and the
+/// exact sum of the values pushed. The sum is kept in a 256-bit integer,
+/// so it cannot overflow for any realistic number of values, and no
+/// allocation is needed.
+///
+/// # Examples
+///
+/// ```rust
+/// use substrate_fixed::{types::I8F8, FixedAccumulator};
+/// let mut acc = FixedAccumulator::new();
+/// for &x in &[1.5, -2.0, 100.0, 120.25] {
+///     acc.push(I8F8::from_num(x));
+/// }
+/// assert_eq!(acc.count(), 4);
+/// assert_eq!(acc.range(), Some((I8F8::from_num(-2), I8F8::from_num(120.25))));
+/// // the sum 219.75 does not fit in I8F8, but the mean does
+/// assert_eq!(acc.mean(), Some(I8F8::from_num(54.9375)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct FixedAccumulator<F> {
+    count: u64,
+    sum: WideSum<F>,
+    range: Option<(F, F)>,
+}
+
+impl<F: Fixed> FixedAccumulator<F> {
+    /// Creates an empty accumulator.
+    #[inline]
+    pub fn new() -> FixedAccumulator<F> {
+        FixedAccumulator {
+            count: 0,
+            sum: WideSum::new(),
+            range: None,
+        }
+    }
+
+    /// Adds a value to the accumulator.
+    #[inline]
+    pub fn push(&mut self, value: F) {
+        self.count += 1;
+        self.sum.add(value);
+        self.range = match self.range {
+            None => Some((value, value)),
+            Some((min, max)) => Some((min.min(value), max.max(value))),
+        };
+    }
+
+    /// Returns the number of values pushed.
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the smallest value pushed, or [`None`] if no values
+    /// have been pushed.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn min(&self) -> Option<F> {
+        self.range.map(|(min, _)| min)
+    }
+
+    /// Returns the largest value pushed, or [`None`] if no values have
+    /// been pushed.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn max(&self) -> Option<F> {
+        self.range.map(|(_, max)| max)
+    }
+
+    /// Returns the smallest and the largest values pushed, or [`None`]
+    /// if no values have been pushed.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn range(&self) -> Option<(F, F)> {
+        self.range
+    }
+
+    /// Returns the sum of the values pushed, or [`None`] if the sum
+    /// does not fit in the fixed-point type.
+    ///
+    /// The sum of no values is zero.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn checked_sum(&self) -> Option<F> {
+        self.sum.finish()
+    }
+
+    /// Returns the mean of the values pushed, or [`None`] if no values
+    /// have been pushed.
+    ///
+    /// The mean is computed from the exact sum, and rounding is to the
+    /// nearest, with ties rounded to even. As the mean lies between the
+    /// minimum and the maximum, it always fits.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn mean(&self) -> Option<F> {
+        if self.count == 0 {
+            return None;
+        }
+        let (neg, hi, lo) = self.sum.neg_abs();
+        let count = u128::from(self.count);
+        let ((_, quot), rem) = count.div_rem_from((hi, lo));
+        // rem < count, so count - rem cannot overflow; and if rem ≠ 0,
+        // quot is less than the largest absolute value, so quot + 1
+        // cannot overflow
+        let diff = count - rem;
+        let round_up = rem > diff || (rem == diff && quot % 2 != 0);
+        F::private_checked_from_neg_abs(neg, quot + u128::from(round_up))
+    }
+}
+
+impl<F: Fixed> Default for FixedAccumulator<F> {
+    #[inline]
+    fn default() -> FixedAccumulator<F> {
+        FixedAccumulator::new()
+    }
+}
+
+impl<F: Fixed> Extend<F> for FixedAccumulator<F> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = F>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+/// An exact sum of a stream of fixed-point numbers.
+///
+/// The running total is kept in a 256-bit integer, so adding values
+/// cannot overflow for any realistic number of values, and nothing is
+/// lost to rounding. The total is only converted back to the
+/// fixed-point type by [`finish`], which reports overflow of the final
+/// sum, so intermediate sums are allowed to go out of range.
+///
+/// # Examples
+///
+/// ```rust
+/// use substrate_fixed::{types::I8F8, WideSum};
+/// let mut sum = WideSum::new();
+/// for &x in &[100.0, 100.0, -120.5] {
+///     sum.add(I8F8::from_num(x));
+/// }
+/// // the partial sum 200 does not fit in I8F8, but the total does
+/// assert_eq!(sum.finish(), Some(I8F8::from_num(79.5)));
+/// sum.add(I8F8::from_num(100));
+/// assert_eq!(sum.finish(), None);
+/// ```
+///
+/// [`finish`]: #method.finish
+#[derive(Clone, Copy, Debug)]
+pub struct WideSum<F> {
+    // the sum of the bits in two’s complement, high part first
+    sum: (i128, u128),
+    phantom: PhantomData<F>,
+}
+
+impl<F: Fixed> WideSum<F> {
+    /// Creates a sum of no values, which is zero.
+    #[inline]
+    pub fn new() -> WideSum<F> {
+        WideSum {
+            sum: (0, 0),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Adds a value to the sum.
+    #[inline]
+    pub fn add(&mut self, value: F) {
+        let helper = value.private_to_float_helper();
+        let (hi, lo) = self.sum;
+        self.sum = if helper.neg {
+            let (lo, borrow) = lo.overflowing_sub(helper.abs);
+            (hi - i128::from(borrow), lo)
+        } else {
+            let (lo, carry) = lo.overflowing_add(helper.abs);
+            (hi + i128::from(carry), lo)
+        };
+    }
+
+    /// Returns the sum, or [`None`] if it does not fit in the
+    /// fixed-point type.
+    ///
+    /// The sum is not consumed, so more values can be added afterwards.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn finish(&self) -> Option<F> {
+        let (neg, hi, lo) = self.neg_abs();
+        if hi != 0 {
+            return None;
+        }
+        F::private_checked_from_neg_abs(neg, lo)
+    }
+
+    // returns the sign and the absolute value of the sum
+    pub(crate) fn neg_abs(&self) -> (bool, u128, u128) {
+        let (hi, lo) = self.sum;
+        if hi < 0 {
+            let (lo, borrow) = 0u128.overflowing_sub(lo);
+            (true, (hi as u128).wrapping_neg() - u128::from(borrow), lo)
+        } else {
+            (false, hi as u128, lo)
+        }
+    }
+}
+
+impl<F: Fixed> Default for WideSum<F> {
+    #[inline]
+    fn default() -> WideSum<F> {
+        WideSum::new()
+    }
+}
+
+impl<F: Fixed> Extend<F> for WideSum<F> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = F>>(&mut self, iter: I) {
+        for value in iter {
+            self.add(value);
+        }
+    }
+}
+
+/// The error returned when adding up a sequence of fixed-point numbers
+/// overflows.
+///
+/// It records the index of the value whose addition overflowed, and
+/// the partial sum of all the values before it.
+///
+/// # Examples
+///
+/// ```rust
+/// use substrate_fixed::types::I8F8;
+/// let values = [100, 20, 10, -50].iter().map(|&x| I8F8::from_num(x));
+/// let err = I8F8::try_fold_add(values).unwrap_err();
+/// assert_eq!(err.index(), 2);
+/// assert_eq!(err.partial_sum(), I8F8::from_num(120));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OverflowAt<F> {
+    index: usize,
+    partial_sum: F,
+}
+
+impl<F: Copy> OverflowAt<F> {
+    #[inline]
+    pub(crate) fn new(index: usize, partial_sum: F) -> OverflowAt<F> {
+        OverflowAt { index, partial_sum }
+    }
+
+    /// Returns the index of the value whose addition overflowed.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the sum of all the values before the one that
+    /// overflowed.
+    #[inline]
+    pub fn partial_sum(&self) -> F {
+        self.partial_sum
+    }
+}
+
+impl<F> Display for OverflowAt<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "overflow at index {}", self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F: Debug> Error for OverflowAt<F> {}
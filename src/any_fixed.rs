@@ -0,0 +1,161 @@
+// Copyright © 2018–2019 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    types::extra::LeEqU128, FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16,
+    FixedU32, FixedU64, FixedU8,
+};
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+/// A fixed-point number of any width and signedness, with `Frac`
+/// fractional bits.
+///
+/// This can be used where the width of a number is only known at run
+/// time, for example with [`from_str_narrowest`], which parses a string
+/// into the narrowest type that can hold it.
+///
+/// Only the variants with at least `Frac` bits can ever be constructed by
+/// this crate.
+///
+/// # Examples
+///
+/// ```rust
+/// use substrate_fixed::{types::extra::U4, AnyFixed, FixedU8};
+/// let any = AnyFixed::<U4>::U8(FixedU8::from_num(2.5));
+/// assert_eq!(any.nbits(), 8);
+/// assert!(!any.is_signed());
+/// ```
+///
+/// [`from_str_narrowest`]: #method.from_str_narrowest
+#[derive(Clone, Copy)]
+pub enum AnyFixed<Frac> {
+    /// An eight-bit signed fixed-point number.
+    I8(FixedI8<Frac>),
+    /// A 16-bit signed fixed-point number.
+    I16(FixedI16<Frac>),
+    /// A 32-bit signed fixed-point number.
+    I32(FixedI32<Frac>),
+    /// A 64-bit signed fixed-point number.
+    I64(FixedI64<Frac>),
+    /// A 128-bit signed fixed-point number.
+    I128(FixedI128<Frac>),
+    /// An eight-bit unsigned fixed-point number.
+    U8(FixedU8<Frac>),
+    /// A 16-bit unsigned fixed-point number.
+    U16(FixedU16<Frac>),
+    /// A 32-bit unsigned fixed-point number.
+    U32(FixedU32<Frac>),
+    /// A 64-bit unsigned fixed-point number.
+    U64(FixedU64<Frac>),
+    /// A 128-bit unsigned fixed-point number.
+    U128(FixedU128<Frac>),
+}
+
+impl<Frac> AnyFixed<Frac> {
+    /// Returns the number of bits of the underlying type.
+    #[inline]
+    pub fn nbits(&self) -> u32 {
+        match self {
+            AnyFixed::I8(_) | AnyFixed::U8(_) => 8,
+            AnyFixed::I16(_) | AnyFixed::U16(_) => 16,
+            AnyFixed::I32(_) | AnyFixed::U32(_) => 32,
+            AnyFixed::I64(_) | AnyFixed::U64(_) => 64,
+            AnyFixed::I128(_) | AnyFixed::U128(_) => 128,
+        }
+    }
+
+    /// Returns [`true`] if the underlying type is signed.
+    ///
+    /// [`true`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    #[inline]
+    pub fn is_signed(&self) -> bool {
+        match self {
+            AnyFixed::I8(_)
+            | AnyFixed::I16(_)
+            | AnyFixed::I32(_)
+            | AnyFixed::I64(_)
+            | AnyFixed::I128(_) => true,
+            AnyFixed::U8(_)
+            | AnyFixed::U16(_)
+            | AnyFixed::U32(_)
+            | AnyFixed::U64(_)
+            | AnyFixed::U128(_) => false,
+        }
+    }
+}
+
+impl<Frac: LeEqU128> AnyFixed<Frac> {
+    /// Returns the Q format of the underlying type as a [tuple] of the
+    /// number of integer bits, the number of fractional bits, and
+    /// whether the type is signed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::extra::U4, AnyFixed, FixedI16};
+    /// let any = AnyFixed::<U4>::I16(FixedI16::from_num(2.5));
+    /// assert_eq!(any.q_format(), (12, 4, true));
+    /// ```
+    ///
+    /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+    #[inline]
+    pub fn q_format(&self) -> (u32, u32, bool) {
+        // variants narrower than Frac cannot be constructed by this
+        // crate, but must not underflow if built by hand
+        let int_nbits = self.nbits().saturating_sub(Frac::U32);
+        (int_nbits, Frac::U32, self.is_signed())
+    }
+}
+
+// The fixed-point types only implement PartialEq and Debug for valid
+// Frac, so these compare and format the underlying bits instead.
+
+impl<Frac> PartialEq for AnyFixed<Frac> {
+    #[inline]
+    fn eq(&self, other: &AnyFixed<Frac>) -> bool {
+        match (self, other) {
+            (AnyFixed::I8(a), AnyFixed::I8(b)) => a.to_bits() == b.to_bits(),
+            (AnyFixed::I16(a), AnyFixed::I16(b)) => a.to_bits() == b.to_bits(),
+            (AnyFixed::I32(a), AnyFixed::I32(b)) => a.to_bits() == b.to_bits(),
+            (AnyFixed::I64(a), AnyFixed::I64(b)) => a.to_bits() == b.to_bits(),
+            (AnyFixed::I128(a), AnyFixed::I128(b)) => a.to_bits() == b.to_bits(),
+            (AnyFixed::U8(a), AnyFixed::U8(b)) => a.to_bits() == b.to_bits(),
+            (AnyFixed::U16(a), AnyFixed::U16(b)) => a.to_bits() == b.to_bits(),
+            (AnyFixed::U32(a), AnyFixed::U32(b)) => a.to_bits() == b.to_bits(),
+            (AnyFixed::U64(a), AnyFixed::U64(b)) => a.to_bits() == b.to_bits(),
+            (AnyFixed::U128(a), AnyFixed::U128(b)) => a.to_bits() == b.to_bits(),
+            _ => false,
+        }
+    }
+}
+
+impl<Frac> Eq for AnyFixed<Frac> {}
+
+impl<Frac> Debug for AnyFixed<Frac> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            AnyFixed::I8(a) => f.debug_struct("I8").field("bits", &a.to_bits()).finish(),
+            AnyFixed::I16(a) => f.debug_struct("I16").field("bits", &a.to_bits()).finish(),
+            AnyFixed::I32(a) => f.debug_struct("I32").field("bits", &a.to_bits()).finish(),
+            AnyFixed::I64(a) => f.debug_struct("I64").field("bits", &a.to_bits()).finish(),
+            AnyFixed::I128(a) => f.debug_struct("I128").field("bits", &a.to_bits()).finish(),
+            AnyFixed::U8(a) => f.debug_struct("U8").field("bits", &a.to_bits()).finish(),
+            AnyFixed::U16(a) => f.debug_struct("U16").field("bits", &a.to_bits()).finish(),
+            AnyFixed::U32(a) => f.debug_struct("U32").field("bits", &a.to_bits()).finish(),
+            AnyFixed::U64(a) => f.debug_struct("U64").field("bits", &a.to_bits()).finish(),
+            AnyFixed::U128(a) => f.debug_struct("U128").field("bits", &a.to_bits()).finish(),
+        }
+    }
+}
@@ -648,6 +648,33 @@ mul_div_widen! { i32, i64, Signed }
 mul_div_widen! { i64, i128, Signed }
 mul_div_fallback! { i128, u128, Signed }
 
+/// These are doc tests that should not appear in the docs, but are
+/// useful as doc tests can check to ensure compilation failure.
+///
+/// The first snippet succeeds, and acts as a control.
+///
+/// ```rust
+/// use substrate_fixed::types::*;
+/// let _ = -I8F8::default();
+/// let _ = U8F8::default().wrapping_neg();
+/// ```
+///
+/// Unsigned fixed-point numbers do not implement [`Neg`], just like
+/// unsigned integers, so the rest of the tests should all fail
+/// compilation.
+///
+/// ```compile_fail
+/// use substrate_fixed::types::*;
+/// let _ = -U8F8::default();
+/// ```
+/// ```compile_fail
+/// use substrate_fixed::types::*;
+/// let _ = -U0F128::default();
+/// ```
+///
+/// [`Neg`]: https://doc.rust-lang.org/nightly/core/ops/trait.Neg.html
+fn _compile_fail_tests() {}
+
 #[cfg(test)]
 #[allow(clippy::cognitive_complexity)]
 mod tests {
@@ -888,4 +915,359 @@ mod tests {
         assert_eq!(i0(0.25) % 1, i0(0.25));
         assert_eq!(i0(0.25).rem_euclid_int(1), i0(0.25));
     }
+
+    macro_rules! check_neg_min {
+        ($($Fixed:ident),*) => { $(
+            let min = $Fixed::min_value();
+            let max = $Fixed::max_value();
+            assert_eq!(min.checked_neg(), None);
+            assert_eq!(min.saturating_neg(), max);
+            assert_eq!(min.wrapping_neg(), min);
+            assert_eq!(min.overflowing_neg(), (min, true));
+            assert_eq!(-max, $Fixed::from_bits(min.to_bits() + 1));
+            assert_eq!(max.saturating_neg(), -max);
+        )* };
+    }
+
+    #[test]
+    fn neg_min() {
+        use crate::types::{I0F16, I0F32, I0F8, I128F0, I16F0, I1F127, I32F0, I4F60, I64F0, I8F0};
+        check_neg_min! { I8F0, I0F8, I16F0, I0F16, I32F0, I0F32, I64F0, I4F60, I128F0, I1F127 }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "overflow")]
+    fn neg_min_panics() {
+        use crate::types::I8F0;
+        let _ = -I8F0::min_value();
+    }
+
+    #[test]
+    fn midpoint() {
+        use crate::types::{I4F4, I64F64, U4F4, U64F64};
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let floor = ((i16::from(a) + i16::from(b)) >> 1) as i8;
+                let (fa, fb) = (I4F4::from_bits(a), I4F4::from_bits(b));
+                assert_eq!(fa.midpoint(fb), I4F4::from_bits(floor));
+                let (ua, ub) = (a as u8, b as u8);
+                let floor = ((u16::from(ua) + u16::from(ub)) >> 1) as u8;
+                let (fa, fb) = (U4F4::from_bits(ua), U4F4::from_bits(ub));
+                assert_eq!(fa.midpoint(fb), U4F4::from_bits(floor));
+            }
+        }
+        let (min, max) = (I64F64::min_value(), I64F64::max_value());
+        assert_eq!(min.midpoint(min), min);
+        assert_eq!(max.midpoint(max), max);
+        assert_eq!(min.midpoint(max), -I64F64::from_bits(1));
+        let max = U64F64::max_value();
+        assert_eq!(
+            max.midpoint(max - U64F64::from_bits(2)),
+            max - U64F64::from_bits(1)
+        );
+    }
+
+    #[test]
+    fn shifts_scale_by_powers_of_two() {
+        use crate::types::{I16F16, U16F16};
+        let x = I16F16::from_num(-5.25);
+        for n in 0..12 {
+            let scale = I16F16::from_num(1 << n);
+            assert_eq!(x << n, x * scale);
+            assert_eq!(x.checked_shl(n), Some(x * scale));
+            assert_eq!(x >> n, x / scale);
+            assert_eq!(x.checked_shr(n), Some(x / scale));
+        }
+        // right shift rounds towards -inf, unlike division
+        let tiny = I16F16::from_bits(-3);
+        assert_eq!(tiny >> 1, I16F16::from_bits(-2));
+        assert_eq!(tiny.checked_shr(1), Some(I16F16::from_bits(-2)));
+        assert_eq!(tiny / 2, I16F16::from_bits(-1));
+        let y = U16F16::from_num(3.5);
+        assert_eq!(y >> 1u32, U16F16::from_num(1.75));
+        assert_eq!(y.checked_shl(32), None);
+        assert_eq!(y.checked_shr(32), None);
+    }
+
+    #[test]
+    fn find_root() {
+        use crate::types::I16F16;
+        let (lo, hi) = (I16F16::from_num(-16000), I16F16::from_num(16000));
+        // decreasing function, bracket given reversed
+        let target = I16F16::from_num(-1234.5625);
+        let root = I16F16::find_root(|x| target - x, hi, lo, 64);
+        assert_eq!(root, Some(target));
+        // cube root of 10 is 2.1544, accurate to within one ulp
+        let ten = I16F16::from_num(10);
+        let root = I16F16::find_root(|x| x * x * x - ten, I16F16::from_num(0), ten, 64).unwrap();
+        assert!((root - I16F16::from_num(2.154_434_69)).abs() <= I16F16::from_bits(1));
+        // too few iterations still gives a point inside the bracket
+        let root = I16F16::find_root(|x| x * x * x - ten, I16F16::from_num(0), ten, 1);
+        assert_eq!(root, Some(I16F16::from_num(2.5)));
+        assert_eq!(I16F16::find_root(|x| x, ten, ten, 64), None);
+    }
+
+    #[test]
+    fn square() {
+        use crate::types::{I0F128, I4F4, I8F8, U0F128, U4F4, U8F8};
+        use core::convert::TryFrom;
+        // squares of 8 fractional bits have 16, so round away 8 of them
+        let round = |square: u64| {
+            let (quot, rem) = (square >> 8, square & 0xFF);
+            quot + u64::from(rem > 0x80 || (rem == 0x80 && quot % 2 == 1))
+        };
+        for bits in i16::MIN..=i16::MAX {
+            let x = I8F8::from_bits(bits);
+            let abs = u64::from(bits.unsigned_abs());
+            let square = round(abs * abs);
+            let expected = i16::try_from(square).ok().map(I8F8::from_bits);
+            assert_eq!(x.checked_square(), expected);
+            let expected = u16::try_from(square).ok().map(U8F8::from_bits);
+            assert_eq!(x.checked_square_unsigned(), expected);
+            let sat = x.saturating_square();
+            assert_eq!(sat, x.checked_square().unwrap_or(I8F8::max_value()));
+            assert!(sat >= 0);
+            let u = U8F8::from_bits(bits as u16);
+            let square = round(u64::from(bits as u16) * u64::from(bits as u16));
+            let expected = u16::try_from(square).ok().map(U8F8::from_bits);
+            assert_eq!(u.checked_square(), expected);
+            assert_eq!(u.saturating_square(), expected.unwrap_or(U8F8::max_value()));
+        }
+        // 0.3125² = 0.09765625 is 1.5625/16, rounded up to 2/16
+        assert_eq!(
+            U4F4::from_num(0.3125).checked_square(),
+            Some(U4F4::from_num(0.125))
+        );
+        // 0.375² = 0.140625 is 2.25/16, rounded down to 2/16
+        assert_eq!(
+            I4F4::from_num(-0.375).checked_square(),
+            Some(I4F4::from_num(0.125))
+        );
+        assert_eq!(I4F4::min_value().checked_square(), None);
+        assert_eq!(I4F4::min_value().checked_square_unsigned(), None);
+        assert_eq!(I4F4::from_num(-3.5).checked_square(), None);
+        assert_eq!(
+            I4F4::from_num(-3.5).checked_square_unsigned(),
+            Some(U4F4::from_num(12.25))
+        );
+        assert_eq!(
+            I0F128::min_value().checked_square_unsigned(),
+            Some(U0F128::from_num(0.25))
+        );
+        assert_eq!(U4F4::max_value().saturating_square(), U4F4::max_value());
+    }
+
+    #[test]
+    fn mul_int_boundaries() {
+        use crate::types::{I128F0, I4F4, I8F8, U4F4, U8F8};
+        for bits in i16::MIN..=i16::MAX {
+            let x = I8F8::from_bits(bits);
+            let u = U8F8::from_bits(bits as u16);
+            for n in [0i16, 1, -1, 2, -2, 3, 255, 256, -256, i16::MIN, i16::MAX]
+                .iter()
+                .cloned()
+            {
+                let exact = i32::from(bits) * i32::from(n);
+                let wrapped = I8F8::from_bits(exact as i16);
+                let sat = I8F8::from_bits(exact.max(i16::MIN.into()).min(i16::MAX.into()) as i16);
+                assert_eq!(x.wrapping_mul_int(n), wrapped);
+                assert_eq!(x.saturating_mul_int(n), sat);
+
+                let n = n as u16;
+                let exact = u32::from(bits as u16) * u32::from(n);
+                assert_eq!(u.wrapping_mul_int(n), U8F8::from_bits(exact as u16));
+                let sat = U8F8::from_bits(exact.min(u16::MAX.into()) as u16);
+                assert_eq!(u.saturating_mul_int(n), sat);
+            }
+        }
+
+        // 10 × 1.5 = 15 fits, 11 × 1.5 = 16.5 just overflows
+        let x = U4F4::from_num(1.5);
+        assert_eq!(x.saturating_mul_int(10), U4F4::from_num(15));
+        assert_eq!(x.saturating_mul_int(11), U4F4::max_value());
+        assert_eq!(x.wrapping_mul_int(11), U4F4::from_num(0.5));
+        let x = I4F4::from_num(1.5);
+        assert_eq!(x.saturating_mul_int(5), I4F4::from_num(7.5));
+        assert_eq!(x.saturating_mul_int(6), I4F4::max_value());
+        assert_eq!(x.saturating_mul_int(-5), I4F4::from_num(-7.5));
+        assert_eq!(x.saturating_mul_int(-6), I4F4::min_value());
+        assert_eq!(x.wrapping_mul_int(6), I4F4::from_num(-7));
+        // the fractional part is kept while wrapping
+        assert_eq!(
+            I4F4::from_num(0.75).wrapping_mul_int(11),
+            I4F4::from_num(-7.75)
+        );
+
+        let (min, max) = (I128F0::min_value(), I128F0::max_value());
+        assert_eq!(min.saturating_mul_int(-1), max);
+        assert_eq!(min.wrapping_mul_int(-1), min);
+        assert_eq!(max.saturating_mul_int(-1), I128F0::from_bits(-i128::MAX));
+        assert_eq!(max.saturating_mul_int(2), max);
+        assert_eq!(max.wrapping_mul_int(2), I128F0::from_num(-2));
+    }
+
+    #[test]
+    fn mul_i128() {
+        use crate::types::{I128F0, I8F8, U128F0, U8F8};
+        let factors = [
+            0,
+            1,
+            -1,
+            2,
+            -2,
+            3,
+            127,
+            -128,
+            255,
+            256,
+            -257,
+            1 << 16,
+            -1 << 100,
+        ];
+        for bits in i16::MIN..=i16::MAX {
+            let x = I8F8::from_bits(bits);
+            let u = U8F8::from_bits(bits as u16);
+            for &n in &factors {
+                let exact = i128::from(bits) * n;
+                let fits = i128::from(i16::MIN) <= exact && exact <= i128::from(i16::MAX);
+                let wrapped = I8F8::from_bits(exact as i16);
+                assert_eq!(x.overflowing_mul_i128(n), (wrapped, !fits));
+                assert_eq!(
+                    x.checked_mul_i128(n),
+                    if fits { Some(wrapped) } else { None }
+                );
+                assert_eq!(x.wrapping_mul_i128(n), wrapped);
+                let sat = if fits {
+                    wrapped
+                } else if exact < 0 {
+                    I8F8::min_value()
+                } else {
+                    I8F8::max_value()
+                };
+                assert_eq!(x.saturating_mul_i128(n), sat);
+
+                let exact = i128::from(bits as u16) * n;
+                let fits = 0 <= exact && exact <= i128::from(u16::MAX);
+                let wrapped = U8F8::from_bits(exact as u16);
+                assert_eq!(u.overflowing_mul_i128(n), (wrapped, !fits));
+                assert_eq!(
+                    u.checked_mul_i128(n),
+                    if fits { Some(wrapped) } else { None }
+                );
+                let sat = if fits {
+                    wrapped
+                } else if exact < 0 {
+                    U8F8::min_value()
+                } else {
+                    U8F8::max_value()
+                };
+                assert_eq!(u.saturating_mul_i128(n), sat);
+            }
+        }
+
+        let min = I128F0::min_value();
+        assert_eq!(min.checked_mul_i128(1), Some(min));
+        assert_eq!(min.checked_mul_i128(-1), None);
+        assert_eq!(min.saturating_mul_i128(-1), I128F0::max_value());
+        assert_eq!(I128F0::from_num(-1).checked_mul_i128(i128::MIN), None);
+        assert_eq!(I128F0::from_num(1).checked_mul_i128(i128::MIN), Some(min));
+        let max = U128F0::max_value();
+        assert_eq!(max.checked_mul_i128(1), Some(max));
+        assert_eq!(max.checked_mul_i128(-1), None);
+        assert_eq!(max.saturating_mul_i128(-1), U128F0::from_num(0));
+        assert_eq!(max.overflowing_mul_i128(-1), (U128F0::from_num(1), true));
+        assert_eq!(
+            U128F0::from_num(0).checked_mul_i128(i128::MIN),
+            Some(U128F0::from_num(0))
+        );
+        let i128_max = U128F0::from_bits(!0 >> 1);
+        assert_eq!(
+            U128F0::from_num(1).checked_mul_i128(i128::MAX),
+            Some(i128_max)
+        );
+        assert_eq!(
+            U128F0::from_num(2).checked_mul_i128(i128::MAX),
+            Some(U128F0::from_bits(!1))
+        );
+        assert_eq!(U128F0::from_num(3).checked_mul_i128(i128::MAX), None);
+    }
+
+    #[test]
+    fn div_i128() {
+        use crate::types::{I0F128, I128F0, I8F8, U128F0, U8F8};
+
+        // rounds num / den to the nearest, ties to even, for den > 0
+        fn nearest(num: i128, den: i128) -> i128 {
+            let (quot, rem) = (num.div_euclid(den), num.rem_euclid(den));
+            let diff = den - rem;
+            if rem > diff || (rem == diff && quot.rem_euclid(2) == 1) {
+                quot + 1
+            } else {
+                quot
+            }
+        }
+
+        let divisors = [
+            1,
+            -1,
+            2,
+            -2,
+            3,
+            -3,
+            4,
+            7,
+            -10,
+            255,
+            256,
+            257,
+            -65536,
+            i128::MAX,
+        ];
+        for bits in i16::MIN..=i16::MAX {
+            let x = I8F8::from_bits(bits);
+            let u = U8F8::from_bits(bits as u16);
+            assert_eq!(x.checked_div_i128(0), None);
+            assert_eq!(u.checked_div_i128(0), None);
+            for &n in &divisors {
+                let signum = if n < 0 { -1 } else { 1 };
+                let q = nearest(i128::from(bits) * signum, n.abs());
+                let expected = if i128::from(i16::MIN) <= q && q <= i128::from(i16::MAX) {
+                    Some(I8F8::from_bits(q as i16))
+                } else {
+                    None
+                };
+                assert_eq!(x.checked_div_i128(n), expected, "{} / {}", x, n);
+                let q = nearest(i128::from(bits as u16) * signum, n.abs());
+                let expected = if 0 <= q && q <= i128::from(u16::MAX) {
+                    Some(U8F8::from_bits(q as u16))
+                } else {
+                    None
+                };
+                assert_eq!(u.checked_div_i128(n), expected, "{} / {}", u, n);
+            }
+        }
+
+        let min = I128F0::min_value();
+        assert_eq!(min.checked_div_i128(1), Some(min));
+        assert_eq!(min.checked_div_i128(-1), None);
+        assert_eq!(min.checked_div_i128(-2), Some(I128F0::from_bits(1 << 126)));
+        assert_eq!(min.checked_div_i128(i128::MIN), Some(I128F0::from_num(1)));
+        assert_eq!(
+            I128F0::max_value().checked_div_i128(i128::MIN),
+            Some(I128F0::from_num(-1))
+        );
+        // −2^127 / 3 = −56_713_727_820_156_410_577_229_101_238_628_035_242.67
+        let third = I0F128::from_bits(-56_713_727_820_156_410_577_229_101_238_628_035_243);
+        assert_eq!(I0F128::min_value().checked_div_i128(3), Some(third));
+        let max = U128F0::max_value();
+        assert_eq!(max.checked_div_i128(1), Some(max));
+        // 2^128 − 1 = 2 × (2^127 − 1) + 1, which rounds up to the even 2^127
+        assert_eq!(max.checked_div_i128(2), Some(U128F0::from_bits(1 << 127)));
+        assert_eq!(max.checked_div_i128(-1), None);
+        assert_eq!(
+            U128F0::from_num(1).checked_div_i128(-3),
+            Some(U128F0::from_num(0))
+        );
+    }
 }
@@ -337,7 +337,7 @@ macro_rules! fixed_cmp_all {
         impl<Frac: $LeEqU> Ord for $Fix<Frac> {
             #[inline]
             fn cmp(&self, rhs: &$Fix<Frac>) -> Ordering {
-                self.to_bits().cmp(&rhs.to_bits())
+                self.total_cmp(rhs)
             }
         }
 
@@ -509,4 +509,29 @@ mod tests {
         assert_eq!(a, 1i32 << 12);
         assert_eq!(b, 0);
     }
+
+    #[test]
+    fn total_cmp() {
+        use crate::traits::Fixed;
+        use core::cmp::Ordering;
+
+        fn sorted<F: Fixed>(mut vals: [F; 4]) -> [F; 4] {
+            vals.sort_unstable_by(F::total_cmp);
+            vals
+        }
+
+        let i = types::I8F8::from_num;
+        let signed = [i(-0.5), i(3.25), i(-8.0), i(0.0)];
+        let expected = [i(-8.0), i(-0.5), i(0.0), i(3.25)];
+        assert_eq!(sorted(signed), expected);
+        let u = types::U8F8::from_num;
+        let unsigned = [u(0.5), u(3.25), u(255.0), u(0.0)];
+        let expected = [u(0.0), u(0.5), u(3.25), u(255.0)];
+        assert_eq!(sorted(unsigned), expected);
+
+        let (min, max) = (types::I8F8::min_value(), types::I8F8::max_value());
+        assert_eq!(min.total_cmp(&max), Ordering::Less);
+        assert_eq!(Fixed::total_cmp(&max, &min), Ordering::Greater);
+        assert_eq!(min.total_cmp(&min), min.cmp(&min));
+    }
 }
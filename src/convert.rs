@@ -21,11 +21,16 @@ use crate::{
         U15, U16, U31, U32, U63, U64, U7, U8,
     },
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8,
+    FixedU8, ParseFixedError,
+};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::Sub,
 };
-use core::ops::Sub;
 #[cfg(feature = "f16")]
 use half::{bf16, f16};
+#[cfg(feature = "std")]
+use std::error::Error;
 
 macro_rules! convert {
     (
@@ -858,6 +863,8 @@ lossy! { f64 }
 /// let _ = I8F8::from(I4F4::default());
 /// let _ = I8F8::from(U7F1::default());
 /// let _ = U8F8::from(U4F4::default());
+/// let _ = I4F12::from(I4F4::default());
+/// let _ = I28F4::from(I4F4::default());
 /// let _ = I8F8::lossy_from(I8F56::default());
 /// let _ = I8F8::lossy_from(U7F57::default());
 /// let _ = U8F8::lossy_from(U8F56::default());
@@ -896,6 +903,19 @@ lossy! { f64 }
 /// ```
 ///
 /// ```compile_fail
+/// use substrate_fixed::types::*;
+/// let _ = I3F13::from(I4F4::default());
+/// ```
+/// ```compile_fail
+/// use substrate_fixed::types::*;
+/// let _ = I28F4::from(I4F12::default());
+/// ```
+/// ```compile_fail
+/// use substrate_fixed::types::*;
+/// let _ = U4F28::from(U5F3::default());
+/// ```
+///
+/// ```compile_fail
 /// use substrate_fixed::{traits::LossyFrom, types::*};
 /// let _ = I8F8::lossy_from(I9F55::default());
 /// ```
@@ -936,6 +956,58 @@ lossy! { f64 }
 /// ```
 fn _compile_fail_tests() {}
 
+/// The error returned by a chain of conversions through
+/// [`FixedConvert`].
+///
+/// This unifies the errors of the different stages of a pipeline, so
+/// that parsing errors and conversions that do not fit can be handled
+/// together.
+///
+/// # Examples
+///
+/// ```rust
+/// use substrate_fixed::{traits::FixedConvert, types::*, ConversionError};
+/// let err = "300".parse::<I16F16>().and_then_to::<U8F8>().unwrap_err();
+/// assert_eq!(err, ConversionError::Overflow);
+/// let err = "x".parse::<I16F16>().and_then_to::<U8F8>().unwrap_err();
+/// assert!(matches!(err, ConversionError::Parse(_)));
+/// ```
+///
+/// [`FixedConvert`]: traits/trait.FixedConvert.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConversionError {
+    /// A string could not be parsed.
+    Parse(ParseFixedError),
+    /// A value does not fit in the destination type.
+    Overflow,
+}
+
+impl From<ParseFixedError> for ConversionError {
+    #[inline]
+    fn from(err: ParseFixedError) -> ConversionError {
+        ConversionError::Parse(err)
+    }
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ConversionError::Parse(err) => Display::fmt(err, f),
+            ConversionError::Overflow => f.write_str("overflow"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ConversionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConversionError::Parse(err) => Some(err),
+            ConversionError::Overflow => None,
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::float_cmp)]
 mod tests {
@@ -1193,6 +1265,231 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_num_round_modes() {
+        use crate::RoundingMode::{Ceil, Floor, Nearest, Zero};
+        let delta = I8F8::from_bits(1);
+        for i in -127_000..127_000 {
+            let x = f64::from(i) / 1000.0 + 0.000_3;
+            let floor = I8F8::from_num_round(x, Floor);
+            let ceil = I8F8::from_num_round(x, Ceil);
+            assert!(floor <= x && x < floor + delta);
+            assert!(ceil >= x && ceil - delta < x);
+            let zero = I8F8::from_num_round(x, Zero);
+            assert_eq!(zero, if x < 0.0 { ceil } else { floor });
+            assert_eq!(I8F8::from_num_round(x, Nearest), I8F8::from_num(x));
+        }
+
+        // exact values and ties
+        for &mode in &[Nearest, Floor, Ceil, Zero] {
+            assert_eq!(I8F8::from_num_round(-1.5, mode), -1.5);
+            assert_eq!(U8F8::from_num_round(0.0, mode), 0);
+            assert_eq!(I8F8::from_num_round(-0.0, mode), 0);
+        }
+        let tie = 3.0 / 512.0;
+        assert_eq!(I8F8::from_num_round(tie, Nearest), I8F8::from_bits(2));
+        assert_eq!(I8F8::from_num_round(tie, Floor), I8F8::from_bits(1));
+        assert_eq!(I8F8::from_num_round(-tie, Ceil), I8F8::from_bits(-1));
+        assert_eq!(I8F8::from_num_round(-tie, Nearest), I8F8::from_bits(-2));
+
+        // far below the least significant bit
+        let tiny = (-200f64).exp2();
+        assert_eq!(U0F128::from_num_round(tiny, Ceil), U0F128::from_bits(1));
+        assert_eq!(U0F128::from_num_round(tiny, Floor), 0);
+        assert_eq!(I8F8::from_num_round(-tiny, Floor), -delta);
+        assert_eq!(I8F8::from_num_round(-tiny, Ceil), 0);
+        assert_eq!(U8F8::from_num_round(-tiny, Ceil), 0);
+        assert_eq!(U8F8::from_num_round(-tiny, Zero), 0);
+
+        // just below the bounds, where rounding to nearest overflows
+        let above_max = 127.999;
+        assert_eq!(I8F8::checked_from_num(above_max), None);
+        assert_eq!(I8F8::from_num_round(above_max, Floor), I8F8::max_value());
+        assert_eq!(I8F8::from_num_round(above_max, Zero), I8F8::max_value());
+        let below_min = -128.003;
+        assert_eq!(I8F8::checked_from_num(below_min), None);
+        assert_eq!(I8F8::from_num_round(below_min, Ceil), I8F8::min_value());
+        assert_eq!(I8F8::from_num_round(below_min, Zero), I8F8::min_value());
+        let max = U128F0::max_value();
+        assert_eq!(U128F0::from_num_round(0.5, Ceil), 1);
+        assert_eq!(U128F0::from_num_round(1e38, Floor), 1e38);
+        assert!(U128F0::from_num_round(3.4e38, Floor) < max);
+    }
+
+    #[test]
+    fn from_num_array() {
+        let floats = [0.0, -1.5, 0.3, 127.99, -128.0];
+        let fixed = I8F8::from_num_array(floats);
+        for (&float, &fix) in floats.iter().zip(fixed.iter()) {
+            assert_eq!(fix, I8F8::from_num(float));
+        }
+        assert_eq!(I8F8::try_from_num_array(floats), Ok(fixed));
+        assert_eq!(U8F8::from_num_array([1u8, 2, 255]), [1, 2, 255]);
+        let empty: [I8F8; 0] = I8F8::from_num_array::<f64, 0>([]);
+        assert!(empty.is_empty());
+
+        assert_eq!(U8F8::try_from_num_array(floats), Err(1));
+        assert_eq!(I8F8::try_from_num_array([1.0, 2.0, 128.0, 256.0]), Err(2));
+        assert_eq!(I8F8::try_from_num_array([f32::NAN]), Err(0));
+        assert_eq!(I8F8::try_from_num_array([f64::INFINITY, 1.0]), Err(0));
+        assert_eq!(
+            I8F8::try_from_num_array([I16F16::from_num(-3), I16F16::max_value()]),
+            Err(1)
+        );
+        assert_eq!(U0F128::try_from_num_array([0u8, 1]), Err(1));
+    }
+
+    #[test]
+    fn from_num_exact() {
+        for bits in i16::MIN..=i16::MAX {
+            let fix = I8F8::from_bits(bits);
+            let exact = fix.to_num::<f64>();
+            assert_eq!(I8F8::from_num_exact(exact), Some(fix));
+            let half_step = exact + 1.0 / 512.0;
+            assert_eq!(I8F8::from_num_exact(half_step), None);
+        }
+        assert_eq!(I8F8::from_num_exact(128.0), None);
+        assert_eq!(I8F8::from_num_exact(-128.0), Some(I8F8::min_value()));
+        assert_eq!(U8F8::from_num_exact(-1.0), None);
+        assert_eq!(U8F8::from_num_exact(-0.0), Some(U8F8::from_num(0)));
+        assert_eq!(I8F8::from_num_exact(f64::INFINITY), None);
+        assert_eq!(I8F8::from_num_exact(f64::NEG_INFINITY), None);
+        assert_eq!(I8F8::from_num_exact(f64::NAN), None);
+        assert_eq!(I8F8::from_num_exact(0.1), None);
+
+        // the smallest positive values
+        let tiny = (-128f64).exp2();
+        assert_eq!(U0F128::from_num_exact(tiny), Some(U0F128::from_bits(1)));
+        assert_eq!(U0F128::from_num_exact(tiny / 2.0), None);
+        let subnormal = f64::from_bits(1);
+        assert_eq!(U0F128::from_num_exact(subnormal), None);
+        assert_eq!(I8F8::from_num_exact(-f64::MIN_POSITIVE), None);
+
+        // integers with more bits than an f64 mantissa
+        let large = 2f64.powi(100) + 2f64.powi(48);
+        assert_eq!(
+            U128F0::from_num_exact(large),
+            Some(U128F0::from_bits((1 << 100) + (1 << 48)))
+        );
+        assert_eq!(U128F0::from_num_exact(2f64.powi(128)), None);
+        assert_eq!(
+            I128F0::from_num_exact(-(2f64.powi(127))),
+            Some(I128F0::min_value())
+        );
+        assert_eq!(I128F0::from_num_exact(2f64.powi(127)), None);
+    }
+
+    #[test]
+    fn bracket_f64() {
+        let delta = I8F8::from_bits(1);
+        for i in -127_000..127_000 {
+            let x = f64::from(i) / 1000.0 + 0.000_3;
+            let (lo, hi) = I8F8::bracket_f64(x);
+            assert!(lo <= x && x <= hi);
+            assert!(hi - lo == delta || (lo == hi && lo == x));
+        }
+        for bits in i16::MIN..=i16::MAX {
+            let fix = I8F8::from_bits(bits);
+            assert_eq!(I8F8::bracket_f64(fix.to_num()), (fix, fix));
+        }
+        let (min, max) = (I8F8::min_value(), I8F8::max_value());
+        assert_eq!(I8F8::bracket_f64(127.999), (max, max));
+        assert_eq!(I8F8::bracket_f64(-128.001), (min, min));
+        assert_eq!(I8F8::bracket_f64(f64::INFINITY), (max, max));
+        assert_eq!(
+            U8F8::bracket_f64(-1e-300),
+            (U8F8::from_num(0), U8F8::from_num(0))
+        );
+        assert_eq!(
+            U8F8::bracket_f64(-1.0),
+            (U8F8::from_num(0), U8F8::from_num(0))
+        );
+        let tiny = (-200f64).exp2();
+        assert_eq!(
+            U0F128::bracket_f64(tiny),
+            (U0F128::from_num(0), U0F128::from_bits(1))
+        );
+        assert_eq!(I8F8::bracket_f64(-tiny), (-delta, I8F8::from_num(0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN")]
+    fn bracket_f64_nan() {
+        let _ = I8F8::bracket_f64(f64::NAN);
+    }
+
+    #[test]
+    fn saturating_checked_from_int() {
+        macro_rules! check {
+            ($($Int:ident),*) => { $(
+                assert_eq!(U8F8::saturating_from_num(255 as $Int), 255);
+                assert_eq!(U8F8::checked_from_num(255 as $Int), Some(U8F8::from_num(255)));
+                assert_eq!(U8F8::saturating_from_num(100 as $Int * 10), U8F8::max_value());
+                assert_eq!(U8F8::checked_from_num(100 as $Int * 10), None);
+                assert_eq!(I8F8::saturating_from_num(127 as $Int), 127);
+                assert_eq!(I8F8::checked_from_num(128 as $Int), None);
+                assert_eq!(I8F8::saturating_from_num(128 as $Int), I8F8::max_value());
+                assert_eq!(U0F128::saturating_from_num(1 as $Int), U0F128::max_value());
+                assert_eq!(
+                    U128F0::checked_from_num(<$Int>::MAX).map(U128F0::to_bits),
+                    Some(<$Int>::MAX as u128)
+                );
+            )* };
+        }
+        check! { i16, i32, i64, i128, isize, u16, u32, u64, u128, usize }
+
+        macro_rules! check_signed {
+            ($($Int:ident),*) => { $(
+                assert_eq!(U8F8::saturating_from_num(-1 as $Int), 0);
+                assert_eq!(U8F8::checked_from_num(-1 as $Int), None);
+                assert_eq!(I8F8::saturating_from_num(-128 as $Int), I8F8::min_value());
+                assert_eq!(I8F8::checked_from_num(-128 as $Int), Some(I8F8::min_value()));
+                assert_eq!(I0F8::saturating_from_num(-1 as $Int), I0F8::min_value());
+                assert_eq!(I0F8::checked_from_num(-1 as $Int), None);
+            )* };
+        }
+        check_signed! { i8, i16, i32, i64, i128, isize }
+
+        assert_eq!(U4F4::saturating_from_num(255u8), U4F4::max_value());
+        assert_eq!(U4F4::checked_from_num(15u8), Some(U4F4::from_num(15)));
+        assert_eq!(I4F4::saturating_from_num(i8::MIN), I4F4::min_value());
+    }
+
+    #[test]
+    fn fixed_from_fixed() {
+        use crate::traits::{FromFixed, ToFixed};
+
+        for bits in i16::MIN..=i16::MAX {
+            let src = I8F8::from_bits(bits);
+            // from the destination agrees with to from the source
+            assert_eq!(
+                I4F4::checked_from_fixed(src),
+                src.checked_to_fixed::<I4F4>()
+            );
+            assert_eq!(
+                U4F4::saturating_from_fixed(src),
+                src.saturating_to_fixed::<U4F4>()
+            );
+            assert_eq!(
+                I4F4::wrapping_from_fixed(src),
+                src.wrapping_to_fixed::<I4F4>()
+            );
+            assert_eq!(
+                U12F4::overflowing_from_fixed(src),
+                src.overflowing_to_fixed::<U12F4>()
+            );
+            assert_eq!(I16F16::from_fixed(src), src);
+            assert_eq!(I24F8::from_fixed(src).to_bits(), i32::from(bits));
+        }
+        assert_eq!(I4F4::checked_from_fixed(I8F8::from_num(8)), None);
+        assert_eq!(
+            I4F4::saturating_from_fixed(I8F8::from_num(-9)),
+            I4F4::min_value()
+        );
+        assert_eq!(U4F4::saturating_from_fixed(I8F8::from_num(-1)), 0);
+        assert_eq!(U0F128::from_fixed(I0F8::from_num(0.25)), 0.25);
+    }
+
     #[cfg(feature = "f16")]
     #[test]
     fn to_f16() {
@@ -19,6 +19,11 @@ use crate::{
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
     FixedU8,
 };
+#[cfg(feature = "alloc")]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 use core::{
     cmp::{self, Ordering},
     fmt::{
@@ -496,6 +501,189 @@ impl Mul10 for u128 {
     }
 }
 
+// Formats a number in engineering notation with sig_figs significant
+// digits, rounding to the nearest with ties rounded to even. The number
+// is given exactly by its sign, its integer part and the decimal digits
+// of its fractional part. The exponent is a multiple of three and is
+// written as an SI prefix, or as e<exp> outside the range of prefixes.
+#[cfg(feature = "alloc")]
+pub(crate) fn eng_string<D>(neg: bool, int_abs: u128, frac_digits: D, sig_figs: usize) -> String
+where
+    D: Iterator<Item = u8>,
+{
+    assert!(sig_figs > 0, "zero significant figures");
+    let mut digits = Vec::new();
+    let mut int = int_abs;
+    while int != 0 {
+        digits.push((int % 10) as u8);
+        int /= 10;
+    }
+    digits.reverse();
+    let int_len = digits.len() as i32;
+    digits.extend(frac_digits);
+
+    let mut s = String::new();
+    let first = match digits.iter().position(|&d| d != 0) {
+        Some(first) => first,
+        None => {
+            s.push('0');
+            if sig_figs > 1 {
+                s.push('.');
+                for _ in 1..sig_figs {
+                    s.push('0');
+                }
+            }
+            return s;
+        }
+    };
+    // exp10 is the decimal exponent of the most significant digit
+    let mut exp10 = int_len - 1 - first as i32;
+    let sig = &digits[first..];
+    let mut mant = Vec::with_capacity(sig_figs);
+    mant.extend(sig.iter().take(sig_figs));
+    if let Some((&dropped, rest)) = sig.get(sig_figs..).and_then(|r| r.split_first()) {
+        let round_up = match dropped.cmp(&5) {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+            Ordering::Equal => rest.iter().any(|&d| d != 0) || mant[sig_figs - 1] % 2 != 0,
+        };
+        if round_up {
+            // the trailing zeros are restored by the resize below
+            match mant.iter().rposition(|&d| d != 9) {
+                Some(i) => {
+                    mant[i] += 1;
+                    mant.truncate(i + 1);
+                }
+                None => {
+                    // 99…9 rounds up to 100…0
+                    mant.clear();
+                    mant.push(1);
+                    exp10 += 1;
+                }
+            }
+        }
+    }
+    let eng_exp = exp10.div_euclid(3) * 3;
+    let int_digits = (exp10 - eng_exp + 1) as usize;
+    let len = cmp::max(sig_figs, int_digits);
+    mant.resize(len, 0);
+
+    if neg {
+        s.push('-');
+    }
+    s.extend(mant[..int_digits].iter().map(|&d| char::from(b'0' + d)));
+    if len > int_digits {
+        s.push('.');
+        s.extend(mant[int_digits..].iter().map(|&d| char::from(b'0' + d)));
+    }
+    let prefix = match eng_exp {
+        -30 => "q",
+        -27 => "r",
+        -24 => "y",
+        -21 => "z",
+        -18 => "a",
+        -15 => "f",
+        -12 => "p",
+        -9 => "n",
+        -6 => "µ",
+        -3 => "m",
+        0 => "",
+        3 => "k",
+        6 => "M",
+        9 => "G",
+        12 => "T",
+        15 => "P",
+        18 => "E",
+        21 => "Z",
+        24 => "Y",
+        27 => "R",
+        30 => "Q",
+        _ => {
+            s.push('e');
+            s.push_str(&eng_exp.to_string());
+            ""
+        }
+    };
+    s.push_str(prefix);
+    s
+}
+
+// Returns the shortest decimal string accepted by round_trips, where the
+// number is given exactly by its sign, its integer part and the decimal
+// digits of its fractional part. For each number of fractional digits,
+// the candidates are the truncated digits and the digits rounded away
+// from zero, the nearer one first. The exact expansion is returned if no
+// shorter candidate is accepted.
+#[cfg(feature = "alloc")]
+pub(crate) fn shortest_string<R>(
+    neg: bool,
+    int_abs: u128,
+    frac_digits: &[u8],
+    round_trips: R,
+) -> String
+where
+    R: Fn(&str) -> bool,
+{
+    let frac_len = frac_digits
+        .iter()
+        .rposition(|&d| d != 0)
+        .map_or(0, |i| i + 1);
+    let frac_digits = &frac_digits[..frac_len];
+    let mut s = String::new();
+    for len in 0..frac_len {
+        let (kept, dropped) = frac_digits.split_at(len);
+        // dropped cannot be all zeros, as trailing zeros are trimmed
+        let away_first = match dropped[0].cmp(&5) {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+            Ordering::Equal => dropped.len() > 1,
+        };
+        for &away in &[away_first, !away_first] {
+            if write_decimal(&mut s, neg, int_abs, kept, away) && round_trips(&s) {
+                return s;
+            }
+        }
+    }
+    write_decimal(&mut s, neg, int_abs, frac_digits, false);
+    s
+}
+
+// Replaces the contents of s with the number, incremented in the last
+// fractional digit if away is set. Returns false if the integer part
+// overflows.
+#[cfg(feature = "alloc")]
+fn write_decimal(s: &mut String, neg: bool, int_abs: u128, frac: &[u8], away: bool) -> bool {
+    let mut int = int_abs;
+    let mut frac = frac;
+    let mut last = None;
+    if away {
+        // 0.199 incremented is 0.2, and 0.999 incremented is 1
+        match frac.iter().rposition(|&d| d != 9) {
+            Some(i) => {
+                last = Some(frac[i] + 1);
+                frac = &frac[..i];
+            }
+            None => {
+                int = match int.checked_add(1) {
+                    Some(int) => int,
+                    None => return false,
+                };
+                frac = &frac[..0];
+            }
+        }
+    }
+    s.clear();
+    if neg {
+        s.push('-');
+    }
+    s.push_str(&int.to_string());
+    if !frac.is_empty() || last.is_some() {
+        s.push('.');
+        s.extend(frac.iter().chain(&last).map(|&d| char::from(b'0' + d)));
+    }
+    true
+}
+
 #[cfg(test)]
 #[allow(clippy::cognitive_complexity, clippy::float_cmp)]
 mod tests {
@@ -505,6 +693,71 @@ mod tests {
         string::{String, ToString},
     };
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eng_string() {
+        let eng = |f: I32F32, sig| f.to_eng_string(sig);
+        assert_eq!(eng(I32F32::from_num(1234.5), 3), "1.23k");
+        assert_eq!(eng(I32F32::from_num(-0.00456), 3), "-4.56m");
+        assert_eq!(eng(I32F32::from_num(12), 3), "12.0");
+        assert_eq!(eng(I32F32::from_num(123_456), 2), "120k");
+        assert_eq!(eng(I32F32::from_num(999.96), 4), "1.000k");
+        assert_eq!(eng(I32F32::from_num(999.94), 4), "999.9");
+        assert_eq!(eng(I32F32::from_num(0), 3), "0.00");
+        assert_eq!(eng(I32F32::from_num(0), 1), "0");
+        assert_eq!(eng(I32F32::from_num(0.5), 1), "500m");
+        // ties to even: 2.5 and 3.5 are exact
+        assert_eq!(eng(I32F32::from_num(2.5), 1), "2");
+        assert_eq!(eng(I32F32::from_num(3.5), 1), "4");
+        assert_eq!(eng(I32F32::from_num(0.000_001), 2), "1.0µ");
+        assert_eq!(U128F0::max_value().to_eng_string(4), "340.3e36");
+        assert_eq!(U0F128::from_bits(1).to_eng_string(3), "2.94e-39");
+        assert_eq!(I0F128::from_bits(-1).to_eng_string(3), "-2.94e-39");
+        assert_eq!(U8F0::from_num(255).to_eng_string(5), "255.00");
+        assert_eq!(I1F127::min_value().to_eng_string(2), "-1.0");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "zero significant figures")]
+    fn eng_string_zero_sig_figs() {
+        let _ = I32F32::from_num(1).to_eng_string(0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn shortest_string() {
+        for bits in i16::MIN..=i16::MAX {
+            let x = I8F8::from_bits(bits);
+            let s = x.to_shortest_string();
+            assert_eq!(s.parse::<I8F8>(), Ok(x), "{}", s);
+            assert!(s.len() <= x.to_string().len(), "{} {}", s, x);
+        }
+        for bits in 0..=u8::MAX {
+            let x = U0F8::from_bits(bits);
+            assert_eq!(x.to_shortest_string().parse::<U0F8>(), Ok(x));
+        }
+        assert_eq!(I8F8::from_num(0).to_shortest_string(), "0");
+        assert_eq!(I8F8::from_num(-128).to_shortest_string(), "-128");
+        // 0.1 is 26/256 = 0.1015625
+        assert_eq!(I8F8::from_bits(26).to_shortest_string(), "0.1");
+        assert_eq!(I8F8::from_bits(-26).to_shortest_string(), "-0.1");
+        // 255/256 = 0.99609375 needs three digits, and 0.996 is nearer than 0.997
+        assert_eq!(U0F8::from_bits(255).to_shortest_string(), "0.996");
+        // 1/256 = 0.00390625, 0.004 is the nearest with three digits
+        assert_eq!(U0F8::from_bits(1).to_shortest_string(), "0.004");
+        // 2, from rounding 1.99 away from zero, is tried and rejected
+        assert_eq!(U8F8::from_bits(0x01FF).to_shortest_string(), "1.996");
+        assert_eq!(U0F128::from_bits(1 << 127).to_shortest_string(), "0.5");
+        let third = U0F128::from_bits(u128::MAX / 3);
+        assert_eq!(third.to_shortest_string().parse::<U0F128>(), Ok(third));
+        assert_eq!(
+            U128F0::max_value().to_shortest_string(),
+            u128::MAX.to_string()
+        );
+        assert_eq!(I0F128::min_value().to_shortest_string(), "-0.5");
+    }
+
     #[test]
     fn format() {
         let pos = I16F16::from_num(12.3);
@@ -523,6 +776,25 @@ mod tests {
         assert_eq!(format!("{:#^08}", pos), "000012.3");
     }
 
+    #[test]
+    fn format_width_matches_float() {
+        for &val in &[3.140625, -3.140625, 0.0, -0.5, 100.25] {
+            let fix = I16F16::from_num(val);
+            for spec in 0..6 {
+                let (f, x) = match spec {
+                    0 => (format!("{:>10.2}", val), format!("{:>10.2}", fix)),
+                    1 => (format!("{:<10.2}", val), format!("{:<10.2}", fix)),
+                    2 => (format!("{:*^11.3}", val), format!("{:*^11.3}", fix)),
+                    3 => (format!("{:09.2}", val), format!("{:09.2}", fix)),
+                    4 => (format!("{:+09.2}", val), format!("{:+09.2}", fix)),
+                    _ => (format!("{:3.1}", val), format!("{:3.1}", fix)),
+                };
+                assert_eq!(f, x);
+            }
+        }
+        assert_eq!(format!("{:09.2}", I16F16::from_num(-3.140625)), "-00003.14");
+    }
+
     fn trim_frac_zeros(mut x: &str) -> &str {
         while x.ends_with('0') {
             x = &x[..x.len() - 1];
@@ -822,4 +1094,90 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn write_to_fixed_buffer() {
+        use core::fmt::{Result as FmtResult, Write};
+
+        struct Buf {
+            data: [u8; 8],
+            len: usize,
+        }
+        impl Write for Buf {
+            fn write_str(&mut self, s: &str) -> FmtResult {
+                let end = self.len + s.len();
+                if end > self.data.len() {
+                    return Err(core::fmt::Error);
+                }
+                self.data[self.len..end].copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        let mut buf = Buf {
+            data: [0; 8],
+            len: 0,
+        };
+        I16F16::from_num(-12.3).write_to(&mut buf, 4).unwrap();
+        assert_eq!(&buf.data[..buf.len], b"-12.3000");
+        buf.len = 0;
+        assert!(I16F16::from_num(-12.3).write_to(&mut buf, 5).is_err());
+    }
+
+    #[test]
+    fn int_digits_match_format() {
+        fn int_len(s: &str) -> u32 {
+            s.trim_start_matches('-').split('.').next().unwrap().len() as u32
+        }
+        for &bits in &[
+            0,
+            1,
+            0xFFFF,
+            0x1_0000,
+            0x9_FFFF,
+            0xA_0000,
+            i32::MAX,
+            -1,
+            -0xA_0000,
+            i32::MIN,
+        ] {
+            let f = I16F16::from_bits(bits);
+            assert_eq!(
+                f.int_digits(10),
+                int_len(&format!("{:.0}", f.to_num::<f64>().trunc()))
+            );
+            assert_eq!(f.int_digits(2), int_len(&format!("{:b}", f)));
+            assert_eq!(f.int_digits(8), int_len(&format!("{:o}", f)));
+            assert_eq!(f.int_digits(16), int_len(&format!("{:x}", f)));
+        }
+        assert_eq!(U0F128::max_value().int_digits(36), 1);
+        assert_eq!(U128F0::max_value().int_digits(10), 39);
+        assert_eq!(I128F0::min_value().int_digits(10), 39);
+        assert_eq!(I128F0::min_value().int_digits(2), 128);
+    }
+
+    #[test]
+    fn decimal_digits_match_format() {
+        fn frac_str<I: Iterator<Item = u8>>(digits: I) -> String {
+            digits.map(|d| char::from(b'0' + d)).collect()
+        }
+        for &bits in &[0, 1, 0x8000, 0xFFFF, 0x1_2345, i32::MAX, -1, i32::MIN] {
+            let f = I16F16::from_bits(bits);
+            let s = format!("{:.16}", f);
+            let expected = s.split('.').nth(1).unwrap().trim_end_matches('0');
+            assert_eq!(frac_str(f.decimal_digits()), expected);
+        }
+        let min_pos = U0F128::from_bits(1);
+        let s = format!("{:.128}", min_pos);
+        assert_eq!(frac_str(min_pos.decimal_digits()), &s[2..]);
+        assert_eq!(min_pos.decimal_digits().count(), 128);
+        assert_eq!(U128F0::max_value().decimal_digits().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be in the range 2..=36, not 37")]
+    fn int_digits_bad_radix() {
+        I16F16::from_num(1).int_digits(37);
+    }
 }
@@ -0,0 +1,169 @@
+// Copyright © 2018–2019 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::traits::Fixed;
+use core::{cmp, iter::FusedIterator};
+
+/// An iterator over evenly spaced fixed-point numbers.
+///
+/// The iterator yields `start`, `start + step`, `start + 2 × step`, and
+/// so on, for at most `count` values. If the next value would overflow,
+/// the iterator stops instead of panicking.
+///
+/// # Examples
+///
+/// ```rust
+/// use substrate_fixed::{types::I8F8, FixedRange};
+/// let range = FixedRange::new(I8F8::from_num(1), I8F8::from_num(0.5), 4);
+/// let v: Vec<f32> = range.map(I8F8::to_num).collect();
+/// assert_eq!(v, [1.0, 1.5, 2.0, 2.5]);
+///
+/// // stops at the edge of the range instead of overflowing
+/// let range = FixedRange::new(I8F8::from_num(126), I8F8::from_num(1), 10);
+/// assert_eq!(range.count(), 2);
+/// ```
+///
+/// Evenly spaced values between two end points can be obtained using
+/// the [`linspace`] method of the fixed-point types.
+///
+/// [`linspace`]: struct.FixedI32.html#method.linspace
+#[derive(Clone, Debug)]
+pub struct FixedRange<F> {
+    next: Option<F>,
+    step: F,
+    remaining: usize,
+    // replaces the final value, used by linspace to hit the end exactly
+    last: Option<F>,
+}
+
+impl<F: Fixed> FixedRange<F> {
+    /// Creates an iterator yielding at most `count` values, starting at
+    /// `start` and increasing by `step` each time.
+    ///
+    /// The step can be negative for signed types.
+    #[inline]
+    pub fn new(start: F, step: F, count: usize) -> FixedRange<F> {
+        FixedRange {
+            next: Some(start),
+            step,
+            remaining: count,
+            last: None,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn with_last(start: F, step: F, count: usize, last: F) -> FixedRange<F> {
+        FixedRange {
+            last: Some(last),
+            ..FixedRange::new(start, step, count)
+        }
+    }
+}
+
+impl<F: Fixed> Iterator for FixedRange<F> {
+    type Item = F;
+
+    #[inline]
+    fn next(&mut self) -> Option<F> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let current = match self.last {
+            Some(last) if self.remaining == 1 => last,
+            _ => self.next?,
+        };
+        self.remaining -= 1;
+        self.next = current.checked_add(self.step);
+        Some(current)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.next {
+            Some(_) => (cmp::min(1, self.remaining), Some(self.remaining)),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<F: Fixed> FusedIterator for FixedRange<F> {}
+
+/// An iterator over shares of a fixed-point number that sum exactly to
+/// it.
+///
+/// This `struct` is created by the `split_evenly_iter` method of the
+/// fixed-point types, for example
+/// [`FixedI32::split_evenly_iter`][`split_evenly_iter`]. The shares differ
+/// by at most the smallest representable step, with the larger
+/// magnitudes first.
+///
+/// # Examples
+///
+/// ```rust
+/// use substrate_fixed::types::I8F8;
+/// let total = I8F8::from_bits(10);
+/// let shares: Vec<I8F8> = total.split_evenly_iter(3).collect();
+/// assert_eq!(shares, [I8F8::from_bits(4), I8F8::from_bits(3), I8F8::from_bits(3)]);
+/// ```
+///
+/// [`split_evenly_iter`]: struct.FixedI32.html#method.split_evenly_iter
+#[derive(Clone, Debug)]
+pub struct SplitEvenly<F> {
+    base: F,
+    // the base with one more step away from zero
+    larger: F,
+    remaining: u32,
+    remaining_larger: u32,
+}
+
+impl<F: Fixed> SplitEvenly<F> {
+    #[inline]
+    pub(crate) fn new(base: F, larger: F, n: u32, n_larger: u32) -> SplitEvenly<F> {
+        SplitEvenly {
+            base,
+            larger,
+            remaining: n,
+            remaining_larger: n_larger,
+        }
+    }
+}
+
+impl<F: Fixed> Iterator for SplitEvenly<F> {
+    type Item = F;
+
+    #[inline]
+    fn next(&mut self) -> Option<F> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        if self.remaining_larger > 0 {
+            self.remaining_larger -= 1;
+            Some(self.larger)
+        } else {
+            Some(self.base)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining as usize;
+        (len, Some(len))
+    }
+}
+
+impl<F: Fixed> ExactSizeIterator for SplitEvenly<F> {}
+
+impl<F: Fixed> FusedIterator for SplitEvenly<F> {}
@@ -13,7 +13,10 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
-use crate::helpers::{FloatKind, IntHelper, ToFixedHelper, ToFloatHelper, Widest};
+use crate::{
+    helpers::{FloatKind, IntHelper, ToFixedHelper, ToFloatHelper, Widest},
+    RoundingMode,
+};
 use core::cmp::Ordering;
 #[cfg(feature = "f16")]
 use half::{bf16, f16};
@@ -36,7 +39,16 @@ pub trait FloatHelper: Copy {
     fn parts(self) -> (bool, i32, Self::Bits);
 
     fn from_to_float_helper(val: ToFloatHelper, frac_bits: u32, int_bits: u32) -> Self;
-    fn to_float_kind(self, dst_frac_bits: u32, dst_int_bits: u32) -> FloatKind;
+    #[inline]
+    fn to_float_kind(self, dst_frac_bits: u32, dst_int_bits: u32) -> FloatKind {
+        self.to_float_kind_round(dst_frac_bits, dst_int_bits, RoundingMode::Nearest)
+    }
+    fn to_float_kind_round(
+        self,
+        dst_frac_bits: u32,
+        dst_int_bits: u32,
+        mode: RoundingMode,
+    ) -> FloatKind;
 }
 
 macro_rules! sealed_float {
@@ -135,7 +147,12 @@ macro_rules! sealed_float {
             }
 
             #[inline]
-            fn to_float_kind(self, dst_frac_bits: u32, dst_int_bits: u32) -> FloatKind {
+            fn to_float_kind_round(
+                self,
+                dst_frac_bits: u32,
+                dst_int_bits: u32,
+                mode: RoundingMode,
+            ) -> FloatKind {
                 let prec = Self::PREC as i32;
 
                 let (neg, exp, mut mantissa) = self.parts();
@@ -161,14 +178,24 @@ macro_rules! sealed_float {
 
                 let mut src_frac_bits = prec - 1 - exp;
                 let need_to_shr = src_frac_bits - dst_frac_bits as i32;
+                // whether a nonzero removed part rounds the magnitude up
+                // for the directed rounding modes
+                let directed_up = match mode {
+                    RoundingMode::Nearest => None,
+                    RoundingMode::Floor => Some(neg),
+                    RoundingMode::Ceil => Some(!neg),
+                    RoundingMode::Zero => Some(false),
+                };
                 if need_to_shr > prec {
-                    let dir = if neg {
-                        Ordering::Greater
-                    } else {
-                        Ordering::Less
+                    // the magnitude is less than half the least significant bit
+                    let (bits, dir) = match (directed_up, neg) {
+                        (Some(true), false) => (Widest::Unsigned(1), Ordering::Greater),
+                        (Some(true), true) => (Widest::Negative(-1), Ordering::Less),
+                        (_, false) => (Widest::Unsigned(0), Ordering::Less),
+                        (_, true) => (Widest::Unsigned(0), Ordering::Greater),
                     };
                     let conv = ToFixedHelper {
-                        bits: Widest::Unsigned(0),
+                        bits,
                         dir,
                         overflow: false,
                     };
@@ -179,11 +206,16 @@ macro_rules! sealed_float {
                     let removed_bits = mantissa & !(!0 << need_to_shr);
                     let will_be_lsb = 1 << need_to_shr;
                     let tie = will_be_lsb >> 1;
+                    let round_up = match directed_up {
+                        Some(up) => up,
+                        None => {
+                            removed_bits > tie
+                                || (removed_bits == tie && mantissa & will_be_lsb != 0)
+                        }
+                    };
                     if removed_bits == 0 {
                         // removed nothing
-                    } else if removed_bits < tie {
-                        dir = Ordering::Less;
-                    } else if removed_bits > tie || mantissa & will_be_lsb != 0 {
+                    } else if round_up {
                         mantissa += will_be_lsb;
                         dir = Ordering::Greater;
                     } else {
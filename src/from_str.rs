@@ -14,8 +14,9 @@
 // <https://opensource.org/licenses/MIT>.
 
 use crate::{
-    display::Mul10,
-    helpers::IntHelper,
+    any_fixed::AnyFixed,
+    helpers::{self, IntHelper},
+    traits::Fixed,
     types::extra::{False, LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
     wide_div::WideDivRem,
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
@@ -174,6 +175,20 @@ where
     let dump_bits = I::NBITS - nbits;
     let mut rem_bits = nbits;
     let mut acc = I::ZERO;
+    // Accumulate chunks of eight digits while all their bits fit,
+    // leaving any rounding at the boundary to the per-digit loop.
+    let mut bytes = bytes;
+    while rem_bits >= 32 && bytes.len() >= 8 {
+        let (chunk, rest) = bytes.split_at(8);
+        let word = chunk.iter().fold(0u32, |word, &byte| {
+            (word << 4) | u32::from(unchecked_hex_digit(byte))
+        });
+        for &byte in &word.to_be_bytes() {
+            acc = (acc << 8) + I::from(byte);
+        }
+        rem_bits -= 32;
+        bytes = rest;
+    }
     for (i, &byte) in bytes.iter().enumerate() {
         let val = unchecked_hex_digit(byte);
         if rem_bits < 4 {
@@ -217,6 +232,76 @@ where
     (acc, overflow)
 }
 
+fn unchecked_digit(byte: u8) -> u8 {
+    // We know that byte is a valid digit for some radix ≤ 36.
+    match byte {
+        b'0'..=b'9' => byte - b'0',
+        b'A'..=b'Z' => byte - b'A' + 10,
+        _ => byte - b'a' + 10,
+    }
+}
+
+fn gen_str_int_to_bin<I>(bytes: &[u8], radix: u32) -> (I, bool)
+where
+    I: IntHelper<IsSigned = False> + From<u8>,
+{
+    let mut acc = I::from(0);
+    let mut overflow = false;
+    for &byte in bytes {
+        let (mul, mul_overflow) = acc.overflowing_mul(I::from(radix as u8));
+        let (add, add_overflow) = mul.overflowing_add(I::from(unchecked_digit(byte)));
+        acc = add;
+        overflow = overflow || mul_overflow || add_overflow;
+    }
+    (acc, overflow)
+}
+
+// Decode fractional digits in any radix ≤ 36 into nbits fractional bits.
+//
+// The fraction is v = (d1 + (d2 + (d3 + …) / r) / r) / r. Scaling by
+// 2^b, we get T_k = (d_k × 2^b + T_{k+1}) / r, and since d_k × 2^b is
+// an integer,
+//
+//     floor(T_k) = floor((d_k × 2^b + floor(T_{k+1})) / r)
+//
+// so we can find floor(v × 2^b) exactly by working from the last digit
+// using integer division only, keeping track of whether any remainder
+// was ever non-zero. We use b = nbits + 1 to get the rounding bit, so
+// intermediate values need up to 129 + 6 bits and are stored as (hi, lo).
+fn gen_str_frac_to_bin(bytes: &[u8], radix: u32, nbits: u32) -> Option<u128> {
+    debug_assert!(nbits <= 128);
+    let b = nbits + 1;
+    let radix = u128::from(radix);
+    let (mut floor_hi, mut floor_lo) = (0u128, 0u128);
+    let mut exact = true;
+    for &byte in bytes.iter().rev() {
+        let digit = u128::from(unchecked_digit(byte));
+        let (digit_hi, digit_lo) = if b >= 128 {
+            (digit << (b - 128), 0)
+        } else {
+            (digit >> (128 - b), digit << b)
+        };
+        let (lo, carry) = digit_lo.overflowing_add(floor_lo);
+        let hi = digit_hi + floor_hi + u128::from(carry);
+        let ((quot_hi, quot_lo), rem) = radix.div_rem_from((hi, lo));
+        floor_hi = quot_hi;
+        floor_lo = quot_lo;
+        exact = exact && rem == 0;
+    }
+    // floor = floor(v × 2^(nbits + 1)) < 2^129, so floor >> 1 fits in u128
+    let round_bit = floor_lo & 1 != 0;
+    let acc = (floor_hi << 127) | (floor_lo >> 1);
+    let acc = if round_bit && (!exact || acc.is_odd()) {
+        acc.checked_add(1)?
+    } else {
+        acc
+    };
+    if nbits < 128 && acc >> nbits != 0 {
+        return None;
+    }
+    Some(acc)
+}
+
 enum Round {
     Nearest,
     Floor,
@@ -343,7 +428,7 @@ impl DecToBin for u128 {
         let fives = 5u128.pow(54);
         let denom = fives * 2;
         // we need to combine (10^27*hi + lo) << (128 - 54 + 1)
-        let (hi_hi, hi_lo) = mul_hi_lo(hi, 10u128.pow(27));
+        let (hi_hi, hi_lo) = helpers::mul_hi_lo(hi, 10u128.pow(27));
         let (val_lo, overflow) = hi_lo.overflowing_add(lo);
         let val_hi = if overflow { hi_hi + 1 } else { hi_hi };
         let (mut numer_lo, mut numer_hi) = (val_lo, val_hi);
@@ -400,26 +485,78 @@ impl DecToBin for u128 {
 
     fn parse_is_short(bytes: &[u8]) -> ((u128, u128), bool) {
         if let Some(rem) = 27usize.checked_sub(bytes.len()) {
-            let hi = dec_str_int_to_bin::<u128>(bytes).0 * 10u128.pow(rem as u32);
+            let hi = dec27_to_u128(bytes) * 10u128.pow(rem as u32);
             ((hi, 0), true)
         } else {
-            let hi = dec_str_int_to_bin::<u128>(&bytes[..27]).0;
+            let hi = dec27_to_u128(&bytes[..27]);
 
             let (is_short, slice, pad) = if let Some(rem) = 54usize.checked_sub(bytes.len()) {
                 (true, &bytes[27..], 10u128.pow(rem as u32))
             } else {
                 (false, &bytes[27..54], 1)
             };
-            let lo = dec_str_int_to_bin::<u128>(slice).0 * pad;
+            let lo = dec27_to_u128(slice) * pad;
             ((hi, lo), is_short)
         }
     }
 }
 
+// Parses up to 19 decimal digits, which always fit in a u64.
+fn dec19_to_u64(bytes: &[u8]) -> u64 {
+    debug_assert!(bytes.len() <= 19);
+    let mut acc = 0;
+    for &byte in bytes {
+        acc = acc * 10 + u64::from(byte - b'0');
+    }
+    acc
+}
+
+// Parses up to 27 decimal digits, which always fit in a u128. Doing
+// most of the work in u64 avoids a 128-bit multiplication per digit.
+fn dec27_to_u128(bytes: &[u8]) -> u128 {
+    debug_assert!(bytes.len() <= 27);
+    match bytes.len().checked_sub(19) {
+        None | Some(0) => u128::from(dec19_to_u64(bytes)),
+        Some(hi_len) => {
+            let (hi, lo) = bytes.split_at(hi_len);
+            u128::from(dec19_to_u64(hi)) * 10u128.pow(19) + u128::from(dec19_to_u64(lo))
+        }
+    }
+}
+
+// Multiplies by pow ≤ 10^19, and returns the part of the product that
+// overflows, which is always < pow.
+trait MulPow10 {
+    fn mul_pow10_assign(&mut self, pow: u64) -> u64;
+}
+
+macro_rules! impl_mul_pow10 {
+    ($($Single:ident)*) => { $(
+        impl MulPow10 for $Single {
+            #[inline]
+            fn mul_pow10_assign(&mut self, pow: u64) -> u64 {
+                let prod = u128::from(*self) * u128::from(pow);
+                *self = prod as $Single;
+                (prod >> $Single::NBITS) as u64
+            }
+        }
+    )* };
+}
+impl_mul_pow10! { u8 u16 u32 u64 }
+
+impl MulPow10 for u128 {
+    #[inline]
+    fn mul_pow10_assign(&mut self, pow: u64) -> u64 {
+        let (hi, lo) = helpers::mul_hi_lo(*self, u128::from(pow));
+        *self = lo;
+        hi as u64
+    }
+}
+
 fn dec_str_frac_to_bin<I>(bytes: &[u8], nbits: u32) -> Option<I>
 where
     I: IntHelper<IsSigned = False> + FromStr + From<u8> + DecToBin,
-    I: Mul10 + Shl<u32, Output = I> + Shr<u32, Output = I> + Add<Output = I> + Mul<Output = I>,
+    I: MulPow10 + Shl<u32, Output = I> + Shr<u32, Output = I> + Add<Output = I> + Mul<Output = I>,
 {
     let (val, is_short) = I::parse_is_short(bytes);
     let one = I::from(1);
@@ -445,25 +582,31 @@ where
         ((floor << dump_bits) + (one << (dump_bits - 1)), false)
     };
     let mut tie = true;
-    for &byte in bytes {
+    let mut rem_bytes = bytes;
+    while !rem_bytes.is_empty() {
         if !add_5 && boundary == I::ZERO {
             // since zeros are trimmed in bytes, there must be some byte > 0 eventually
             tie = false;
             break;
         }
-        let mut boundary_digit = boundary.mul10_assign();
+        // compare up to 19 digits at a time, but only one digit if we need to add 5
+        let len = if add_5 { 1 } else { rem_bytes.len().min(19) };
+        let (chunk, rest) = rem_bytes.split_at(len);
+        rem_bytes = rest;
+        let mut boundary_digits = boundary.mul_pow10_assign(10u64.pow(len as u32));
         if add_5 {
             let (wrapped, overflow) = boundary.overflowing_add(I::from(5));
             boundary = wrapped;
             if overflow {
-                boundary_digit += 1;
+                boundary_digits += 1;
             }
             add_5 = false;
         }
-        if byte - b'0' < boundary_digit {
+        let digits = dec19_to_u64(chunk);
+        if digits < boundary_digits {
             return Some(floor);
         }
-        if byte - b'0' > boundary_digit {
+        if digits > boundary_digits {
             tie = false;
             break;
         }
@@ -479,31 +622,13 @@ where
     }
 }
 
-fn mul_hi_lo(lhs: u128, rhs: u128) -> (u128, u128) {
-    const LO: u128 = !(!0 << 64);
-    let (lhs_hi, lhs_lo) = (lhs >> 64, lhs & LO);
-    let (rhs_hi, rhs_lo) = (rhs >> 64, rhs & LO);
-    let lhs_lo_rhs_lo = lhs_lo.wrapping_mul(rhs_lo);
-    let lhs_hi_rhs_lo = lhs_hi.wrapping_mul(rhs_lo);
-    let lhs_lo_rhs_hi = lhs_lo.wrapping_mul(rhs_hi);
-    let lhs_hi_rhs_hi = lhs_hi.wrapping_mul(rhs_hi);
-
-    let col01 = lhs_lo_rhs_lo;
-    let (col01_hi, col01_lo) = (col01 >> 64, col01 & LO);
-    let partial_col12 = lhs_hi_rhs_lo + col01_hi;
-    let (col12, carry_col3) = partial_col12.overflowing_add(lhs_lo_rhs_hi);
-    let (col12_hi, col12_lo) = (col12 >> 64, col12 & LO);
-    let ans01 = (col12_lo << 64) + col01_lo;
-    let ans23 = lhs_hi_rhs_hi + col12_hi + if carry_col3 { 1u128 << 64 } else { 0 };
-    (ans23, ans01)
-}
 fn div_tie(dividend_hi: u128, dividend_lo: u128, divisor: u128) -> (u128, bool) {
     let ((_, lo), rem) = divisor.div_rem_from((dividend_hi, dividend_lo));
     (lo, rem == 0)
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, scale_info::TypeInfo)]
-struct Parse<'a> {
+pub(crate) struct Parse<'a> {
     neg: bool,
     int: &'a [u8],
     frac: &'a [u8],
@@ -536,6 +661,8 @@ enum ParseErrorKind {
     NoDigits,
     TooManyPoints,
     Overflow,
+    TooLong,
+    FormatMismatch,
 }
 
 impl From<ParseErrorKind> for ParseFixedError {
@@ -553,6 +680,8 @@ impl ParseFixedError {
             NoDigits => "string has no digits",
             TooManyPoints => "more than one decimal point found in string",
             Overflow => "overflow",
+            TooLong => "string is too long",
+            FormatMismatch => "Q format does not match the fixed-point type",
         }
     }
 }
@@ -602,12 +731,7 @@ fn parse_bounds(bytes: &[u8], radix: u32) -> Result<Parse<'_>, ParseFixedError>
                 trimmed_frac_end = Some(index + 1);
                 continue;
             }
-            (b'0'..=b'1', 2)
-            | (b'0'..=b'7', 8)
-            | (b'0'..=b'9', 10)
-            | (b'0'..=b'9', 16)
-            | (b'a'..=b'f', 16)
-            | (b'A'..=b'F', 16) => {
+            _ if (byte as char).is_digit(radix) => {
                 if trimmed_int_start.is_none() && point.is_none() && byte != b'0' {
                     trimmed_int_start = Some(index);
                 }
@@ -635,21 +759,413 @@ fn parse_bounds(bytes: &[u8], radix: u32) -> Result<Parse<'_>, ParseFixedError>
     Ok(Parse { neg, int, frac })
 }
 
+// Parses a number once, returning the rounded value, whether it
+// overflows and the fractional digits with trailing zeros trimmed, or
+// None if the string is not a valid number.
+pub(crate) fn overflowing_from_str_with_frac<F>(src: &str, radix: u32) -> Option<(F, bool, &[u8])>
+where
+    F: FromStrRadix,
+{
+    let parse = parse_bounds(src.as_bytes(), radix).ok()?;
+    let (val, overflow) = F::overflowing_from_parse(parse, radix);
+    Some((val, overflow, parse.frac))
+}
+
+// Returns the sign, the integer digits with leading zeros trimmed and
+// the fractional digits with trailing zeros trimmed of a valid number.
+pub(crate) fn trimmed_digits(
+    src: &str,
+    radix: u32,
+) -> Result<(bool, &[u8], &[u8]), ParseFixedError> {
+    parse_bounds(src.as_bytes(), radix).map(|parse| (parse.neg, parse.int, parse.frac))
+}
+
+// Splits off a trailing Q-format tag such as "Q8.8", and checks that
+// it matches int_nbits and frac_nbits. Returns the number before the tag.
+pub(crate) fn strip_q_format(
+    src: &str,
+    int_nbits: u32,
+    frac_nbits: u32,
+) -> Result<&str, ParseFixedError> {
+    let q = match src.rfind('Q') {
+        Some(q) => q,
+        None => return Err(ParseErrorKind::InvalidDigit.into()),
+    };
+    let mut nbits = src[q + 1..].splitn(2, '.').map(|digits| {
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseFixedError::from(ParseErrorKind::InvalidDigit));
+        }
+        // a count too large for u32 cannot match either
+        Ok(digits.parse::<u32>().ok())
+    });
+    let int = nbits
+        .next()
+        .unwrap_or(Err(ParseErrorKind::InvalidDigit.into()))?;
+    let frac = nbits
+        .next()
+        .unwrap_or(Err(ParseErrorKind::InvalidDigit.into()))?;
+    if int != Some(int_nbits) || frac != Some(frac_nbits) {
+        return Err(ParseErrorKind::FormatMismatch.into());
+    }
+    Ok(&src[..q])
+}
+
+// Parses a decimal number whose integer part may have its digits
+// grouped in threes by commas, for example "-1,234,567.89". Commas must
+// separate every group if present, and are not accepted in the
+// fractional part.
+pub(crate) fn from_str_grouped<F>(src: &str) -> Result<F, ParseFixedError>
+where
+    F: FromStrRadix,
+{
+    let (neg, rest) = split_sign(src);
+    from_grouped_digits(neg, rest, '.', ',')
+}
+
+// Parses a price such as "$1,234.56" or "-€1.000,50": an optional sign,
+// an optional currency symbol, and then a decimal number with the given
+// decimal point and group separator, grouped as for from_str_grouped.
+pub(crate) fn from_str_currency<F>(
+    src: &str,
+    symbol: &str,
+    point: char,
+    group: char,
+) -> Result<F, ParseFixedError>
+where
+    F: FromStrRadix,
+{
+    assert!(
+        point != group,
+        "the decimal point and the group separator must be different"
+    );
+    let (neg, rest) = split_sign(src);
+    let rest = rest.strip_prefix(symbol).unwrap_or(rest);
+    from_grouped_digits(neg, rest, point, group)
+}
+
+fn split_sign(src: &str) -> (bool, &str) {
+    match src.as_bytes().first() {
+        Some(b'-') => (true, &src[1..]),
+        Some(b'+') => (false, &src[1..]),
+        _ => (false, src),
+    }
+}
+
+// Parses unsigned decimal digits with the given decimal point, where
+// the integer digits may be grouped in threes by group separators.
+fn from_grouped_digits<F>(
+    neg: bool,
+    digits: &str,
+    point: char,
+    group: char,
+) -> Result<F, ParseFixedError>
+where
+    F: FromStrRadix,
+{
+    let (int, frac) = match digits.find(point) {
+        Some(index) => (&digits[..index], &digits[index + point.len_utf8()..]),
+        None => (digits, &digits[..0]),
+    };
+    if frac.contains(point) {
+        return Err(ParseErrorKind::TooManyPoints.into());
+    }
+    let frac = frac.as_bytes();
+    if !frac.iter().all(u8::is_ascii_digit) {
+        return Err(ParseErrorKind::InvalidDigit.into());
+    }
+    if int.contains(group) {
+        for (index, digits) in int.split(group).enumerate() {
+            let valid_len = if index == 0 {
+                (1..=3).contains(&digits.len())
+            } else {
+                digits.len() == 3
+            };
+            if !valid_len {
+                return Err(ParseErrorKind::InvalidDigit.into());
+            }
+        }
+    }
+    if !int.chars().all(|c| c == group || c.is_ascii_digit()) {
+        return Err(ParseErrorKind::InvalidDigit.into());
+    }
+    if int.is_empty() && frac.is_empty() {
+        return Err(ParseErrorKind::NoDigits.into());
+    }
+
+    // u128::MAX has 39 decimal digits, so more significant digits
+    // than that overflow every fixed-point type.
+    let mut buf = [0u8; 39];
+    let mut len = 0;
+    for b in int.bytes().filter(u8::is_ascii_digit) {
+        if len == 0 && b == b'0' {
+            continue;
+        }
+        if len == buf.len() {
+            return Err(ParseErrorKind::Overflow.into());
+        }
+        buf[len] = b;
+        len += 1;
+    }
+    let frac_len = frac.iter().rposition(|&b| b != b'0').map_or(0, |i| i + 1);
+    let parse = Parse {
+        neg,
+        int: &buf[..len],
+        frac: &frac[..frac_len],
+    };
+    match F::overflowing_from_parse(parse, 10) {
+        (_, true) => Err(ParseErrorKind::Overflow.into()),
+        (val, false) => Ok(val),
+    }
+}
+
+// Parses an optionally signed string of integer digits in the given
+// radix, without any decimal point, and places it in the integer part.
+// As there is no fraction, the digits are accumulated directly and
+// shifted into place with no rounding.
+pub(crate) fn from_integer_str<F>(src: &str, radix: u32) -> Result<F, ParseFixedError>
+where
+    F: Fixed,
+{
+    assert!(
+        (2..=36).contains(&radix),
+        "radix must be in the range 2 to 36"
+    );
+    let bytes = src.as_bytes();
+    let (neg, digits) = match bytes.first() {
+        Some(b'-') => (true, &bytes[1..]),
+        Some(b'+') => (false, &bytes[1..]),
+        _ => (false, bytes),
+    };
+    if digits.is_empty() {
+        return Err(ParseErrorKind::NoDigits.into());
+    }
+    let mut abs = 0u128;
+    for &byte in digits {
+        let digit = match (byte as char).to_digit(radix) {
+            Some(digit) => digit,
+            None => return Err(ParseErrorKind::InvalidDigit.into()),
+        };
+        abs = match abs
+            .checked_mul(u128::from(radix))
+            .and_then(|mul| mul.checked_add(u128::from(digit)))
+        {
+            Some(abs) => abs,
+            None => return Err(ParseErrorKind::Overflow.into()),
+        };
+    }
+    let frac_nbits = F::frac_nbits();
+    if abs.leading_zeros() < frac_nbits {
+        return Err(ParseErrorKind::Overflow.into());
+    }
+    // abs is zero if frac_nbits is 128
+    let abs = abs.checked_shl(frac_nbits).unwrap_or(0);
+    F::private_checked_from_neg_abs(neg, abs).ok_or_else(|| ParseErrorKind::Overflow.into())
+}
+
+// Parses the longest prefix of src that is a decimal number, and
+// returns the number together with the rest of the string. A point is
+// part of the number if the number has a digit on either side of it,
+// so "12." is parsed completely, while "." and "-" are not numbers.
+pub(crate) fn from_str_prefix<F>(src: &str) -> Result<(F, &str), ParseFixedError>
+where
+    F: FromStrRadix<Err = ParseFixedError>,
+{
+    let bytes = src.as_bytes();
+    let count_digits = |start: usize| {
+        bytes[start..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+    let mut end = match bytes.first() {
+        Some(b'-') | Some(b'+') => 1,
+        _ => 0,
+    };
+    let int_digits = count_digits(end);
+    end += int_digits;
+    if bytes.get(end) == Some(&b'.') {
+        let frac_digits = count_digits(end + 1);
+        if int_digits > 0 || frac_digits > 0 {
+            end += 1 + frac_digits;
+        }
+    }
+    if !bytes[..end].iter().any(u8::is_ascii_digit) {
+        return Err(ParseErrorKind::NoDigits.into());
+    }
+    let (num, rest) = src.split_at(end);
+    F::from_str_radix(num, 10).map(|val| (val, rest))
+}
+
+// Parses an optionally signed string of integer digits in the given
+// radix, and places the point implied_frac_digits digits from the
+// right, padding with zeros on the left if there are fewer digits.
+pub(crate) fn from_scaled_str<F>(
+    src: &str,
+    radix: u32,
+    implied_frac_digits: u32,
+) -> Result<F, ParseFixedError>
+where
+    F: FromStrRadix,
+{
+    assert!(
+        (2..=36).contains(&radix),
+        "radix must be in the range 2 to 36"
+    );
+    let bytes = src.as_bytes();
+    let (neg, digits) = match bytes.first() {
+        Some(b'-') => (true, &bytes[1..]),
+        Some(b'+') => (false, &bytes[1..]),
+        _ => (false, bytes),
+    };
+    if digits.is_empty() {
+        return Err(ParseErrorKind::NoDigits.into());
+    }
+    if !digits.iter().all(|&b| (b as char).is_digit(radix)) {
+        return Err(ParseErrorKind::InvalidDigit.into());
+    }
+
+    // With more than 128 leading fractional zeros the number is less
+    // than 2^-129 in magnitude, which rounds to zero for every type.
+    let mut buf = [b'0'; 256];
+    let implied_frac_digits = implied_frac_digits as usize;
+    let (int, frac) = match digits.len().checked_sub(implied_frac_digits) {
+        Some(point) => digits.split_at(point),
+        None if implied_frac_digits - digits.len() > 128 => (&digits[..0], &digits[..0]),
+        None => {
+            let zeros = implied_frac_digits - digits.len();
+            let len = zeros + digits.len();
+            if len > buf.len() {
+                return Err(ParseErrorKind::TooLong.into());
+            }
+            buf[zeros..len].copy_from_slice(digits);
+            (&digits[..0], &buf[..len])
+        }
+    };
+    let int_start = int.iter().position(|&b| b != b'0').unwrap_or(int.len());
+    let frac_len = frac.iter().rposition(|&b| b != b'0').map_or(0, |i| i + 1);
+    let parse = Parse {
+        neg,
+        int: &int[int_start..],
+        frac: &frac[..frac_len],
+    };
+    match F::overflowing_from_parse(parse, radix) {
+        (_, true) => Err(ParseErrorKind::Overflow.into()),
+        (val, false) => Ok(val),
+    }
+}
+
+// Parses a decimal number whose fractional part may end with a
+// repeating group in parentheses, for example "0.1(6)" for 1/6.
+//
+// A repeating value that is not exactly representable in binary
+// differs from every rounding boundary of a type with at most 128
+// fractional bits by more than 10^-(k + p + 39), where k is the number
+// of fractional digits before the group and p is the length of the
+// group, as 2^129 < 10^39. So expanding the group to k + p + 39 digits
+// rounds the same as the exact value. The only repeating values that
+// are exactly representable are those with a group of zeros or nines,
+// and those are replaced by the equal terminating decimals.
+pub(crate) fn from_str_repeating<F>(src: &str) -> Result<F, ParseFixedError>
+where
+    F: FromStrRadix<Err = ParseFixedError>,
+{
+    let (neg, rest) = split_sign(src);
+    let (digits, group) = match rest.find('(') {
+        Some(open) => match rest[open + 1..].strip_suffix(')') {
+            Some(group) if !group.is_empty() => (&rest[..open], group.as_bytes()),
+            _ => return Err(ParseErrorKind::InvalidDigit.into()),
+        },
+        // without a group this is an ordinary decimal number
+        None => return F::from_str_radix(src, 10),
+    };
+    let (int, frac) = match digits.find('.') {
+        Some(point) => (&digits[..point], &digits[point + 1..]),
+        // a repeating group is only allowed after the point
+        None => return Err(ParseErrorKind::InvalidDigit.into()),
+    };
+    if frac.contains('.') {
+        return Err(ParseErrorKind::TooManyPoints.into());
+    }
+    let (int, frac) = (int.as_bytes(), frac.as_bytes());
+    if !int.iter().chain(frac).chain(group).all(u8::is_ascii_digit) {
+        return Err(ParseErrorKind::InvalidDigit.into());
+    }
+
+    // u128::MAX has 39 decimal digits, so more significant digits
+    // than that overflow every fixed-point type. A leading zero is kept
+    // for the carry when a group of nines is replaced.
+    let mut int_buf = [b'0'; 40];
+    let int = &int[int.iter().position(|&b| b != b'0').unwrap_or(int.len())..];
+    if int.len() >= int_buf.len() {
+        return Err(ParseErrorKind::Overflow.into());
+    }
+    let int_buf_len = int_buf.len();
+    int_buf[int_buf_len - int.len()..].copy_from_slice(int);
+    let mut frac_buf = [b'0'; 256];
+    let all = |digit| group.iter().all(|&b| b == digit);
+    let expand = !all(b'0') && !all(b'9');
+    let frac_len = if expand {
+        frac.len() + group.len() + 39
+    } else {
+        frac.len()
+    };
+    if frac_len > frac_buf.len() {
+        return Err(ParseErrorKind::TooLong.into());
+    }
+    frac_buf[..frac.len()].copy_from_slice(frac);
+    if expand {
+        let repeated = group.iter().cycle();
+        for (dst, &src) in frac_buf[frac.len()..frac_len].iter_mut().zip(repeated) {
+            *dst = src;
+        }
+    } else if group.first() == Some(&b'9') {
+        // x.y(9) is equal to x.y + 10^-k
+        let digits = frac_buf[..frac_len].iter_mut().rev();
+        for digit in digits.chain(int_buf.iter_mut().rev()) {
+            if *digit == b'9' {
+                *digit = b'0';
+            } else {
+                *digit += 1;
+                break;
+            }
+        }
+    }
+    let int_start = int_buf
+        .iter()
+        .position(|&b| b != b'0')
+        .unwrap_or(int_buf_len);
+    let frac_len = frac_buf[..frac_len]
+        .iter()
+        .rposition(|&b| b != b'0')
+        .map_or(0, |i| i + 1);
+    let parse = Parse {
+        neg,
+        int: &int_buf[int_start..],
+        frac: &frac_buf[..frac_len],
+    };
+    match F::overflowing_from_parse(parse, 10) {
+        (_, true) => Err(ParseErrorKind::Overflow.into()),
+        (val, false) => Ok(val),
+    }
+}
+
 fn frac_is_half(bytes: &[u8], radix: u32) -> bool {
     // since zeros are trimmed, there must be exatly one byte
-    bytes.len() == 1 && bytes[0] - b'0' == (radix as u8) / 2
+    bytes.len() == 1 && u32::from(unchecked_digit(bytes[0])) * 2 == radix
 }
 
 pub(crate) trait FromStrRadix: Sized {
     type Err;
     fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::Err>;
+    fn bounded_from_str_radix(s: &str, radix: u32, max_len: usize) -> Result<Self, Self::Err>;
     fn saturating_from_str_radix(s: &str, radix: u32) -> Result<Self, Self::Err>;
     fn wrapping_from_str_radix(s: &str, radix: u32) -> Result<Self, Self::Err>;
     fn overflowing_from_str_radix(s: &str, radix: u32) -> Result<(Self, bool), Self::Err>;
+    fn overflowing_from_parse(parse: Parse<'_>, radix: u32) -> (Self, bool);
 }
 
 macro_rules! impl_from_str_traits {
-    ($Fixed:ident($Bits:ident), $LeEqU:ident; fn $from:ident) => {
+    ($Fixed:ident($Bits:ident), $LeEqU:ident, $signed:expr) => {
         impl<Frac: $LeEqU> FromStr for $Fixed<Frac> {
             type Err = ParseFixedError;
             /// Parses a string slice to return a fixed-point number.
@@ -671,6 +1187,17 @@ macro_rules! impl_from_str_traits {
                 }
             }
             #[inline]
+            fn bounded_from_str_radix(
+                s: &str,
+                radix: u32,
+                max_len: usize,
+            ) -> Result<Self, Self::Err> {
+                if s.len() > max_len {
+                    return Err(ParseErrorKind::TooLong.into());
+                }
+                Self::from_str_radix(s, radix)
+            }
+            #[inline]
             fn saturating_from_str_radix(s: &str, radix: u32) -> Result<Self, Self::Err> {
                 match Self::overflowing_from_str_radix(s, radix) {
                     Ok((val, false)) => Ok(val),
@@ -690,87 +1217,25 @@ macro_rules! impl_from_str_traits {
             }
             #[inline]
             fn overflowing_from_str_radix(s: &str, radix: u32) -> Result<(Self, bool), Self::Err> {
-                $from(s.as_bytes(), radix, Self::INT_NBITS, Self::FRAC_NBITS)
-                    .map(|(bits, overflow)| (Self::from_bits(bits), overflow))
+                let parse = parse_bounds(s.as_bytes(), radix)?;
+                Ok(Self::overflowing_from_parse(parse, radix))
+            }
+            #[inline]
+            fn overflowing_from_parse(parse: Parse<'_>, radix: u32) -> (Self, bool) {
+                let nbits = Self::INT_NBITS + Self::FRAC_NBITS;
+                let (bits, overflow) = from_parse(parse, radix, nbits, Self::FRAC_NBITS, $signed);
+                (Self::from_bits(bits as $Bits), overflow)
             }
         }
     };
 }
 
-macro_rules! impl_from_str {
+macro_rules! impl_get_int_frac {
     (
-        $FixedI:ident($BitsI:ident), $FixedU:ident($BitsU:ident), $LeEqU:ident;
-        fn $from_i:ident;
-        fn $from_u:ident;
-        fn $get_int_frac:ident;
+        $BitsU:ident;
         fn $get_int:ident, ($get_int_half:ident, $attempt_int_half:expr);
         fn $get_frac:ident, ($get_frac_half:ident, $attempt_frac_half:expr);
     ) => {
-        impl_from_str_traits! { $FixedI($BitsI), $LeEqU; fn $from_i }
-        impl_from_str_traits! { $FixedU($BitsU), $LeEqU; fn $from_u }
-
-        fn $from_i(
-            bytes: &[u8],
-            radix: u32,
-            int_nbits: u32,
-            frac_nbits: u32,
-        ) -> Result<($BitsI, bool), ParseFixedError> {
-            let (neg, abs, mut overflow) = $get_int_frac(bytes, radix, int_nbits, frac_nbits)?;
-            let max_abs = $BitsU::MSB - if !neg { 1 } else { 0 };
-            if abs > max_abs {
-                overflow = true;
-            }
-            let abs = if neg { abs.wrapping_neg() } else { abs } as $BitsI;
-            Ok((abs, overflow))
-        }
-
-        fn $from_u(
-            bytes: &[u8],
-            radix: u32,
-            int_nbits: u32,
-            frac_nbits: u32,
-        ) -> Result<($BitsU, bool), ParseFixedError> {
-            let (neg, abs, mut overflow) = $get_int_frac(bytes, radix, int_nbits, frac_nbits)?;
-            if neg && abs > 0 {
-                overflow = true;
-            }
-            let abs = if neg { abs.wrapping_neg() } else { abs };
-            Ok((abs, overflow))
-        }
-
-        fn $get_int_frac(
-            bytes: &[u8],
-            radix: u32,
-            int_nbits: u32,
-            frac_nbits: u32,
-        ) -> Result<(bool, $BitsU, bool), ParseFixedError> {
-            let Parse { neg, int, frac } = parse_bounds(bytes, radix)?;
-            let (int_val, mut overflow) = $get_int(int, radix, int_nbits);
-            let (frac_val, frac_overflow) = match $get_frac(frac, radix, frac_nbits) {
-                Some(val) => (val, false),
-                None => (0, true),
-            };
-            let mut val = int_val | frac_val;
-            // frac_overflow does not catch the case where:
-            //  1. int is odd
-            //  2. frac_nbits is 0
-            //  3. frac_bytes is exactly half, e.g. "5" for decimal
-            // In this case, get_frac returns 0.5 rounded to even 0.0,
-            // as it does not have a way to know that int is odd.
-            if frac_overflow || (int_val.is_odd() && frac_nbits == 0 && frac_is_half(frac, radix)) {
-                let (new_val, new_overflow) = if int_nbits == 0 {
-                    (val, true)
-                } else {
-                    val.overflowing_add(1 << frac_nbits)
-                };
-                if new_overflow {
-                    overflow = true;
-                }
-                val = new_val;
-            }
-            Ok((neg, val, overflow))
-        }
-
         fn $get_int(int: &[u8], radix: u32, nbits: u32) -> ($BitsU, bool) {
             const HALF: u32 = <$BitsU as IntHelper>::NBITS / 2;
             if $attempt_int_half && nbits <= HALF {
@@ -785,7 +1250,8 @@ macro_rules! impl_from_str {
                 2 => bin_str_int_to_bin(int),
                 8 => oct_str_int_to_bin(int),
                 16 => hex_str_int_to_bin(int),
-                _ => dec_str_int_to_bin(int),
+                10 => dec_str_int_to_bin(int),
+                _ => gen_str_int_to_bin(int, radix),
             };
             let remove_bits = <$BitsU as IntHelper>::NBITS - nbits;
             if nbits == 0 {
@@ -812,53 +1278,186 @@ macro_rules! impl_from_str {
                 8 => oct_str_frac_to_bin(frac, nbits),
                 16 => hex_str_frac_to_bin(frac, nbits),
                 10 => dec_str_frac_to_bin(frac, nbits),
-                _ => unreachable!(),
+                _ => gen_str_frac_to_bin(frac, radix, nbits).map(|val| val as $BitsU),
             }
         }
     };
 }
 
-impl_from_str! {
-    FixedI8(i8), FixedU8(u8), LeEqU8;
-    fn from_str_i8;
-    fn from_str_u8;
-    fn get_int_frac8;
+impl_get_int_frac! {
+    u8;
     fn get_int8, (get_int8, false);
     fn get_frac8, (get_frac8, false);
 }
-impl_from_str! {
-    FixedI16(i16), FixedU16(u16), LeEqU16;
-    fn from_str_i16;
-    fn from_str_u16;
-    fn get_int_frac16;
+impl_get_int_frac! {
+    u16;
     fn get_int16, (get_int8, true);
     fn get_frac16, (get_frac8, true);
 }
-impl_from_str! {
-    FixedI32(i32), FixedU32(u32), LeEqU32;
-    fn from_str_i32;
-    fn from_str_u32;
-    fn get_int_frac32;
+impl_get_int_frac! {
+    u32;
     fn get_int32, (get_int16, true);
     fn get_frac32, (get_frac16, true);
 }
-impl_from_str! {
-    FixedI64(i64), FixedU64(u64), LeEqU64;
-    fn from_str_i64;
-    fn from_str_u64;
-    fn get_int_frac64;
+impl_get_int_frac! {
+    u64;
     fn get_int64, (get_int32, true);
     fn get_frac64, (get_frac32, false);
 }
-impl_from_str! {
-    FixedI128(i128), FixedU128(u128), LeEqU128;
-    fn from_str_i128;
-    fn from_str_u128;
-    fn get_int_frac128;
+impl_get_int_frac! {
+    u128;
     fn get_int128, (get_int64, true);
     fn get_frac128, (get_frac64, true);
 }
 
+impl_from_str_traits! { FixedI8(i8), LeEqU8, true }
+impl_from_str_traits! { FixedI16(i16), LeEqU16, true }
+impl_from_str_traits! { FixedI32(i32), LeEqU32, true }
+impl_from_str_traits! { FixedI64(i64), LeEqU64, true }
+impl_from_str_traits! { FixedI128(i128), LeEqU128, true }
+impl_from_str_traits! { FixedU8(u8), LeEqU8, false }
+impl_from_str_traits! { FixedU16(u16), LeEqU16, false }
+impl_from_str_traits! { FixedU32(u32), LeEqU32, false }
+impl_from_str_traits! { FixedU64(u64), LeEqU64, false }
+impl_from_str_traits! { FixedU128(u128), LeEqU128, false }
+
+// The parsing core, shared by all widths so that it is compiled only
+// once. Returns the bits of the parsed value in the low nbits bits and
+// whether it overflows a type with nbits bits of which frac_nbits are
+// fractional.
+//
+// The number is decoded as for a 128-bit type with frac_nbits
+// fractional bits, so that it is exact unless it overflows 128 bits,
+// and then checked against the range of the narrower type. Decoding
+// only multiplies, adds, shifts and negates modulo a power of two, so on
+// overflow the low nbits bits are the same wrapped value that decoding
+// in nbits bits would give.
+fn from_parse(
+    parse: Parse<'_>,
+    radix: u32,
+    nbits: u32,
+    frac_nbits: u32,
+    signed: bool,
+) -> (u128, bool) {
+    debug_assert!(frac_nbits <= nbits && nbits <= 128);
+    let (neg, abs, mut overflow) = get_int_frac(parse, radix, nbits, frac_nbits);
+    let max_abs = if signed {
+        (1u128 << (nbits - 1)) - u128::from(!neg)
+    } else if neg {
+        0
+    } else {
+        !0 >> (128 - nbits)
+    };
+    if abs > max_abs {
+        overflow = true;
+    }
+    let bits = if neg { abs.wrapping_neg() } else { abs };
+    (bits, overflow)
+}
+
+// The fraction is decoded with the decoder for the type's own width,
+// as the narrower decoders do not always round the same way as the
+// 128-bit one when there are more digits than they can take at once.
+fn get_frac(frac: &[u8], radix: u32, nbits: u32, frac_nbits: u32) -> Option<u128> {
+    match nbits {
+        8 => get_frac8(frac, radix, frac_nbits).map(u128::from),
+        16 => get_frac16(frac, radix, frac_nbits).map(u128::from),
+        32 => get_frac32(frac, radix, frac_nbits).map(u128::from),
+        64 => get_frac64(frac, radix, frac_nbits).map(u128::from),
+        _ => get_frac128(frac, radix, frac_nbits),
+    }
+}
+
+fn get_int_frac(parse: Parse<'_>, radix: u32, nbits: u32, frac_nbits: u32) -> (bool, u128, bool) {
+    let Parse { neg, int, frac } = parse;
+    let int_nbits = 128 - frac_nbits;
+    if frac_nbits == nbits {
+        // Fractional-only types skip the integer pipeline. As
+        // leading zeros are trimmed, any integer digit left
+        // overflows, and so does a fraction rounding up to one.
+        return match get_frac(frac, radix, nbits, frac_nbits) {
+            Some(val) => (neg, val, !int.is_empty()),
+            None => (neg, 0, true),
+        };
+    }
+    let (int_val, mut overflow) = get_int128(int, radix, int_nbits);
+    let (frac_val, frac_overflow) = match get_frac(frac, radix, nbits, frac_nbits) {
+        Some(val) => (val, false),
+        None => (0, true),
+    };
+    let mut val = int_val | frac_val;
+    // frac_overflow does not catch the case where:
+    //  1. int is odd
+    //  2. frac_nbits is 0
+    //  3. frac_bytes is exactly half, e.g. "5" for decimal
+    // In this case, get_frac returns 0.5 rounded to even 0.0,
+    // as it does not have a way to know that int is odd.
+    if frac_overflow || (int_val.is_odd() && frac_nbits == 0 && frac_is_half(frac, radix)) {
+        let (new_val, new_overflow) = val.overflowing_add(1 << frac_nbits);
+        if new_overflow {
+            overflow = true;
+        }
+        val = new_val;
+    }
+    (neg, val, overflow)
+}
+
+impl<Frac: LeEqU128> AnyFixed<Frac> {
+    /// Parses a string slice containing decimal digits into the narrowest
+    /// fixed-point type with `Frac` fractional bits that can hold the
+    /// number.
+    ///
+    /// The widths are tried in increasing order, starting with the
+    /// narrowest width that has at least `Frac` bits. For each width the
+    /// unsigned type is tried before the signed type, so non-negative
+    /// numbers are always parsed into unsigned types. The fractional part
+    /// is rounded to `Frac` bits as in [`from_str`], with ties rounded to
+    /// even.
+    ///
+    /// An overflow error is returned if the number does not fit in any of
+    /// the 128-bit types.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::extra::U4, AnyFixed, FixedI16, FixedU16, FixedU8};
+    /// type Any = AnyFixed<U4>;
+    /// assert_eq!(Any::from_str_narrowest("12.5"), Ok(Any::U8(FixedU8::from_num(12.5))));
+    /// assert_eq!(Any::from_str_narrowest("300.25"), Ok(Any::U16(FixedU16::from_num(300.25))));
+    /// // −12.5 does not fit in FixedI8<U4>, which has a minimum of −8
+    /// assert_eq!(Any::from_str_narrowest("-12.5"), Ok(Any::I16(FixedI16::from_num(-12.5))));
+    /// assert!(Any::from_str_narrowest("1e3").is_err());
+    /// ```
+    ///
+    /// [`from_str`]: https://doc.rust-lang.org/nightly/std/str/trait.FromStr.html#tymethod.from_str
+    pub fn from_str_narrowest(src: &str) -> Result<AnyFixed<Frac>, ParseFixedError> {
+        let parse = parse_bounds(src.as_bytes(), 10)?;
+        let frac_nbits = Frac::U32;
+        macro_rules! try_width {
+            ($($nbits:expr, $U:ident($FixedU:ident, $BitsU:ident), $I:ident($FixedI:ident, $BitsI:ident);)*) => { $(
+                if frac_nbits <= $nbits {
+                    let (bits, overflow) = from_parse(parse, 10, $nbits, frac_nbits, false);
+                    if !overflow {
+                        return Ok(AnyFixed::$U($FixedU::from_bits(bits as $BitsU)));
+                    }
+                    let (bits, overflow) = from_parse(parse, 10, $nbits, frac_nbits, true);
+                    if !overflow {
+                        return Ok(AnyFixed::$I($FixedI::from_bits(bits as $BitsI)));
+                    }
+                }
+            )* };
+        }
+        try_width! {
+            8, U8(FixedU8, u8), I8(FixedI8, i8);
+            16, U16(FixedU16, u16), I16(FixedI16, i16);
+            32, U32(FixedU32, u32), I32(FixedI32, i32);
+            64, U64(FixedU64, u64), I64(FixedI64, i64);
+            128, U128(FixedU128, u128), I128(FixedI128, i128);
+        }
+        Err(ParseErrorKind::Overflow.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -870,6 +1469,7 @@ mod tests {
         fmt::Debug,
         format,
         string::{String, ToString},
+        vec::Vec,
     };
 
     #[test]
@@ -958,6 +1558,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hex_frac_matches_bin() {
+        let digits = b"0123456789abcdefFEDCBA9876543210F8000000000000000000000000000000001";
+        for len in 1..digits.len() {
+            let hex = &digits[..len];
+            let mut bin = std::vec::Vec::new();
+            for &byte in hex {
+                let val = unchecked_hex_digit(byte);
+                for shift in (0..4).rev() {
+                    bin.push(b'0' + ((val >> shift) & 1));
+                }
+            }
+            for &nbits in &[128, 127, 100, 64, 36, 32, 31, 4, 1] {
+                assert_eq!(
+                    hex_str_frac_to_bin::<u128>(hex, nbits),
+                    bin_str_frac_to_bin::<u128>(&bin, nbits),
+                    "{:?} {}",
+                    std::str::from_utf8(hex),
+                    nbits
+                );
+            }
+            for &nbits in &[64, 40, 33, 32, 16] {
+                assert_eq!(
+                    hex_str_frac_to_bin::<u64>(hex, nbits),
+                    bin_str_frac_to_bin::<u64>(&bin, nbits),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn frac_only_int_part() {
+        assert_ok::<U0F8>("00.5", 10, 0x80, false);
+        assert_ok::<U0F8>("3.5", 10, 0x80, true);
+        assert_ok::<U0F8>("F.8", 16, 0x80, true);
+        assert_ok::<I0F16>("-000.25", 10, -0x4000, false);
+        assert_ok::<I0F16>("-10.01", 2, -0x4000, true);
+        assert_ok::<U0F128>("0.1", 16, 1 << 124, false);
+        assert_ok::<U0F128>("1.1", 16, 1 << 124, true);
+    }
+
     #[test]
     fn check_dec_8() {
         let two_pow = 8f64.exp2();
@@ -1113,6 +1754,613 @@ mod tests {
         assert_eq!(kind, ParseErrorKind::InvalidDigit);
     }
 
+    #[test]
+    fn unsigned_sign_handling() {
+        macro_rules! check {
+            ($($Fix:ident),*) => { $(
+                for &radix in &[2, 8, 10, 16] {
+                    let parse = |s| <$Fix as FromStrRadix>::from_str_radix(s, radix);
+                    assert_eq!(parse("+1"), Ok($Fix::from_num(1)));
+                    assert_eq!(parse("+01"), Ok($Fix::from_num(1)));
+                    assert_eq!(parse("+1.0"), Ok($Fix::from_num(1)));
+                    assert_eq!(parse("+0"), Ok($Fix::from_num(0)));
+                    assert_eq!(parse("-0"), Ok($Fix::from_num(0)));
+                    assert_eq!(parse("-.0"), Ok($Fix::from_num(0)));
+                    assert_eq!(parse("-1"), Err(ParseErrorKind::Overflow.into()));
+                    assert_eq!(parse("+"), Err(ParseErrorKind::NoDigits.into()));
+                    assert_eq!(parse("-"), Err(ParseErrorKind::NoDigits.into()));
+                    assert_eq!(parse("+."), Err(ParseErrorKind::NoDigits.into()));
+                    assert_eq!(parse("++1"), Err(ParseErrorKind::InvalidDigit.into()));
+                    assert_eq!(parse("+-1"), Err(ParseErrorKind::InvalidDigit.into()));
+                    assert_eq!(parse("1+"), Err(ParseErrorKind::InvalidDigit.into()));
+                    assert_eq!(parse(".+1"), Err(ParseErrorKind::InvalidDigit.into()));
+                }
+            )* };
+        }
+        check! { U8F0, U7F1, U4F4, U16F0, U8F8, U32F0, U16F16, U64F0, U32F32, U128F0, U64F64 }
+    }
+
+    #[test]
+    fn gen_frac_matches_dec_hex() {
+        let decs: &[&[u8]] = &[
+            b"5",
+            b"25",
+            b"1",
+            b"9999",
+            b"00390625",
+            b"001953125",
+            b"0019531250000000000000000000000000000000000000001",
+            b"333333333333333333333333333333333333333333333333333333333333",
+            b"9999999999999999999999999999999999999999999999999999999999",
+            b"5000000000000000000000000000000000000000000000000000000000000001",
+        ];
+        for &dec in decs {
+            let s = format!("0.{}", std::str::from_utf8(dec).unwrap());
+            macro_rules! check {
+                ($($Fixed:ident),*) => { $(
+                    let (val, overflow) = $Fixed::overflowing_from_str(&s).unwrap();
+                    let nbits = $Fixed::FRAC_NBITS;
+                    let bits = u128::from(val.to_bits());
+                    // rounding up to one is an overflow for gen_str_frac_to_bin
+                    let parsed = if overflow || (nbits < 128 && bits >> nbits != 0) {
+                        None
+                    } else {
+                        Some(bits)
+                    };
+                    assert_eq!(gen_str_frac_to_bin(dec, 10, nbits), parsed, "{} {}", s, nbits);
+                )* };
+            }
+            check! { U0F8, U0F16, U0F32, U0F64, U0F128, U1F7, U1F127, U63F65, U31F97 }
+        }
+        let hexs: &[&[u8]] = &[
+            b"8",
+            b"18",
+            b"FF",
+            b"fedcba9876543210FEDCBA98765432108",
+            b"01",
+        ];
+        for &hex in hexs {
+            for &nbits in &[1, 4, 5, 8, 64, 127, 128] {
+                assert_eq!(
+                    gen_str_frac_to_bin(hex, 16, nbits),
+                    hex_str_frac_to_bin::<u128>(hex, nbits),
+                );
+            }
+        }
+    }
+
+    // Returns the exact decimal digits of numer / 2^shift, with
+    // trailing zeros trimmed.
+    fn exact_dec_frac(numer: u128, shift: u32) -> Vec<u8> {
+        // little-endian decimal digits of numer × 5^shift
+        let mut digits = Vec::new();
+        let mut n = numer;
+        while n != 0 {
+            digits.push((n % 10) as u8);
+            n /= 10;
+        }
+        for _ in 0..shift {
+            let mut carry = 0;
+            for d in digits.iter_mut() {
+                let prod = *d * 5 + carry;
+                *d = prod % 10;
+                carry = prod / 10;
+            }
+            if carry != 0 {
+                digits.push(carry);
+            }
+        }
+        digits.resize(shift as usize, 0);
+        let mut bytes: Vec<u8> = digits.iter().rev().map(|&d| b'0' + d).collect();
+        while bytes.last() == Some(&b'0') {
+            bytes.pop();
+        }
+        bytes
+    }
+
+    #[test]
+    fn dec_frac_exact_ties() {
+        let numers = [1, 3, 5, 0x55, 0xFF, 0x1234_5679, u128::MAX >> 1, u128::MAX];
+        for &numer in &numers {
+            macro_rules! check {
+                ($($I:ident),*) => { $(
+                    for &nbits in &[$I::NBITS, $I::NBITS - 1] {
+                        let shift = nbits + 1;
+                        let numer = if shift < 128 { numer & !(!0 << shift) } else { numer };
+                        let tie = exact_dec_frac(numer, shift);
+                        if tie.is_empty() {
+                            continue;
+                        }
+                        let mut above = tie.clone();
+                        above.extend_from_slice(b"0000000000000000000000000000001");
+                        let mut below = tie.clone();
+                        *below.last_mut().unwrap() -= 1;
+                        below.extend_from_slice(b"9999999999999999999999999999999");
+                        for bytes in &[tie, above, below] {
+                            assert_eq!(
+                                dec_str_frac_to_bin::<$I>(bytes, nbits).map(u128::from),
+                                gen_str_frac_to_bin(bytes, 10, nbits),
+                                "0.{} {}",
+                                std::str::from_utf8(bytes).unwrap(),
+                                nbits
+                            );
+                        }
+                    }
+                )* };
+            }
+            check! { u8, u16, u32, u64, u128 }
+        }
+    }
+
+    #[test]
+    fn narrowest() {
+        use crate::{
+            types::extra::{U0, U12, U128},
+            AnyFixed,
+        };
+        type Int = AnyFixed<U0>;
+        assert_eq!(
+            Int::from_str_narrowest("255"),
+            Ok(Int::U8(FixedU8::from_num(255)))
+        );
+        assert_eq!(
+            Int::from_str_narrowest("-0"),
+            Ok(Int::U8(FixedU8::from_num(0)))
+        );
+        assert_eq!(
+            Int::from_str_narrowest("256"),
+            Ok(Int::U16(FixedU16::from_num(256)))
+        );
+        assert_eq!(
+            Int::from_str_narrowest("-128"),
+            Ok(Int::I8(FixedI8::from_num(-128)))
+        );
+        assert_eq!(
+            Int::from_str_narrowest("-129"),
+            Ok(Int::I16(FixedI16::from_num(-129)))
+        );
+        // 255.5 is rounded to 256 (even)
+        assert_eq!(
+            Int::from_str_narrowest("255.5"),
+            Ok(Int::U16(FixedU16::from_num(256)))
+        );
+        let max = u128::MAX.to_string();
+        assert_eq!(
+            Int::from_str_narrowest(&max),
+            Ok(Int::U128(FixedU128::max_value()))
+        );
+        let min = i128::MIN.to_string();
+        assert_eq!(
+            Int::from_str_narrowest(&min),
+            Ok(Int::I128(FixedI128::min_value()))
+        );
+        let overflow = ParseFixedError {
+            kind: ParseErrorKind::Overflow,
+        };
+        assert_eq!(Int::from_str_narrowest(&format!("{}0", max)), Err(overflow));
+        assert_eq!(Int::from_str_narrowest(&format!("{}0", min)), Err(overflow));
+
+        // 8 bits are too few for 12 fractional bits
+        type Frac12 = AnyFixed<U12>;
+        let any = Frac12::from_str_narrowest("1").unwrap();
+        assert_eq!(any, Frac12::U16(FixedU16::from_num(1)));
+        assert_eq!((any.nbits(), any.is_signed()), (16, false));
+        let any = Frac12::from_str_narrowest("-8").unwrap();
+        assert_eq!(any, Frac12::I16(FixedI16::from_num(-8)));
+        assert_eq!((any.nbits(), any.is_signed()), (16, true));
+        assert_eq!(
+            Frac12::from_str_narrowest("-8.001"),
+            Ok(Frac12::I32(FixedI32::from_num(-8.001)))
+        );
+
+        type Frac128 = AnyFixed<U128>;
+        assert_eq!(
+            Frac128::from_str_narrowest("0.5"),
+            Ok(Frac128::U128(FixedU128::from_num(0.5)))
+        );
+        assert_eq!(
+            Frac128::from_str_narrowest("-0.5"),
+            Ok(Frac128::I128(FixedI128::from_num(-0.5)))
+        );
+        assert_eq!(Frac128::from_str_narrowest("1"), Err(overflow));
+        assert_eq!(
+            Frac128::from_str_narrowest("x"),
+            Err(ParseFixedError {
+                kind: ParseErrorKind::InvalidDigit,
+            })
+        );
+    }
+
+    #[test]
+    fn check_radix_36() {
+        // 1/3 = 0.1 base 3 ≈ 85.33 / 256
+        assert_ok::<U0F8>("0.1", 3, 85, false);
+        // 2/3 = 0.2 base 3 ≈ 170.67 / 256
+        assert_ok::<U0F8>("0.2", 3, 171, false);
+        // 1/2 = 0.3 base 6, tie rounded to even
+        assert_ok::<U8F0>("0.3", 6, 0, false);
+        assert_ok::<U8F0>("1.3", 6, 2, false);
+        assert_ok::<U8F0>("1.3000001", 6, 2, false);
+        assert_ok::<U8F0>("0.3000001", 6, 1, false);
+        // 1/2 − ε in base 3 is 0.111…
+        assert_ok::<U7F1>("0.1111111111111111111111111", 3, 1, false);
+        assert_ok::<U0F8>("0.zzz", 36, 0, true);
+        assert_ok::<I8F8>("-Z.I", 36, -(35 << 8) - 128, false);
+        assert_ok::<U8F8>("73.i", 36, 0xFF80, false);
+        assert_ok::<U8F8>("74.i", 36, 0x0080, true);
+        assert_ok::<U128F0>("3w5e11264sgsf", 36, u128::from(u64::MAX), false);
+        assert_ok::<U64F64>("3w5e11264sgsg", 36, 0, true);
+        assert_ok::<I16F16>("-AbC.d", 15, -((2427 << 16) + 56798), false);
+
+        let ParseFixedError { kind } = parse_bounds(b"12", 2).unwrap_err();
+        assert_eq!(kind, ParseErrorKind::InvalidDigit);
+        let ParseFixedError { kind } = parse_bounds(b"z", 35).unwrap_err();
+        assert_eq!(kind, ParseErrorKind::InvalidDigit);
+        assert!(parse_bounds(b"z", 36).is_ok());
+    }
+
+    #[test]
+    fn check_bounded() {
+        assert_eq!(
+            I8F8::bounded_from_str_radix("-1.5", 10, 4),
+            Ok(I8F8::from_num(-1.5))
+        );
+        let ParseFixedError { kind } = I8F8::bounded_from_str_radix("-1.50", 10, 4).unwrap_err();
+        assert_eq!(kind, ParseErrorKind::TooLong);
+        let ParseFixedError { kind } = I8F8::bounded_from_str_radix("1.x", 10, 4).unwrap_err();
+        assert_eq!(kind, ParseErrorKind::InvalidDigit);
+        let ParseFixedError { kind } = I8F8::bounded_from_str_radix("", 10, 0).unwrap_err();
+        assert_eq!(kind, ParseErrorKind::NoDigits);
+    }
+
+    #[test]
+    fn check_q_format() {
+        assert_eq!(I8F8::from_str_q("-1.5Q8.8"), Ok(I8F8::from_num(-1.5)));
+        assert_eq!(U0F128::from_str_q("0.25Q0.128"), Ok(U0F128::from_num(0.25)));
+        assert_eq!(I8F0::from_str_q("17Q8.0"), Ok(I8F0::from_num(17)));
+        // the number is parsed as usual, including rounding and overflow
+        assert_eq!(I4F4::from_str_q("0.1Q4.4"), Ok(I4F4::from_bits(2)));
+        let ParseFixedError { kind } = I4F4::from_str_q("8Q4.4").unwrap_err();
+        assert_eq!(kind, ParseErrorKind::Overflow);
+        let ParseFixedError { kind } = I4F4::from_str_q("Q4.4").unwrap_err();
+        assert_eq!(kind, ParseErrorKind::NoDigits);
+
+        for &src in &[
+            "1.5Q8.7",
+            "1.5Q7.8",
+            "1.5Q16.16",
+            "1.5Q08.9",
+            "1.5Q8.99999999999",
+        ] {
+            let ParseFixedError { kind } = I8F8::from_str_q(src).unwrap_err();
+            assert_eq!(kind, ParseErrorKind::FormatMismatch, "{}", src);
+        }
+        for &src in &[
+            "1.5",
+            "1.5Q8",
+            "1.5Q8.",
+            "1.5Q.8",
+            "1.5Q8.8.8",
+            "1.5Q+8.8",
+            "1.5q8.8",
+        ] {
+            let ParseFixedError { kind } = I8F8::from_str_q(src).unwrap_err();
+            assert_eq!(kind, ParseErrorKind::InvalidDigit, "{}", src);
+        }
+        // leading zeros in the tag are accepted
+        assert_eq!(I8F8::from_str_q("1.5Q08.008"), Ok(I8F8::from_num(1.5)));
+        // the default parser does not accept a tag
+        assert!("1.5Q8.8".parse::<I8F8>().is_err());
+    }
+
+    #[test]
+    fn check_grouped() {
+        assert_eq!(
+            I16F16::from_str_grouped("1,234.5"),
+            Ok(I16F16::from_num(1234.5))
+        );
+        assert_eq!(I16F16::from_str_grouped("-32,768"), Ok(I16F16::min_value()));
+        assert_eq!(
+            I16F16::from_str_grouped("+12,345"),
+            Ok(I16F16::from_num(12345))
+        );
+        assert_eq!(I16F16::from_str_grouped("999"), Ok(I16F16::from_num(999)));
+        assert_eq!(
+            I16F16::from_str_grouped("12345.25"),
+            Ok(I16F16::from_num(12345.25))
+        );
+        assert_eq!(I16F16::from_str_grouped(".5"), Ok(I16F16::from_num(0.5)));
+        assert_eq!(I16F16::from_str_grouped("0,001."), Ok(I16F16::from_num(1)));
+        let max = "340,282,366,920,938,463,463,374,607,431,768,211,455";
+        assert_eq!(U128F0::from_str_grouped(max), Ok(U128F0::max_value()));
+        // leading zeros do not count towards the limit on digits
+        let padded = "000,340,282,366,920,938,463,463,374,607,431,768,211,455.0";
+        assert_eq!(U128F0::from_str_grouped(padded), Ok(U128F0::max_value()));
+        // rounding is the same as for from_str
+        for &src in &["0.1", "1,000.03125", "7,999.99999", "0.000007629394531"] {
+            assert_eq!(
+                I16F16::from_str_grouped(src),
+                src.replace(",", "").parse::<I16F16>(),
+                "{}",
+                src
+            );
+        }
+
+        let overflow = &[
+            "32,768",
+            "-32,768.00001",
+            "340,282,366,920,938,463,463,374,607,431,768,211,456",
+            "1,000,000,000,000,000,000,000,000,000,000,000,000,000",
+        ];
+        for &src in overflow {
+            let ParseFixedError { kind } = I16F16::from_str_grouped(src).unwrap_err();
+            assert_eq!(kind, ParseErrorKind::Overflow, "{}", src);
+        }
+        let invalid = &[
+            "1,23", "1,2345", "1234,567", ",123", "123,", "1,,234", "1.234,5", "1,234.5,", "-,123",
+            "1,234-", "1, 234", "a,123",
+        ];
+        for &src in invalid {
+            let ParseFixedError { kind } = I16F16::from_str_grouped(src).unwrap_err();
+            assert_eq!(kind, ParseErrorKind::InvalidDigit, "{}", src);
+        }
+        for &src in &["", "-", "+", "."] {
+            let ParseFixedError { kind } = I16F16::from_str_grouped(src).unwrap_err();
+            assert_eq!(kind, ParseErrorKind::NoDigits, "{}", src);
+        }
+        let ParseFixedError { kind } = I16F16::from_str_grouped("1,234.5.6").unwrap_err();
+        assert_eq!(kind, ParseErrorKind::TooManyPoints);
+        // the default parser does not accept separators
+        assert!("1,234".parse::<I16F16>().is_err());
+    }
+
+    #[test]
+    fn check_integer_str() {
+        use crate::types::{I0F8, I128F0, I8F8, U0F128, U128F0, U8F8};
+        for i in i16::from(i8::MIN)..=i16::from(i8::MAX) {
+            let src = format!("{}", i);
+            let expected = I8F8::from_num(i);
+            assert_eq!(I8F8::from_integer_str(&src, 10), Ok(expected));
+            assert_eq!(Ok(expected), src.parse::<I8F8>());
+        }
+        for radix in 2..=36 {
+            for i in 0..=255u32 {
+                let src = core::char::from_digit(i % radix, radix)
+                    .unwrap()
+                    .to_string();
+                let expected = U8F8::from_num(i % radix);
+                assert_eq!(U8F8::from_integer_str(&src, radix), Ok(expected));
+            }
+        }
+        assert_eq!(
+            I8F8::from_integer_str("0000127", 10),
+            Ok(I8F8::from_num(127))
+        );
+        assert_eq!(I8F8::from_integer_str("-80", 16), Ok(I8F8::min_value()));
+        assert_eq!(
+            U8F8::from_integer_str("zz", 36),
+            Err(ParseErrorKind::Overflow.into())
+        );
+        assert_eq!(
+            U8F8::from_integer_str("-1", 10),
+            Err(ParseErrorKind::Overflow.into())
+        );
+        assert_eq!(U8F8::from_integer_str("-0", 10), Ok(U8F8::from_num(0)));
+        assert_eq!(
+            I8F8::from_integer_str("128", 10),
+            Err(ParseErrorKind::Overflow.into())
+        );
+        assert_eq!(
+            I8F8::from_integer_str("-129", 10),
+            Err(ParseErrorKind::Overflow.into())
+        );
+        assert_eq!(I0F8::from_integer_str("0", 10), Ok(I0F8::from_num(0)));
+        assert_eq!(
+            I0F8::from_integer_str("-1", 10),
+            Err(ParseErrorKind::Overflow.into())
+        );
+        assert_eq!(U0F128::from_integer_str("-0", 2), Ok(U0F128::from_num(0)));
+        assert_eq!(
+            U0F128::from_integer_str("1", 2),
+            Err(ParseErrorKind::Overflow.into())
+        );
+        let max = "340282366920938463463374607431768211455";
+        assert_eq!(U128F0::from_integer_str(max, 10), Ok(U128F0::max_value()));
+        let over = "340282366920938463463374607431768211456";
+        assert_eq!(
+            U128F0::from_integer_str(over, 10),
+            Err(ParseErrorKind::Overflow.into())
+        );
+        let min = "-170141183460469231731687303715884105728";
+        assert_eq!(I128F0::from_integer_str(min, 10), Ok(I128F0::min_value()));
+        let over = "170141183460469231731687303715884105728";
+        assert_eq!(
+            I128F0::from_integer_str(over, 10),
+            Err(ParseErrorKind::Overflow.into())
+        );
+        for &src in &["1.5", "1.", "1 ", "+-1", "--1", "1-", "12a"] {
+            let ParseFixedError { kind } = I8F8::from_integer_str(src, 10).unwrap_err();
+            assert_eq!(kind, ParseErrorKind::InvalidDigit, "{}", src);
+        }
+        for &src in &["", "-", "+"] {
+            let ParseFixedError { kind } = I8F8::from_integer_str(src, 10).unwrap_err();
+            assert_eq!(kind, ParseErrorKind::NoDigits, "{}", src);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "radix")]
+    fn check_integer_str_radix() {
+        let _ = I16F16::from_integer_str("1", 37);
+    }
+
+    #[test]
+    fn check_currency() {
+        let ok = |val: f64| Ok(I32F32::from_num(val));
+        assert_eq!(
+            I32F32::from_str_currency("$1,234.5", "$", '.', ','),
+            ok(1234.5)
+        );
+        assert_eq!(
+            I32F32::from_str_currency("€1.000,50", "€", ',', '.'),
+            ok(1000.5)
+        );
+        assert_eq!(
+            I32F32::from_str_currency("-€1.000,50", "€", ',', '.'),
+            ok(-1000.5)
+        );
+        assert_eq!(
+            I32F32::from_str_currency("+CHF1'000.25", "CHF", '.', '\''),
+            ok(1000.25)
+        );
+        // the symbol and the separators are optional
+        assert_eq!(
+            I32F32::from_str_currency("1000,5", "€", ',', '.'),
+            ok(1000.5)
+        );
+        assert_eq!(I32F32::from_str_currency("12", "", '.', ','), ok(12.0));
+        assert_eq!(
+            I32F32::from_str_currency("1 234 567", "", ',', ' '),
+            ok(1_234_567.0)
+        );
+        // rounding is the same as for from_str
+        assert_eq!(
+            I8F8::from_str_currency("£0.001953125", "£", '.', ','),
+            "0.001953125".parse::<I8F8>()
+        );
+
+        let invalid = &[
+            "$1,23.5",
+            "$12,345,67",
+            "$1.234,5",
+            "$$1",
+            "1$",
+            "$-1",
+            "€1",
+            "$ 1",
+            "$1,000.000,5",
+        ];
+        for &src in invalid {
+            let err = I32F32::from_str_currency(src, "$", '.', ',').unwrap_err();
+            assert_eq!(err, ParseErrorKind::InvalidDigit.into(), "{}", src);
+        }
+        let err = I32F32::from_str_currency("$1.5.5", "$", '.', ',').unwrap_err();
+        assert_eq!(err, ParseErrorKind::TooManyPoints.into());
+        for &src in &["$", "-$", "", "$."] {
+            let err = I32F32::from_str_currency(src, "$", '.', ',').unwrap_err();
+            assert_eq!(err, ParseErrorKind::NoDigits.into(), "{}", src);
+        }
+        let err = I8F8::from_str_currency("$128", "$", '.', ',').unwrap_err();
+        assert_eq!(err, ParseErrorKind::Overflow.into());
+    }
+
+    #[test]
+    #[should_panic(expected = "the decimal point and the group separator must be different")]
+    fn check_currency_same_separators() {
+        let _ = I32F32::from_str_currency("1,5", "", ',', ',');
+    }
+
+    #[test]
+    fn check_str_prefix() {
+        let ok = |val: f64, rest| Ok((I8F8::from_num(val), rest));
+        assert_eq!(I8F8::from_str_prefix("12"), ok(12.0, ""));
+        assert_eq!(I8F8::from_str_prefix("12.5abc"), ok(12.5, "abc"));
+        assert_eq!(I8F8::from_str_prefix("12."), ok(12.0, ""));
+        assert_eq!(I8F8::from_str_prefix("12..5"), ok(12.0, ".5"));
+        assert_eq!(I8F8::from_str_prefix("-.5-"), ok(-0.5, "-"));
+        assert_eq!(I8F8::from_str_prefix("+0012 34"), ok(12.0, " 34"));
+        assert_eq!(I8F8::from_str_prefix("1e5"), ok(1.0, "e5"));
+        assert_eq!(I8F8::from_str_prefix("1--1"), ok(1.0, "--1"));
+        for &s in &["", "-", "+", ".", "-.", "+.x", "x1", " 1", "--1"] {
+            let err = I8F8::from_str_prefix(s).unwrap_err();
+            assert_eq!(err, ParseErrorKind::NoDigits.into(), "{:?}", s);
+        }
+        let err = I8F8::from_str_prefix("128,").unwrap_err();
+        assert_eq!(err, ParseErrorKind::Overflow.into());
+        let err = U8F8::from_str_prefix("-1,").unwrap_err();
+        assert_eq!(err, ParseErrorKind::Overflow.into());
+        // the prefix gives the same result as parsing the whole number
+        for &s in &["0.1679", "-127.99999", "3.14159 ", "0.00195312500001;"] {
+            let (num, rest) = I8F8::from_str_prefix(s).unwrap();
+            assert_eq!(Ok(num), s[..s.len() - rest.len()].parse::<I8F8>());
+        }
+    }
+
+    #[test]
+    fn check_scaled_str() {
+        // compare with inserting the point into the string
+        for &digits in &[
+            "0", "5", "15", "1679", "-1234", "-0", "+0625", "99999", "000100",
+        ] {
+            for implied in 0..8 {
+                let (sign, abs) = match digits.strip_prefix('-') {
+                    Some(abs) => ("-", abs),
+                    None => ("", digits.trim_start_matches('+')),
+                };
+                let padded = format!("{:0>width$}", abs, width = implied + 1);
+                let (int, frac) = padded.split_at(padded.len() - implied);
+                let dotted = format!("{}{}.{}", sign, int, frac);
+                let implied = implied as u32;
+                assert_eq!(
+                    I8F8::from_scaled_str(digits, 10, implied),
+                    dotted.parse::<I8F8>()
+                );
+                assert_eq!(
+                    U8F8::from_scaled_str(digits, 10, implied),
+                    dotted.parse::<U8F8>()
+                );
+                assert_eq!(
+                    I1F7::from_scaled_str(digits, 10, implied),
+                    dotted.parse::<I1F7>()
+                );
+                let expected = dotted.parse::<I32F32>();
+                assert_eq!(I32F32::from_scaled_str(digits, 10, implied), expected);
+            }
+        }
+        assert_eq!(U8F8::from_scaled_str("8", 16, 1), Ok(U8F8::from_num(0.5)));
+        assert_eq!(
+            I16F16::from_scaled_str("-11", 2, 2),
+            Ok(I16F16::from_num(-0.75))
+        );
+        let expected = format!("0.{:0>38}", 1).parse::<U0F128>();
+        assert_eq!(U0F128::from_scaled_str("1", 10, 38), expected);
+        // with more than 128 leading fractional zeros the number rounds to zero
+        assert_eq!(
+            U0F128::from_scaled_str("9", 10, 130),
+            Ok(U0F128::from_num(0))
+        );
+        assert_eq!(
+            U8F8::from_scaled_str("", 10, 1),
+            Err(ParseErrorKind::NoDigits.into())
+        );
+        assert_eq!(
+            U8F8::from_scaled_str("-", 10, 1),
+            Err(ParseErrorKind::NoDigits.into())
+        );
+        assert_eq!(
+            U8F8::from_scaled_str("1.5", 10, 1),
+            Err(ParseErrorKind::InvalidDigit.into())
+        );
+        assert_eq!(
+            U8F8::from_scaled_str("12", 2, 1),
+            Err(ParseErrorKind::InvalidDigit.into())
+        );
+        assert_eq!(
+            U8F8::from_scaled_str("2560", 10, 1),
+            Err(ParseErrorKind::Overflow.into())
+        );
+        let long = "1".repeat(300);
+        assert_eq!(
+            U8F8::from_scaled_str(&long, 10, 200),
+            Err(ParseErrorKind::Overflow.into())
+        );
+        assert_eq!(U8F8::from_scaled_str(&long, 10, 500), Ok(U8F8::from_num(0)));
+        assert_eq!(
+            U8F8::from_scaled_str(&long, 10, 310),
+            Err(ParseErrorKind::TooLong.into())
+        );
+    }
+
     fn assert_ok<F>(s: &str, radix: u32, bits: F::Bits, overflow: bool)
     where
         F: Fixed + FromStrRadix<Err = ParseFixedError>,
@@ -2031,4 +3279,229 @@ mod tests {
             assert_eq!(I15F17::from_str(&fix_neg_str9).unwrap(), fix_neg);
         }
     }
+
+    #[test]
+    fn representable() {
+        for bits in i16::MIN..=i16::MAX {
+            let val = I8F8::from_bits(bits);
+            let int_abs = i32::from(bits).abs() >> 8;
+            let mut exact = format!("{}{}.", if bits < 0 { "-" } else { "" }, int_abs);
+            exact.extend(val.decimal_digits().map(|d| char::from(b'0' + d)));
+            assert!(I8F8::is_representable(&exact), "{}", exact);
+            exact.push_str("000");
+            assert!(I8F8::is_representable(&exact), "{}", exact);
+            exact.push('1');
+            assert!(!I8F8::is_representable(&exact), "{}", exact);
+        }
+        assert!(U8F8::is_representable("255.99609375"));
+        assert!(!U8F8::is_representable("255.998046875"));
+        assert!(!U8F8::is_representable("256"));
+        assert!(!U8F8::is_representable("-0.00390625"));
+        assert!(U8F8::is_representable("-0.0"));
+        assert!(I8F8::is_representable("-128"));
+        assert!(!I8F8::is_representable("-128.00390625"));
+        assert!(!I8F8::is_representable(""));
+        assert!(!I8F8::is_representable("1.x"));
+        // 0.99999 rounds to 1.0, whose fractional part is empty
+        assert!(!U4F4::is_representable("0.99999"));
+        assert!(U0F128::is_representable("0.5"));
+        assert!(!U0F128::is_representable("1"));
+    }
+
+    #[test]
+    fn rounded_direction() {
+        use core::cmp::Ordering;
+
+        // compare the parsed value against thousandths exactly
+        macro_rules! check {
+            ($($Fix:ident),*) => { $(
+                for thousandths in -130_000i32..=260_000 {
+                    let abs = thousandths.abs();
+                    let sign = if thousandths < 0 { "-" } else { "" };
+                    let s = format!("{}{}.{:03}", sign, abs / 1000, abs % 1000);
+                    let (val, ord) = match $Fix::from_str_rounded(&s) {
+                        Ok(res) => res,
+                        Err(_) => {
+                            assert!($Fix::from_str(&s).is_err(), "{}", s);
+                            continue;
+                        }
+                    };
+                    assert_eq!(Ok(val), $Fix::from_str(&s), "{}", s);
+                    let scaled = i64::from(val.to_bits()) * 1000;
+                    let exact = i64::from(thousandths) << $Fix::FRAC_NBITS;
+                    assert_eq!(ord, scaled.cmp(&exact), "{}", s);
+                }
+            )* };
+        }
+        check!(I8F0, I7F1, I5F3, I0F8, U8F0, U6F2, U0F8, I8F8, U7F9, I16F16);
+
+        assert_eq!(
+            U0F128::from_str_rounded("0.5"),
+            Ok((U0F128::from_num(0.5), Ordering::Equal))
+        );
+        assert_eq!(
+            I0F8::from_str_rounded("-0.5"),
+            Ok((I0F8::from_num(-0.5), Ordering::Equal))
+        );
+        assert_eq!(
+            U8F0::from_str_rounded("-0.4"),
+            Ok((U8F0::from_num(0), Ordering::Greater))
+        );
+        assert!(U8F0::from_str_rounded("255.5").is_err());
+        assert!(I8F8::from_str_rounded("x").is_err());
+    }
+
+    #[test]
+    fn saturating_radix() {
+        // rounding up at the boundary overflows and saturates
+        for &(src, radix) in &[("FF.FFF", 16), ("11111111.111111111", 2), ("377.7777", 8)] {
+            assert_eq!(
+                U8F8::from_str_radix(src, radix),
+                Err(ParseErrorKind::Overflow.into())
+            );
+            assert_eq!(
+                U8F8::saturating_from_str_radix(src, radix),
+                Ok(U8F8::max_value())
+            );
+        }
+        assert_eq!(
+            I8F8::saturating_from_str_radix("-80.0001", 16),
+            Ok(I8F8::from_num(-128))
+        );
+        assert_eq!(
+            I8F8::saturating_from_str_radix("-80.008", 16),
+            Ok(I8F8::min_value())
+        );
+        assert_eq!(
+            I8F8::saturating_from_str_radix("-80.01", 16),
+            Ok(I8F8::min_value())
+        );
+        assert_eq!(
+            I8F8::saturating_from_str_radix("7f.ff8", 16),
+            Ok(I8F8::max_value())
+        );
+        assert_eq!(
+            U8F8::saturating_from_str_radix("-0.1", 2),
+            Ok(U8F8::from_num(0))
+        );
+        assert_eq!(
+            U0F8::saturating_from_str_radix("z", 36),
+            Ok(U0F8::max_value())
+        );
+        // malformed input is still an error
+        assert_eq!(
+            U8F8::saturating_from_str_radix("FG", 16),
+            Err(ParseErrorKind::InvalidDigit.into())
+        );
+        assert_eq!(
+            I8F8::saturating_from_str_radix("-", 8),
+            Err(ParseErrorKind::NoDigits.into())
+        );
+    }
+
+    #[test]
+    fn parse_into() {
+        let mut slots = [I8F8::from_num(7); 4];
+        let srcs = ["1.5", "-0.25", "200", "x"];
+        let results: Vec<_> = slots
+            .iter_mut()
+            .zip(srcs.iter())
+            .map(|(slot, src)| I8F8::parse_into(src, slot))
+            .collect();
+        assert_eq!(
+            results,
+            [
+                Ok(()),
+                Ok(()),
+                Err(ParseErrorKind::Overflow.into()),
+                Err(ParseErrorKind::InvalidDigit.into())
+            ]
+        );
+        // failed slots keep their previous value
+        let seven = I8F8::from_num(7);
+        assert_eq!(
+            slots,
+            [I8F8::from_num(1.5), I8F8::from_num(-0.25), seven, seven]
+        );
+        let mut x = U0F128::from_num(0);
+        assert_eq!(U0F128::parse_into("0.5", &mut x), Ok(()));
+        assert_eq!(x, U0F128::from_num(0.5));
+    }
+
+    #[test]
+    fn repeating() {
+        // 2^128 / 3 and 2^128 / 7 rounded to nearest
+        let third = u128::MAX / 3;
+        let seventh = u128::MAX / 7 + 1;
+        assert_eq!(
+            U0F128::from_str_repeating("0.(3)"),
+            Ok(U0F128::from_bits(third))
+        );
+        assert_eq!(
+            U0F128::from_str_repeating("0.(142857)"),
+            Ok(U0F128::from_bits(seventh))
+        );
+        assert_eq!(
+            U0F128::from_str_repeating("0.(142857142857)"),
+            Ok(U0F128::from_bits(seventh))
+        );
+        assert_eq!(
+            U0F16::from_str_repeating(".(6)"),
+            Ok(U0F16::from_bits(43691))
+        );
+        assert_eq!(
+            I8F8::from_str_repeating("-1.1(6)"),
+            Ok(I8F8::from_num(-7.0 / 6.0))
+        );
+        assert_eq!(
+            U8F8::from_str_repeating("0.08(3)"),
+            Ok(U8F8::from_num(1.0 / 12.0))
+        );
+        // groups of zeros and nines are exact
+        assert_eq!(I8F8::from_str_repeating("2.5(0)"), Ok(I8F8::from_num(2.5)));
+        assert_eq!(I8F8::from_str_repeating("-2.(9)"), Ok(I8F8::from_num(-3)));
+        assert_eq!(U8F0::from_str_repeating("0.4(9)"), Ok(U8F0::from_num(0)));
+        assert_eq!(U8F0::from_str_repeating("1.4(99)"), Ok(U8F0::from_num(2)));
+        assert_eq!(U8F0::from_str_repeating("99.(9)"), Ok(U8F0::from_num(100)));
+        assert_eq!(
+            U8F0::from_str_repeating("255.(9)"),
+            Err(ParseErrorKind::Overflow.into())
+        );
+        // without a group this is the same as from_str
+        assert_eq!(
+            I8F8::from_str_repeating("12.375"),
+            Ok(I8F8::from_num(12.375))
+        );
+        assert_eq!(I8F8::from_str_repeating("12"), Ok(I8F8::from_num(12)));
+        // only the expanded group is limited in length
+        let long = std::format!("1.{}1", "0".repeat(300));
+        assert_eq!(I8F8::from_str_repeating(&long), Ok(I8F8::from_num(1)));
+        let long = std::format!("1.{}(3)", "0".repeat(300));
+        assert_eq!(
+            I8F8::from_str_repeating(&long),
+            Err(ParseErrorKind::TooLong.into())
+        );
+        for &src in &[
+            "0.(3", "0.3)", "0.()", "0.((3))", "0.(3)4", "1(3)", "(3)", "0.(-3)",
+        ] {
+            assert_eq!(
+                I8F8::from_str_repeating(src),
+                Err(ParseErrorKind::InvalidDigit.into()),
+                "{}",
+                src
+            );
+        }
+        assert_eq!(
+            I8F8::from_str_repeating("1.2.(3)"),
+            Err(ParseErrorKind::TooManyPoints.into())
+        );
+        assert_eq!(
+            I8F8::from_str_repeating("-"),
+            Err(ParseErrorKind::NoDigits.into())
+        );
+        assert_eq!(
+            I8F8::from_str_repeating("."),
+            Err(ParseErrorKind::NoDigits.into())
+        );
+    }
 }
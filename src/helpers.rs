@@ -16,10 +16,11 @@
 pub use crate::{float_helper::FloatHelper, int_helper::IntHelper};
 use crate::{
     types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
+    wide_div::WideDivRem,
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
     FixedU8,
 };
-use core::cmp::Ordering;
+use core::{cmp::Ordering, iter};
 
 // Unsigned can have 0 ≤ x < 2↑128, that is its msb can be 0 or 1.
 // Negative can have −2↑127 ≤ x < 0, that is its msb must be 1.
@@ -53,6 +54,7 @@ pub trait Sealed: Copy {
     fn private_to_float_helper(self) -> ToFloatHelper;
     fn private_saturating_from_float_helper(src: FromFloatHelper) -> Self;
     fn private_overflowing_from_float_helper(src: FromFloatHelper) -> (Self, bool);
+    fn private_checked_from_neg_abs(neg: bool, abs: u128) -> Option<Self>;
 }
 macro_rules! impl_sealed {
     ($Fixed:ident($LeEqU:ident, $Signedness:tt)) => {
@@ -143,6 +145,19 @@ macro_rules! impl_sealed {
                 };
                 (Self::from_bits(bits), conv.overflow || new_overflow)
             }
+            // Returns abs negated if neg, or None if it does not fit.
+            #[inline]
+            fn private_checked_from_neg_abs(neg: bool, abs: u128) -> Option<Self> {
+                if_signed_unsigned! {
+                    $Signedness,
+                    Self::checked_from_wide_bits(mul_shr_round(neg, abs, 1, 0)?, Self::FRAC_NBITS),
+                    if neg && abs != 0 {
+                        None
+                    } else {
+                        Self::checked_from_wide_bits(abs, Self::FRAC_NBITS)
+                    },
+                }
+            }
         }
     };
 }
@@ -157,3 +172,452 @@ impl_sealed! { FixedU16(LeEqU16, Unsigned) }
 impl_sealed! { FixedU32(LeEqU32, Unsigned) }
 impl_sealed! { FixedU64(LeEqU64, Unsigned) }
 impl_sealed! { FixedU128(LeEqU128, Unsigned) }
+
+pub fn mul_hi_lo(lhs: u128, rhs: u128) -> (u128, u128) {
+    const LO: u128 = !(!0 << 64);
+    let (lhs_hi, lhs_lo) = (lhs >> 64, lhs & LO);
+    let (rhs_hi, rhs_lo) = (rhs >> 64, rhs & LO);
+    let lhs_lo_rhs_lo = lhs_lo.wrapping_mul(rhs_lo);
+    let lhs_hi_rhs_lo = lhs_hi.wrapping_mul(rhs_lo);
+    let lhs_lo_rhs_hi = lhs_lo.wrapping_mul(rhs_hi);
+    let lhs_hi_rhs_hi = lhs_hi.wrapping_mul(rhs_hi);
+
+    let col01 = lhs_lo_rhs_lo;
+    let (col01_hi, col01_lo) = (col01 >> 64, col01 & LO);
+    let partial_col12 = lhs_hi_rhs_lo + col01_hi;
+    let (col12, carry_col3) = partial_col12.overflowing_add(lhs_lo_rhs_hi);
+    let (col12_hi, col12_lo) = (col12 >> 64, col12 & LO);
+    let ans01 = (col12_lo << 64) + col01_lo;
+    let ans23 = lhs_hi_rhs_hi + col12_hi + if carry_col3 { 1u128 << 64 } else { 0 };
+    (ans23, ans01)
+}
+
+// The constants have an error < 2^-128, so the angle conversions are
+// correctly rounded unless the operand uses nearly all 128 bits.
+
+// π/180 with 128 fractional bits
+pub const RAD_PER_DEG: u128 = 0x0477_D1A8_94A7_4E45_7076_2FB3_74A4_2E27;
+pub const RAD_PER_DEG_FRAC_NBITS: u32 = 128;
+// 180/π with 122 fractional bits
+pub const DEG_PER_RAD: u128 = 0xE52E_E0D3_1E0F_BDC3_0A97_537F_40D2_57D7;
+pub const DEG_PER_RAD_FRAC_NBITS: u32 = 122;
+
+// Returns round(abs × mul / 2^frac_nbits), negated if neg, or None on
+// overflow. Rounding is to the nearest, with ties rounded to even.
+pub fn mul_shr_round(neg: bool, abs: u128, mul: u128, frac_nbits: u32) -> Option<i128> {
+    let abs = mul_shr_round_abs(abs, mul, frac_nbits)?;
+    if neg {
+        if abs == 1 << 127 {
+            Some(i128::MIN)
+        } else if abs < 1 << 127 {
+            Some(-(abs as i128))
+        } else {
+            None
+        }
+    } else if abs < 1 << 127 {
+        Some(abs as i128)
+    } else {
+        None
+    }
+}
+
+// Returns round(abs × mul / 2^frac_nbits), or None on overflow.
+// Rounding is to the nearest, with ties rounded to even.
+pub fn mul_shr_round_abs(abs: u128, mul: u128, frac_nbits: u32) -> Option<u128> {
+    let (hi, lo) = mul_hi_lo(abs, mul);
+    let (quot, rem, half) = match frac_nbits {
+        0 => {
+            if hi != 0 {
+                return None;
+            }
+            (lo, 0, 1)
+        }
+        128 => (hi, lo, 1 << 127),
+        _ => {
+            if hi >> frac_nbits != 0 {
+                return None;
+            }
+            let quot = (hi << (128 - frac_nbits)) | (lo >> frac_nbits);
+            (quot, lo & !(!0 << frac_nbits), 1 << (frac_nbits - 1))
+        }
+    };
+    let round_up = rem > half || (rem == half && quot.is_odd());
+    quot.checked_add(u128::from(round_up))
+}
+
+// Returns round(a × b / c) for b ≤ c, so that the result is ≤ a.
+// Rounding is to the nearest, with ties rounded to even.
+pub fn mul_div_round(a: u128, b: u128, c: u128) -> u128 {
+    debug_assert!(b <= c && c != 0);
+    let ((_, quot), rem) = c.div_rem_from(mul_hi_lo(a, b));
+    // rem < c, so c - rem cannot overflow; and if rem ≠ 0, then
+    // quot < a × b / c ≤ a, so quot + 1 cannot overflow
+    let diff = c - rem;
+    let round_up = rem > diff || (rem == diff && quot.is_odd());
+    quot + u128::from(round_up)
+}
+
+// Returns round(abs × 2^shl / divisor) for shl ≤ 128, or None on
+// overflow. Rounding is to the nearest, with ties rounded to even.
+pub fn shl_div_round_abs(abs: u128, shl: u32, divisor: u128) -> Option<u128> {
+    mul_shl_div_round_abs(abs, 1, shl, divisor)
+}
+
+// Returns round(abs × mul × 2^shl / divisor) for shl ≤ 128, or None on
+// overflow. Rounding is to the nearest, with ties rounded to even.
+pub fn mul_shl_div_round_abs(abs: u128, mul: u128, shl: u32, divisor: u128) -> Option<u128> {
+    debug_assert!(shl <= 128 && divisor != 0);
+    let (hi, lo) = mul_hi_lo(abs, mul);
+    // if the dividend does not fit in 256 bits, the quotient cannot fit
+    // in 128 bits either
+    let dividend = match shl {
+        0 => (hi, lo),
+        128 => {
+            if hi != 0 {
+                return None;
+            }
+            (lo, 0)
+        }
+        _ => {
+            if hi >> (128 - shl) != 0 {
+                return None;
+            }
+            ((hi << shl) | (lo >> (128 - shl)), lo << shl)
+        }
+    };
+    let ((hi, quot), rem) = divisor.div_rem_from(dividend);
+    if hi != 0 {
+        return None;
+    }
+    // rem < divisor, so divisor - rem cannot overflow
+    let diff = divisor - rem;
+    let round_up = rem > diff || (rem == diff && quot.is_odd());
+    quot.checked_add(u128::from(round_up))
+}
+
+// Returns the square root of the 256-bit number hi:lo rounded to the
+// nearest, for hi < 2^126 so that the root fits in 127 bits. Ties
+// cannot occur, as the square of a number ending in a half is never an
+// integer.
+pub fn sqrt_hi_lo_round(hi: u128, lo: u128) -> u128 {
+    debug_assert!(hi >> 126 == 0);
+    let nbits = if hi != 0 {
+        256 - hi.leading_zeros()
+    } else {
+        128 - lo.leading_zeros()
+    };
+    if nbits == 0 {
+        return 0;
+    }
+    // Newton's method starting above the root: while x > floor(√n),
+    // (x + n / x) / 2 is smaller than x, and it never goes below floor(√n).
+    let mut root = 1u128 << ((nbits + 1) / 2);
+    loop {
+        // root ≥ √n, so n / root ≤ root and the quotient fits
+        let ((_, quot), _) = root.div_rem_from((hi, lo));
+        let next = (root + quot) / 2;
+        if next >= root {
+            break;
+        }
+        root = next;
+    }
+    // Round up if n > (r + 1/2)² = r² + r + 1/4, that is if n − r² > r.
+    let (sq_hi, sq_lo) = mul_hi_lo(root, root);
+    let (rem_lo, borrow) = lo.overflowing_sub(sq_lo);
+    let rem_hi = hi - sq_hi - u128::from(borrow);
+    if rem_hi != 0 || rem_lo > root {
+        root + 1
+    } else {
+        root
+    }
+}
+
+// Little-endian 64-bit limbs, enough for 384-bit numbers.
+type Limbs = [u64; 6];
+
+fn limbs_from_u128(val: u128) -> Limbs {
+    [val as u64, (val >> 64) as u64, 0, 0, 0, 0]
+}
+
+fn limbs_mul_u128(lhs: u128, rhs: u128) -> Limbs {
+    let (hi, lo) = mul_hi_lo(lhs, rhs);
+    [
+        lo as u64,
+        (lo >> 64) as u64,
+        hi as u64,
+        (hi >> 64) as u64,
+        0,
+        0,
+    ]
+}
+
+// a = a × mul + add, where the result must fit
+fn limbs_mul_add(a: &mut Limbs, mul: u64, add: u64) {
+    let mut carry = u128::from(add);
+    for limb in a.iter_mut() {
+        let prod = u128::from(*limb) * u128::from(mul) + carry;
+        *limb = prod as u64;
+        carry = prod >> 64;
+    }
+    debug_assert_eq!(carry, 0);
+}
+
+// a = a + b, where the result must fit
+fn limbs_add(a: &mut Limbs, b: &Limbs) {
+    let mut carry = false;
+    for (limb, &b) in a.iter_mut().zip(b.iter()) {
+        let (sum, c1) = limb.overflowing_add(b);
+        let (sum, c2) = sum.overflowing_add(u64::from(carry));
+        *limb = sum;
+        carry = c1 || c2;
+    }
+    debug_assert!(!carry);
+}
+
+// a = a − b, where a ≥ b
+fn limbs_sub(a: &mut Limbs, b: &Limbs) {
+    let mut borrow = false;
+    for (limb, &b) in a.iter_mut().zip(b.iter()) {
+        let (diff, b1) = limb.overflowing_sub(b);
+        let (diff, b2) = diff.overflowing_sub(u64::from(borrow));
+        *limb = diff;
+        borrow = b1 || b2;
+    }
+    debug_assert!(!borrow);
+}
+
+fn limbs_cmp(a: &Limbs, b: &Limbs) -> Ordering {
+    a.iter().rev().cmp(b.iter().rev())
+}
+
+// Returns the 64 bits of a starting at bit start, with zeros past the end.
+fn limbs_bits_from(a: &Limbs, start: u32) -> u64 {
+    let (index, offset) = ((start / 64) as usize, start % 64);
+    let limb = |i: usize| a.get(i).cloned().unwrap_or(0);
+    if offset == 0 {
+        limb(index)
+    } else {
+        (limb(index) >> offset) | (limb(index + 1) << (64 - offset))
+    }
+}
+
+// Returns round(a / 2^shift) for shift ≤ 128, or None if it does not fit
+// in a u128. Rounding is to the nearest, with ties rounded to even.
+fn limbs_shr_round(a: &Limbs, shift: u32) -> Option<u128> {
+    debug_assert!(shift <= 128);
+    let mut above = shift + 128;
+    while above < 384 {
+        if limbs_bits_from(a, above) != 0 {
+            return None;
+        }
+        above += 64;
+    }
+    let lo = u128::from(limbs_bits_from(a, shift));
+    let quot = (u128::from(limbs_bits_from(a, shift + 64)) << 64) | lo;
+    if shift == 0 {
+        return Some(quot);
+    }
+    let half_bit = shift - 1;
+    let half = (a[(half_bit / 64) as usize] >> (half_bit % 64)) & 1 != 0;
+    let below_half = (0..half_bit).step_by(64).any(|start| {
+        let len = half_bit - start;
+        let mask = if len >= 64 { !0 } else { !(!0 << len) };
+        limbs_bits_from(a, start) & mask != 0
+    });
+    let round_up = half && (below_half || quot.is_odd());
+    quot.checked_add(u128::from(round_up))
+}
+
+// Returns the sign and absolute value of round(Σ ± a × b / 2^frac_nbits)
+// for frac_nbits ≤ 128, where each item is (neg, a, b), or None if the
+// absolute value does not fit in a u128. Rounding is to the nearest, with
+// ties rounded to even, and happens only once at the end. Fewer than
+// 2^64 products below 2^256 each sum to less than 2^320, so the 384-bit
+// sums cannot overflow.
+pub fn sum_products_shr_round<I>(products: I, frac_nbits: u32) -> Option<(bool, u128)>
+where
+    I: Iterator<Item = (bool, u128, u128)>,
+{
+    let mut pos: Limbs = [0; 6];
+    let mut neg: Limbs = [0; 6];
+    for (is_neg, a, b) in products {
+        let prod = limbs_mul_u128(a, b);
+        limbs_add(if is_neg { &mut neg } else { &mut pos }, &prod);
+    }
+    let is_neg = limbs_cmp(&pos, &neg) == Ordering::Less;
+    let abs = if is_neg {
+        limbs_sub(&mut neg, &pos);
+        neg
+    } else {
+        limbs_sub(&mut pos, &neg);
+        pos
+    };
+    Some((is_neg, limbs_shr_round(&abs, frac_nbits)?))
+}
+
+// 1/sqrt(x) with 16 fractional bits for x at the midpoints of the
+// intervals [i/8, (i + 1)/8) for 8 ≤ i < 32.
+const RSQRT_SEED: [u16; 24] = [
+    63579, 60140, 57205, 54661, 52429, 50450, 48679, 47082, 45633, 44310, 43096, 41977, 40940,
+    39977, 39078, 38238, 37449, 36708, 36008, 35347, 34722, 34128, 33564, 33027,
+];
+
+// Returns the reciprocal square root of the fixed-point number with bits
+// abs and frac_nbits fractional bits, as the bits of a number with
+// frac_nbits fractional bits, or None if it does not fit in a u128.
+//
+// The initial estimate is read from a small table and is refined with
+// Newton's method in 128-bit fixed point, so no floating-point
+// arithmetic is needed. The result is not always correctly rounded, but
+// it is within a few units of the exact result; the error is largest
+// when the result uses all 128 bits and there are no guard bits left.
+pub fn rsqrt_round(abs: u128, frac_nbits: u32) -> Option<u128> {
+    debug_assert!(abs != 0 && frac_nbits <= 128);
+    // Shift abs into m in [2^125, 2^127), so that the number is
+    // (m / 2^125) × 2^(2 half_exp), with the shift chosen so that the
+    // exponent is even.
+    let frac_nbits = frac_nbits as i32;
+    let msb = 127 - abs.leading_zeros() as i32;
+    let mut shl = 125 - msb;
+    if (shl + frac_nbits) % 2 == 0 {
+        shl += 1;
+    }
+    let m = if shl >= 0 { abs << shl } else { abs >> -shl };
+    let half_exp = (125 - shl - frac_nbits) / 2;
+
+    // Estimate y = 1/sqrt(m / 2^125), which is in (1/2, 1], from the
+    // top bits of m. The estimate has a relative error of at most 2^-5,
+    // and y has 127 fractional bits.
+    let seed = RSQRT_SEED[(m >> 122) as usize - 8];
+    let mut y = u128::from(seed) << 111;
+    // Each step y ← y(3 − my²)/2 about doubles the number of correct
+    // bits. After a step y is never larger than the exact result, so
+    // 3 − my² stays positive.
+    for _ in 0..5 {
+        let y2 = shr_hi_lo(mul_hi_lo(y, y), 127);
+        let my2 = shr_hi_lo(mul_hi_lo(m, y2), 126);
+        y = shr_hi_lo(mul_hi_lo(y, (3 << 126) - my2), 127);
+    }
+
+    // The result is y × 2^(frac_nbits − half_exp − 127).
+    let shr = 127 - frac_nbits + half_exp;
+    if shr <= 0 {
+        if y.leading_zeros() < -shr as u32 {
+            return None;
+        }
+        Some(y << -shr)
+    } else if shr > 128 {
+        Some(0)
+    } else {
+        let half = y >> (shr - 1);
+        Some((half >> 1) + (half & 1))
+    }
+}
+
+// Returns the 256-bit number hi:lo shifted right by 0 < shr < 128, which
+// must fit in 128 bits.
+fn shr_hi_lo((hi, lo): (u128, u128), shr: u32) -> u128 {
+    debug_assert!(0 < shr && shr < 128 && hi >> shr == 0);
+    (hi << (128 - shr)) | (lo >> shr)
+}
+
+// Returns the cube root of abs × 2^shl rounded to the nearest, or None
+// if the rounded root does not fit in a u128. Ties cannot occur, as the
+// cube of a number ending in a half is never an integer.
+pub fn cbrt_shl_round(abs: u128, shl: u32) -> Option<u128> {
+    debug_assert!(shl <= 256);
+    // Binary digit-by-digit cube root, taking three bits of the 384-bit
+    // operand at a time. When y is the root so far and rem = n − y³ for
+    // the bits of n processed so far, the next root bit is set if
+    // (y + 1)³ − y³ = 3y(y + 1) + 1 ≤ rem.
+    if abs == 0 {
+        return Some(0);
+    }
+    // skip the groups above the most significant bit
+    let num_groups = (128 - abs.leading_zeros() + shl + 2) / 3;
+    let mut root = 0u128;
+    let mut rem: Limbs = [0; 6];
+    for group in (0..num_groups).rev() {
+        let mut bits = 0;
+        for bit in (group * 3..group * 3 + 3).rev() {
+            bits <<= 1;
+            if bit >= shl && bit - shl < 128 && (abs >> (bit - shl)) & 1 != 0 {
+                bits |= 1;
+            }
+        }
+        limbs_mul_add(&mut rem, 8, bits);
+        // root < 2^127 here, so it can be doubled, and then root + 1
+        // cannot overflow as root is even
+        root <<= 1;
+        let mut step = limbs_mul_u128(root, root + 1);
+        limbs_mul_add(&mut step, 3, 1);
+        if limbs_cmp(&rem, &step) != Ordering::Less {
+            limbs_sub(&mut rem, &step);
+            root += 1;
+        }
+    }
+    // Round up if n > (y + 1/2)³ = y³ + 3y²/2 + 3y/4 + 1/8, that is if
+    // 8 rem > 6y(2y + 1) + 1, or 4 rem > 3(2y² + y) as 8 rem is even.
+    let mut threshold = limbs_mul_u128(root, root);
+    limbs_mul_add(&mut threshold, 2, 0);
+    limbs_add(&mut threshold, &limbs_from_u128(root));
+    limbs_mul_add(&mut threshold, 3, 0);
+    limbs_mul_add(&mut rem, 4, 0);
+    if limbs_cmp(&rem, &threshold) == Ordering::Greater {
+        root.checked_add(1)
+    } else {
+        Some(root)
+    }
+}
+
+// Returns the continued-fraction terms of int + frac / 2^frac_nbits,
+// where 0 ≤ frac < 2^frac_nbits, using the Euclidean algorithm. The
+// terms after the first are positive. int is None if the integer part
+// does not fit, and the terms stop early if any term does not fit.
+pub fn continued_fraction(
+    int: Option<i128>,
+    frac: u128,
+    frac_nbits: u32,
+) -> impl Iterator<Item = i128> {
+    let mut first = int;
+    let mut done = int.is_none();
+    // the remaining value is 1 / (num / den), with den = 2^128 when
+    // den_is_2_128 is set, as it does not fit in a u128
+    let mut num = frac;
+    let mut den = 1u128.checked_shl(frac_nbits).unwrap_or(0);
+    let mut den_is_2_128 = frac_nbits == 128;
+    iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        if let Some(int) = first.take() {
+            return Some(int);
+        }
+        if num == 0 {
+            done = true;
+            return None;
+        }
+        let (quot, rem) = if den_is_2_128 {
+            let (quot, rem) = (u128::MAX / num, u128::MAX % num + 1);
+            if rem == num {
+                (quot.checked_add(1), 0)
+            } else {
+                (Some(quot), rem)
+            }
+        } else {
+            (Some(den / num), den % num)
+        };
+        match quot {
+            Some(quot) if quot <= i128::MAX as u128 => {
+                den = num;
+                num = rem;
+                den_is_2_128 = false;
+                Some(quot as i128)
+            }
+            _ => {
+                done = true;
+                None
+            }
+        }
+    })
+}
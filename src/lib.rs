@@ -75,11 +75,17 @@ The conversions supported cover the following cases.
   * Fixed-point numbers can be parsed from decimal strings using
     [`FromStr`], and from binary, octal and hexadecimal strings using
     the [`from_str_binary`], [`from_str_octal`] and [`from_str_hex`]
-    methods. The result is rounded to the nearest, with ties rounded
-    to even.
+    methods, or from strings in any radix up to 36 using the
+    [`from_str_radix`] method. The result is rounded to the nearest,
+    with ties rounded to even.
   * Fixed-point numbers can be converted to strings using [`Display`],
     [`Binary`], [`Octal`], [`LowerHex`] and [`UpperHex`]. The output
     is rounded to the nearest, with ties rounded to even.
+  * The fixed-point numbers have the same layout as the corresponding
+    types of the upstream [*fixed* crate], so that during a migration
+    values can be moved between the two crates bit for bit using
+    [`to_bits`] and [`from_bits`], or using [`From`] when the `fixed`
+    feature is enabled.
 
 ## Quick examples
 
@@ -146,22 +152,34 @@ it in your crate, add it as a dependency inside [*Cargo.toml*]:
 fixed = "0.5.4"
 ```
 
-The *fixed* crate requires rustc version 1.39.0 or later.
+The *fixed* crate requires rustc version 1.51.0 or later.
+The optional `fixed` and `num-rational` features need the newer rustc
+versions required by those crates.
 
 ## Optional features
 
-The *fixed* crate has four optional feature:
+The *fixed* crate has seven optional features:
 
- 1. `az`, disabled by default. This implements the cast traits
+ 1. `alloc`, disabled by default. This is for features that need
+    memory allocation: currently [`parse_all`], which returns a
+    vector.
+ 2. `az`, disabled by default. This implements the cast traits
     provided by the [*az* crate].
- 2. `f16`, disabled by default. This provides conversion to/from
+ 3. `f16`, disabled by default. This provides conversion to/from
     [`f16`] and [`bf16`]. This features requires the [*half* crate].
- 3. `serde`, disabled by default. This provides serialization support
+ 4. `fixed`, disabled by default. This implements [`From`] in both
+    directions between the fixed-point types and the corresponding
+    types of the upstream [*fixed* crate], bit for bit.
+ 5. `num-rational`, disabled by default. This provides
+    [`to_big_rational`], an exact conversion to an arbitrary-precision
+    rational number. This feature requires the [*num-rational* crate].
+ 6. `serde`, disabled by default. This provides serialization support
     for the fixed-point types. This feature requires the
     [*serde* crate].
- 4. `std`, disabled by default. This is for features that are not
+ 7. `std`, disabled by default. This is for features that are not
     possible under `no_std`: currently the implementation of the
-    [`Error`] trait for [`ParseFixedError`].
+    [`Error`] trait for [`ParseFixedError`]. This feature also enables
+    `alloc`.
 
 To enable features, you can add the dependency like this to
 [*Cargo.toml*]:
@@ -194,6 +212,7 @@ additional terms or conditions.
 [*fixed* crate]: https://crates.io/crates/fixed
 [*fixed-sqrt* crate]: https://crates.io/crates/fixed-sqrt
 [*half* crate]: https://crates.io/crates/half
+[*num-rational* crate]: https://crates.io/crates/num-rational
 [*serde* crate]: https://crates.io/crates/serde
 [*typenum* crate]: https://crates.io/crates/typenum
 [LICENSE-APACHE]: https://www.apache.org/licenses/LICENSE-2.0
@@ -230,10 +249,15 @@ additional terms or conditions.
 [`bf16`]: https://docs.rs/half/^1/half/struct.bf16.html
 [`checked_from_num`]: struct.FixedI32.html#method.checked_from_num
 [`f16`]: https://docs.rs/half/^1/half/struct.f16.html
+[`from_bits`]: struct.FixedI32.html#method.from_bits
 [`from_num`]: struct.FixedI32.html#method.from_num
 [`from_str_binary`]: struct.FixedI32.html#method.from_str_binary
 [`from_str_hex`]: struct.FixedI32.html#method.from_str_hex
 [`from_str_octal`]: struct.FixedI32.html#method.from_str_octal
+[`from_str_radix`]: struct.FixedI32.html#method.from_str_radix
+[`parse_all`]: struct.FixedI32.html#method.parse_all
+[`to_big_rational`]: struct.FixedI32.html#method.to_big_rational
+[`to_bits`]: struct.FixedI32.html#method.to_bits
 [`to_num`]: struct.FixedI32.html#method.to_num
 [const generics]: https://github.com/rust-lang/rust/issues/44580
 */
@@ -244,12 +268,16 @@ additional terms or conditions.
 #![cfg_attr(feature = "fail-on-warnings", deny(warnings))]
 #![allow(clippy::type_repetition_in_bounds)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 #[cfg(all(not(feature = "std"), test))]
 extern crate std;
 
 #[macro_use]
 mod macros;
 
+mod accumulator;
+mod any_fixed;
 mod arith;
 #[cfg(feature = "az")]
 mod cast;
@@ -257,31 +285,51 @@ mod cmp;
 pub mod consts;
 mod convert;
 mod display;
+mod fixed_range;
 mod float_helper;
 mod from_str;
 mod helpers;
 mod int_helper;
+mod rounding_mode;
 #[cfg(feature = "serde")]
 mod serdeize;
 pub mod traits;
 pub mod transcendental;
 pub mod types;
+#[cfg(feature = "fixed")]
+mod upstream;
 mod wide_div;
 mod wrapping;
 
+pub use crate::{
+    accumulator::{FixedAccumulator, OverflowAt, WideSum},
+    any_fixed::AnyFixed,
+    convert::ConversionError,
+    fixed_range::{FixedRange, SplitEvenly},
+    from_str::ParseFixedError,
+    rounding_mode::RoundingMode,
+    wrapping::Wrapping,
+};
 use crate::{
     arith::MulDivOverflow,
+    display::Mul10,
     from_str::FromStrRadix,
-    traits::{FromFixed, ToFixed},
-    types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
+    helpers::{FloatHelper, FromFloatHelper, IntHelper, Sealed},
+    traits::{Fixed, FromFixed, ToFixed},
+    types::extra::{IsLessOrEqual, LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8, True},
 };
-pub use crate::{from_str::ParseFixedError, wrapping::Wrapping};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
 use core::{
     cmp::Ordering,
+    fmt,
     hash::{Hash, Hasher},
+    iter,
     marker::PhantomData,
     mem,
 };
+#[cfg(feature = "num-rational")]
+use num_rational::BigRational;
 
 /// A prelude for users of the *fixed* crate.
 ///
@@ -311,19 +359,19 @@ mod macros_no_frac;
 #[macro_use]
 mod macros_frac;
 
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Compact, CompactAs, Decode, Encode, MaxEncodedLen};
 macro_rules! fixed {
     (
         $description:expr,
         $Fixed:ident($Inner:ty, $LeEqU:tt, $s_nbits:expr, $s_nbits_m4:expr),
         $nbytes:expr, $bytes_val:expr, $be_bytes:expr, $le_bytes:expr,
-        $UInner:ty, $Signedness:tt
+        $UFixed:ident($UInner:ty), $Signedness:tt
     ) => {
         fixed! {
             $description,
             $Fixed[stringify!($Fixed)]($Inner[stringify!($Inner)], $LeEqU, $s_nbits, $s_nbits_m4),
             $nbytes, $bytes_val, $be_bytes, $le_bytes,
-            $UInner, $Signedness
+            $UFixed[stringify!($UFixed)]($UInner), $Signedness
         }
     };
     (
@@ -332,7 +380,7 @@ macro_rules! fixed {
             $Inner:ty[$s_inner:expr], $LeEqU:tt, $s_nbits:expr, $s_nbits_m4:expr
         ),
         $nbytes:expr, $bytes_val:expr, $be_bytes:expr, $le_bytes:expr,
-        $UInner:ty, $Signedness:tt
+        $UFixed:ident[$s_ufixed:expr]($UInner:ty), $Signedness:tt
     ) => {
         comment! {
             $description,
@@ -340,8 +388,16 @@ macro_rules! fixed {
 
 Currently `Frac` is an [`Unsigned`] as provided by the
 [typenum crate]; it is planned to move to [const generics] when they
-are implemented by the Rust compiler.
-
+are implemented by the Rust compiler. `Frac` can be at most ", $s_nbits, ".
+",
+            if_unsigned_else_empty_str! {
+                $Signedness,
+                "
+Like unsigned integers, this type does not implement [`Neg`], so `-x`
+does not compile; use [`checked_neg`] or [`wrapping_neg`] instead.
+",
+            },
+            "
 # Examples
 
 ```rust
@@ -356,7 +412,15 @@ assert_eq!(two_point_75, 2.75);
 assert_eq!(two_point_75.to_string(), \"2.8\");
 ```
 
-[`Unsigned`]: https://docs.rs/typenum/^1.3/typenum/marker_traits/trait.Unsigned.html
+",
+            if_unsigned_else_empty_str! {
+                $Signedness,
+                "[`Neg`]: https://doc.rust-lang.org/nightly/core/ops/trait.Neg.html
+[`checked_neg`]: #method.checked_neg
+[`wrapping_neg`]: #method.wrapping_neg
+",
+            },
+            "[`Unsigned`]: https://docs.rs/typenum/^1.3/typenum/marker_traits/trait.Unsigned.html
 [const generics]: https://github.com/rust-lang/rust/issues/44580
 [typenum crate]: https://crates.io/crates/typenum
 ";
@@ -397,6 +461,31 @@ assert_eq!(two_point_75.to_string(), \"2.8\");
             }
         }
 
+        // SCALE compact encoding is only defined for unsigned integers
+        if_unsigned! {
+            $Signedness;
+            impl<Frac> CompactAs for $Fixed<Frac> {
+                type As = $Inner;
+
+                #[inline]
+                fn encode_as(&self) -> &$Inner {
+                    &self.bits
+                }
+
+                #[inline]
+                fn decode_from(bits: $Inner) -> Result<Self, codec::Error> {
+                    Ok(Self::from_bits(bits))
+                }
+            }
+
+            impl<Frac> From<Compact<$Fixed<Frac>>> for $Fixed<Frac> {
+                #[inline]
+                fn from(compact: Compact<$Fixed<Frac>>) -> Self {
+                    compact.0
+                }
+            }
+        }
+
         // inherent methods that do not require Frac bounds, some of which can thus be const
         fixed_no_frac! {
             $description,
@@ -408,7 +497,7 @@ assert_eq!(two_point_75.to_string(), \"2.8\");
         fixed_frac! {
             $description,
             $Fixed[$s_fixed]($Inner[$s_inner], $LeEqU, $s_nbits, $s_nbits_m4),
-            $UInner, $Signedness
+            $UFixed[$s_ufixed]($UInner), $Signedness
         }
     };
 }
@@ -417,19 +506,19 @@ fixed! {
     "An eight-bit fixed-point unsigned",
     FixedU8(u8, LeEqU8, "8", "4"),
     1, "0x12", "[0x12]", "[0x12]",
-    u8, Unsigned
+    FixedU8(u8), Unsigned
 }
 fixed! {
     "A 16-bit fixed-point unsigned",
     FixedU16(u16, LeEqU16, "16", "12"),
     2, "0x1234", "[0x12, 0x34]", "[0x34, 0x12]",
-    u16, Unsigned
+    FixedU16(u16), Unsigned
 }
 fixed! {
     "A 32-bit fixed-point unsigned",
     FixedU32(u32, LeEqU32, "32", "28"),
     4, "0x1234_5678", "[0x12, 0x34, 0x56, 0x78]", "[0x78, 0x56, 0x34, 0x12]",
-    u32, Unsigned
+    FixedU32(u32), Unsigned
 }
 fixed! {
     "A 64-bit fixed-point unsigned",
@@ -437,7 +526,7 @@ fixed! {
     8, "0x1234_5678_9ABC_DEF0",
     "[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0]",
     "[0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12]",
-    u64, Unsigned
+    FixedU64(u64), Unsigned
 }
 fixed! {
     "A 128-bit fixed-point unsigned",
@@ -447,25 +536,25 @@ fixed! {
      0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0]",
     "[0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12, \
      0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12]",
-    u128, Unsigned
+    FixedU128(u128), Unsigned
 }
 fixed! {
     "An eight-bit fixed-point signed",
     FixedI8(i8, LeEqU8, "8", "4"),
     1, "0x12", "[0x12]", "[0x12]",
-    u8, Signed
+    FixedU8(u8), Signed
 }
 fixed! {
     "A 16-bit fixed-point signed",
     FixedI16(i16, LeEqU16, "16", "12"),
     2, "0x1234", "[0x12, 0x34]", "[0x34, 0x12]",
-    u16, Signed
+    FixedU16(u16), Signed
 }
 fixed! {
     "A 32-bit fixed-point signed",
     FixedI32(i32, LeEqU32, "32", "28"),
     4, "0x1234_5678", "[0x12, 0x34, 0x56, 0x78]", "[0x78, 0x56, 0x34, 0x12]",
-    u32, Signed
+    FixedU32(u32), Signed
 }
 fixed! {
     "A 64-bit fixed-point signed",
@@ -473,7 +562,7 @@ fixed! {
     8, "0x1234_5678_9ABC_DEF0",
     "[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0]",
     "[0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12]",
-    u64, Signed
+    FixedU64(u64), Signed
 }
 fixed! {
     "A 128-bit fixed-point signed",
@@ -483,7 +572,7 @@ fixed! {
      0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0]",
     "[0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12, \
      0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12]",
-    u128, Signed
+    FixedU128(u128), Signed
 }
 
 #[cfg(test)]
@@ -502,6 +591,59 @@ mod tests {
         I64F64::type_info();
     }
 
+    #[test]
+    fn compact_encoding() {
+        use crate::types::{U0F8, U64F64, U8F8};
+        use codec::{Compact, Decode, Encode};
+        use std::vec::Vec;
+
+        // small bits take a single byte, large bits take more bytes
+        // than the plain encoding
+        for &(bits, len) in &[(0, 1), (63, 1), (64, 2), (u32::MAX, 5)] {
+            let encoded = Compact(U16F16::from_bits(bits)).encode();
+            assert_eq!(encoded.len(), len);
+            let decoded = Compact::<U16F16>::decode(&mut &encoded[..]).unwrap();
+            assert_eq!(decoded.0, U16F16::from_bits(bits));
+        }
+        assert_eq!(Compact(U0F8::max_value()).encode().len(), 2);
+        assert_eq!(Compact(U8F8::from_bits(1)).encode().len(), 1);
+        for &(val, len) in &[(U64F64::from_num(1), 10), (U64F64::max_value(), 17)] {
+            let encoded = Compact(val).encode();
+            assert_eq!(encoded.len(), len);
+            assert_eq!(Compact::<U64F64>::decode(&mut &encoded[..]).unwrap().0, val);
+        }
+
+        // the compact encoding is that of the bits
+        let val = U16F16::from_num(1.5);
+        assert_eq!(Compact(val).encode(), Compact(val.to_bits()).encode());
+
+        #[derive(Encode, Decode, PartialEq, Debug)]
+        struct Stored {
+            #[codec(compact)]
+            balance: U64F64,
+        }
+        let stored = Stored {
+            balance: U64F64::from_bits(5),
+        };
+        let encoded: Vec<u8> = stored.encode();
+        assert_eq!(encoded.len(), 1);
+        assert_eq!(Stored::decode(&mut &encoded[..]), Ok(stored));
+    }
+
+    #[test]
+    fn default_is_zero() {
+        #[derive(Default)]
+        struct Config {
+            gain: I16F16,
+            offset: U16F16,
+            scale: I0F32,
+        }
+        let config = Config::default();
+        assert_eq!(config.gain.to_bits(), 0);
+        assert_eq!(config.offset.to_bits(), 0);
+        assert_eq!(config.scale.to_bits(), 0);
+    }
+
     #[test]
     fn rounding_signed() {
         // -0.5
@@ -1083,4 +1225,1642 @@ mod tests {
             (U16F16::from_num(4), false)
         );
     }
+
+    #[test]
+    fn rescale_from() {
+        use crate::types::{
+            extra::{U0, U12, U16, U28, U8},
+            I16F0, I28F4, U16F0, U8F8,
+        };
+
+        // −1.25 = −0b1.01 with 4 and 12 fractional bits
+        assert_eq!(I28F4::rescale_from::<U12>(-0x1400), I28F4::from_bits(-0x14));
+        // −0b1.0001_1 rounds to even −0b1.0010
+        assert_eq!(I28F4::rescale_from::<U12>(-0x1180), I28F4::from_bits(-0x12));
+        // −0b1.0010_1 rounds to even −0b1.0010
+        assert_eq!(I28F4::rescale_from::<U12>(-0x1280), I28F4::from_bits(-0x12));
+        assert_eq!(I28F4::rescale_from::<U0>(-3), I28F4::from_num(-3));
+        assert_eq!(I28F4::rescale_from::<U28>(1 << 27), I28F4::from_num(0.5));
+
+        // all bits are fractional in source
+        assert_eq!(U16F0::rescale_from::<U16>(0x8000), 0);
+        assert_eq!(U16F0::rescale_from::<U16>(0x8001), 1);
+        assert_eq!(I16F0::rescale_from::<U16>(-0x8000), 0);
+        assert_eq!(I16F0::rescale_from::<U16>(-0x4000), 0);
+        assert_eq!(I16F0::rescale_from::<U16>(-0x7fff), 0);
+        assert_eq!(I16F0::rescale_from::<U16>(0x7fff), 0);
+        assert_eq!(U8F8::rescale_from::<U8>(0xffff), U8F8::max_value());
+    }
+
+    #[test]
+    fn to_scaled() {
+        use crate::types::{I0F128, I128F0, I64F64, U0F128, U128F0};
+
+        assert_eq!(I64F64::from_num(0.25).to_scaled(10_000), Some(2500));
+        assert_eq!(I64F64::from_num(-0.375).to_scaled(4), Some(-2));
+        assert_eq!(I64F64::from_num(-0.625).to_scaled(4), Some(-2));
+        assert_eq!(I128F0::min_value().to_scaled(1), Some(i128::MIN));
+        assert_eq!(I128F0::min_value().to_scaled(2), None);
+        assert_eq!(I128F0::max_value().to_scaled(1), Some(i128::MAX));
+        assert_eq!(U128F0::max_value().to_scaled(1), None);
+        assert_eq!(I0F128::min_value().to_scaled(u128::MAX), Some(i128::MIN));
+        assert_eq!(U0F128::max_value().to_scaled(1 << 126), Some(1 << 126));
+        // 2^127 − 0.5 is rounded to 2^127 (even), which overflows
+        assert_eq!(U0F128::max_value().to_scaled(1 << 127), None);
+        assert_eq!(U0F128::max_value().to_scaled(u128::MAX), None);
+    }
+
+    #[test]
+    fn interpolate() {
+        use crate::types::{I128F0, I4F4, U0F8, U128F0};
+
+        assert_eq!(I4F4::interpolate(&[], I4F4::from_num(0), true), None);
+        let single = [(I4F4::from_num(1), I4F4::from_num(-2))];
+        assert_eq!(
+            I4F4::interpolate(&single, I4F4::from_num(1), false),
+            Some(I4F4::from_num(-2))
+        );
+        assert_eq!(I4F4::interpolate(&single, I4F4::from_num(0), false), None);
+        assert_eq!(
+            I4F4::interpolate(&single, I4F4::from_num(0), true),
+            Some(I4F4::from_num(-2))
+        );
+        assert_eq!(
+            I4F4::interpolate(&single, I4F4::from_num(2), true),
+            Some(I4F4::from_num(-2))
+        );
+
+        // full range in both input and output
+        let table = [
+            (I4F4::min_value(), I4F4::max_value()),
+            (I4F4::max_value(), I4F4::min_value()),
+        ];
+        assert_eq!(
+            I4F4::interpolate(&table, I4F4::min_value(), false),
+            Some(I4F4::max_value())
+        );
+        assert_eq!(
+            I4F4::interpolate(&table, I4F4::max_value(), false),
+            Some(I4F4::min_value())
+        );
+        // 7.9375 − 15.9375 × 8/15.9375 = −0.0625
+        assert_eq!(
+            I4F4::interpolate(&table, I4F4::from_num(0), false),
+            Some(I4F4::from_num(-0.0625))
+        );
+
+        let table = [
+            (U0F8::from_bits(0), U0F8::from_bits(0)),
+            (U0F8::from_bits(4), U0F8::from_bits(1)),
+        ];
+        // 0.5 is rounded to 0 (even), 1.5 is rounded to 2 (even)
+        assert_eq!(
+            U0F8::interpolate(&table, U0F8::from_bits(2), false),
+            Some(U0F8::from_bits(0))
+        );
+        assert_eq!(
+            U0F8::interpolate(&table, U0F8::from_bits(3), false),
+            Some(U0F8::from_bits(1))
+        );
+        let table = [
+            (U0F8::from_bits(0), U0F8::from_bits(0)),
+            (U0F8::from_bits(4), U0F8::from_bits(3)),
+        ];
+        assert_eq!(
+            U0F8::interpolate(&table, U0F8::from_bits(2), false),
+            Some(U0F8::from_bits(2))
+        );
+
+        let table = [
+            (I128F0::min_value(), I128F0::min_value()),
+            (I128F0::max_value(), I128F0::max_value()),
+        ];
+        for &x in &[i128::MIN, -1, 0, 1, i128::MAX] {
+            let x = I128F0::from_bits(x);
+            assert_eq!(I128F0::interpolate(&table, x, false), Some(x));
+        }
+        let table = [
+            (U128F0::from_num(0), U128F0::max_value()),
+            (U128F0::max_value(), U128F0::from_num(0)),
+        ];
+        assert_eq!(
+            U128F0::interpolate(&table, U128F0::from_num(1), false),
+            Some(U128F0::max_value() - U128F0::from_num(1))
+        );
+    }
+
+    #[test]
+    fn round_to_int() {
+        use crate::types::{I0F128, I128F0, I8F8, U0F128, U128F0, U8F8};
+        for bits in i16::MIN..=i16::MAX {
+            let f = I8F8::from_bits(bits);
+            let expected = f.to_num::<f64>().round();
+            assert_eq!(f64::from(f.round_to_i32()), expected, "{}", f);
+            assert_eq!(f.round_to_i64() as f64, expected, "{}", f);
+            let u = U8F8::from_bits(bits as u16);
+            assert_eq!(
+                f64::from(u.round_to_i32()),
+                u.to_num::<f64>().round(),
+                "{}",
+                u
+            );
+        }
+        assert_eq!(I0F128::min_value().round_to_i32(), -1);
+        assert_eq!(I0F128::max_value().round_to_i64(), 0);
+        assert_eq!(U0F128::max_value().round_to_i32(), 1);
+        assert_eq!(U0F128::from_bits(1 << 127).round_to_i64(), 1);
+        assert_eq!(U0F128::from_bits((1 << 127) - 1).round_to_i64(), 0);
+        assert_eq!(I128F0::min_value().round_to_i64(), i64::MIN);
+        assert_eq!(I128F0::from_num(i64::MIN).round_to_i64(), i64::MIN);
+        assert_eq!(I128F0::from_num(i64::MAX).round_to_i64(), i64::MAX);
+        assert_eq!(I128F0::from_num(i32::MIN).round_to_i32(), i32::MIN);
+        assert_eq!(
+            I128F0::from_num(i64::from(i32::MIN) - 1).round_to_i32(),
+            i32::MIN
+        );
+        assert_eq!(U128F0::max_value().round_to_i64(), i64::MAX);
+    }
+
+    #[test]
+    fn min_positive() {
+        use crate::types::{I0F8, I128F0, I8F8, U0F128, U8F0};
+        assert_eq!(I8F8::MIN_POSITIVE.to_num::<f64>(), 1.0 / 256.0);
+        assert!(I8F8::MIN_POSITIVE > I8F8::min_value());
+        assert_eq!(I0F8::MIN_POSITIVE.to_bits(), 1);
+        assert_eq!(U0F128::MIN_POSITIVE.to_bits(), 1);
+        assert_eq!(U8F0::MIN_POSITIVE, 1);
+        assert_eq!(I128F0::MIN_POSITIVE, 1);
+        assert_ne!(U8F0::MIN_POSITIVE, U8F0::min_value());
+    }
+
+    #[test]
+    fn wide_bits() {
+        use crate::types::{I0F128, I128F0, I8F8, U0F128, U128F0, U8F8};
+        for bits in i16::MIN..=i16::MAX {
+            let f = I8F8::from_bits(bits);
+            assert_eq!(f.to_wide_bits(), (i128::from(bits), 8));
+            assert_eq!(I8F8::from_wide_bits(i128::from(bits), 8), f);
+            let u = U8F8::from_bits(bits as u16);
+            assert_eq!(U8F8::from_wide_bits(u.to_wide_bits().0, 8), u);
+        }
+        // compare with the rounding of from_num for exact f64 values
+        for wide in -(1i32 << 18)..(1 << 18) {
+            for &scale in &[0, 3, 8, 11] {
+                let exact = f64::from(wide) / f64::from(1 << scale);
+                let expected = I8F8::checked_from_num(exact);
+                assert_eq!(
+                    I8F8::checked_from_wide_bits(i128::from(wide), scale),
+                    expected
+                );
+                if wide >= 0 {
+                    let expected = U8F8::checked_from_num(exact);
+                    let got = U8F8::checked_from_wide_bits(wide as u128, scale);
+                    assert_eq!(got, expected);
+                }
+            }
+        }
+        assert_eq!(
+            I128F0::checked_from_wide_bits(i128::MIN, 0),
+            Some(I128F0::min_value())
+        );
+        let half_min = I128F0::from_num(i128::MIN / 2);
+        assert_eq!(I128F0::checked_from_wide_bits(i128::MIN, 1), Some(half_min));
+        assert_eq!(
+            I128F0::checked_from_wide_bits(1, 200),
+            Some(I128F0::from_num(0))
+        );
+        assert_eq!(
+            I128F0::checked_from_wide_bits(i128::MIN, 500),
+            Some(I128F0::from_num(0))
+        );
+        assert_eq!(
+            U128F0::checked_from_wide_bits(u128::MAX, 128),
+            Some(U128F0::from_num(1))
+        );
+        assert_eq!(
+            U128F0::checked_from_wide_bits(u128::MAX, 129),
+            Some(U128F0::from_num(0))
+        );
+        assert_eq!(
+            I0F128::checked_from_wide_bits(0, 0),
+            Some(I0F128::from_num(0))
+        );
+        assert_eq!(I0F128::checked_from_wide_bits(1, 0), None);
+        assert_eq!(
+            I0F128::checked_from_wide_bits(-1, 1),
+            Some(I0F128::from_num(-0.5))
+        );
+        assert_eq!(
+            U0F128::checked_from_wide_bits(1, 1),
+            Some(U0F128::from_num(0.5))
+        );
+        assert_eq!(U0F128::checked_from_wide_bits(1, 0), None);
+        assert_eq!(
+            U0F128::checked_from_wide_bits(u128::MAX, 128),
+            Some(U0F128::max_value())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn from_wide_bits_overflow() {
+        let _ = crate::types::I8F8::from_wide_bits(128 << 8, 8);
+    }
+
+    #[test]
+    fn clamp01() {
+        use crate::types::{I0F8, I1F7, I2F6, I8F8, U0F8, U1F7, U8F8};
+        for bits in i16::MIN..=i16::MAX {
+            let f = I8F8::from_bits(bits);
+            let expected = f.to_num::<f64>().clamp(0.0, 1.0);
+            assert_eq!(f.clamp01().to_num::<f64>(), expected);
+            let u = U8F8::from_bits(bits as u16);
+            assert_eq!(u.clamp01().to_num::<f64>(), u.to_num::<f64>().min(1.0));
+        }
+        for bits in i8::MIN..=i8::MAX {
+            // one is not representable, so max is used instead
+            let f = I0F8::from_bits(bits);
+            assert_eq!(f.clamp01(), f.max(I0F8::from_num(0)));
+            let f = I1F7::from_bits(bits);
+            assert_eq!(f.clamp01(), f.max(I1F7::from_num(0)));
+            let u = U0F8::from_bits(bits as u8);
+            assert_eq!(u.clamp01(), u);
+        }
+        assert_eq!(I2F6::max_value().clamp01(), 1);
+        assert_eq!(U1F7::max_value().clamp01(), 1);
+    }
+
+    #[test]
+    fn fixed_range() {
+        use crate::{
+            types::{I0F8, I8F8, U0F8, U8F8},
+            FixedRange,
+        };
+        use std::vec::Vec;
+
+        let range = FixedRange::new(I8F8::from_num(-126), -I8F8::from_num(1), 10);
+        assert_eq!(range.size_hint(), (1, Some(10)));
+        let v: Vec<I8F8> = range.collect();
+        assert_eq!(v, [-126, -127, -128]);
+        let mut range = FixedRange::new(U8F8::from_num(0), U8F8::from_num(0), 2);
+        assert_eq!(range.next(), Some(U8F8::from_num(0)));
+        assert_eq!(range.next(), Some(U8F8::from_num(0)));
+        assert_eq!(range.next(), None);
+        assert_eq!(
+            FixedRange::new(U8F8::from_num(1), U8F8::from_num(1), 0).count(),
+            0
+        );
+
+        // every pair of end points and a few lengths over the whole range
+        for start in i8::MIN..=i8::MAX {
+            for end in i8::MIN..=i8::MAX {
+                let (start, end) = (I0F8::from_bits(start), I0F8::from_bits(end));
+                for n in 0..6 {
+                    let v: Vec<I0F8> = I0F8::linspace(start, end, n).collect();
+                    assert_eq!(v.len(), n);
+                    if n > 0 {
+                        assert_eq!(v[0], start);
+                    }
+                    if n > 1 {
+                        assert_eq!(v[n - 1], end);
+                    }
+                    let ascending = start <= end;
+                    for pair in v.windows(2) {
+                        assert!(if ascending {
+                            pair[0] <= pair[1]
+                        } else {
+                            pair[0] >= pair[1]
+                        });
+                    }
+                }
+            }
+        }
+        let v: Vec<U0F8> = U0F8::linspace(U0F8::max_value(), U0F8::from_num(0), 2).collect();
+        assert_eq!(v, [U0F8::max_value(), U0F8::from_num(0)]);
+        let v: Vec<U0F8> = U0F8::linspace(U0F8::from_num(0), U0F8::max_value(), 256).collect();
+        assert!(v.iter().enumerate().all(|(i, x)| x.to_bits() as usize == i));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn linspace_unsigned_descending() {
+        use crate::types::U8F8;
+        let _ = U8F8::linspace(U8F8::from_num(1), U8F8::from_num(0), 3);
+    }
+
+    #[test]
+    fn split_evenly() {
+        use crate::types::{I0F128, I8F8, U128F0, U8F8};
+        use std::vec::Vec;
+        for bits in i16::MIN..=i16::MAX {
+            let total = I8F8::from_bits(bits);
+            for &n in &[1u32, 2, 3, 7, 256, 300] {
+                let v: Vec<I8F8> = total.split_evenly_iter(n).collect();
+                assert_eq!(v.len(), n as usize);
+                let sum: i32 = v.iter().map(|x| i32::from(x.to_bits())).sum();
+                assert_eq!(sum, i32::from(bits));
+                let first = i32::from(v[0].to_bits());
+                let last = i32::from(v[v.len() - 1].to_bits());
+                assert!((first - last).abs() <= 1);
+                assert!(first.abs() >= last.abs());
+            }
+        }
+        for bits in 0..=u16::MAX {
+            let total = U8F8::from_bits(bits);
+            let v: Vec<U8F8> = total.split_evenly_iter(3).collect();
+            let sum: u32 = v.iter().map(|x| u32::from(x.to_bits())).sum();
+            assert_eq!(sum, u32::from(bits));
+        }
+        let v: Vec<I0F128> = I0F128::min_value().split_evenly_iter(1).collect();
+        assert_eq!(v, [I0F128::min_value()]);
+        let v: Vec<I0F128> = I0F128::min_value().split_evenly_iter(2).collect();
+        assert_eq!(v, [I0F128::from_bits(i128::MIN / 2); 2]);
+        let v: Vec<U128F0> = U128F0::max_value().split_evenly_iter(1).collect();
+        assert_eq!(v, [U128F0::max_value()]);
+        let v: Vec<U128F0> = U128F0::max_value().split_evenly_iter(2).collect();
+        assert_eq!(
+            v,
+            [
+                U128F0::from_bits(u128::MAX / 2 + 1),
+                U128F0::from_bits(u128::MAX / 2)
+            ]
+        );
+        let iter = I8F8::from_num(1).split_evenly_iter(5);
+        assert_eq!(iter.len(), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "zero shares")]
+    fn split_evenly_zero() {
+        use crate::types::I8F8;
+        let _ = I8F8::from_num(1).split_evenly_iter(0);
+    }
+
+    #[test]
+    fn cbrt() {
+        use crate::types::{I0F128, I128F0, I8F8, U0F128, U128F0, U64F64, U8F8};
+
+        // f64 is precise enough to round 16-bit results correctly
+        for bits in 0..=u16::MAX {
+            let x = U8F8::from_bits(bits);
+            let expected = U8F8::from_num((x.to_num::<f64>().cbrt() * 256.0).round() / 256.0);
+            assert_eq!(x.cbrt(), expected);
+            let x = I8F8::from_bits(bits as i16);
+            let expected = I8F8::from_num((x.to_num::<f64>().cbrt() * 256.0).round() / 256.0);
+            assert_eq!(x.cbrt(), expected);
+        }
+
+        let k = (1u128 << 42) - 1;
+        assert_eq!(U128F0::from_num(k * k * k).cbrt(), k);
+        assert_eq!(U128F0::from_num(k * k * k - 1).cbrt(), k);
+        // the cube root of 2^128 − 1 is 0x6597FA94F5B.C…
+        assert_eq!(U128F0::max_value().cbrt(), 0x659_7FA9_4F5Cu128);
+        // the cube root of −2^127 is −0x50A28BE635C.C…
+        assert_eq!(I128F0::min_value().cbrt(), -0x50A_28BE_635Di128);
+        assert_eq!(U64F64::from_num(27).cbrt(), 3);
+        assert_eq!(
+            U0F128::from_bits(1 << 125).cbrt(),
+            U0F128::from_bits(1 << 127)
+        );
+        assert_eq!(U0F128::max_value().checked_cbrt(), None);
+        assert_eq!(
+            I0F128::from_bits(-1 << 125).cbrt(),
+            I0F128::from_bits(-1 << 127)
+        );
+        assert_eq!(I0F128::max_value().checked_cbrt(), None);
+    }
+
+    #[test]
+    fn degrees_radians() {
+        use crate::types::{I128F0, I16F16, U128F0};
+
+        for &x in &[0.0, 1.0, -1.0, 0.001, core::f64::consts::PI, -100.0, 500.0] {
+            let fix = I16F16::from_num(x);
+            let deg = fix.to_num::<f64>().to_degrees();
+            assert!((fix.to_degrees().to_num::<f64>() - deg).abs() <= 0.5 / 65536.0);
+            let rad = fix.to_num::<f64>().to_radians();
+            assert!((fix.to_radians().to_num::<f64>() - rad).abs() <= 0.5 / 65536.0);
+        }
+        // 32768 / 57.29… is 571.90…
+        assert!(I16F16::from_num(571.9).checked_to_degrees().is_some());
+        assert_eq!(I16F16::from_num(571.91).checked_to_degrees(), None);
+        assert_eq!(I16F16::from_num(-571.91).checked_to_degrees(), None);
+
+        let max_rad = U128F0::from_bits(5_939_047_689_249_815_265_887_113_184_232_615_462);
+        assert_eq!(
+            max_rad.to_degrees(),
+            U128F0::from_bits(340_282_366_920_938_463_463_374_607_431_768_211_411)
+        );
+        assert_eq!((max_rad + U128F0::from_num(1)).checked_to_degrees(), None);
+        assert_eq!(
+            U128F0::max_value().to_radians(),
+            U128F0::from_bits(5_939_047_689_249_815_265_887_113_184_232_615_463)
+        );
+        // 2^127 × π/180 = …731.39, but with the 128-bit constant it is …731.5
+        // exactly, and the tie is rounded to even
+        assert_eq!(
+            I128F0::min_value().to_radians(),
+            I128F0::from_bits(-2_969_523_844_624_907_632_943_556_592_116_307_732)
+        );
+    }
+
+    #[test]
+    fn sign_siblings() {
+        use crate::{
+            traits::Fixed,
+            types::{I0F128, I8F8, U0F128, U8F8},
+        };
+
+        fn round_trip<F: Fixed>(x: F) -> Option<F> {
+            x.checked_to_unsigned()?
+                .checked_to_signed()?
+                .checked_to_num()
+        }
+
+        for bits in i16::MIN..=i16::MAX {
+            let f = I8F8::from_bits(bits);
+            let u = U8F8::from_bits(bits as u16);
+            let expected_u = if bits < 0 { None } else { Some(u) };
+            assert_eq!(f.checked_to_unsigned(), expected_u);
+            assert_eq!(u.checked_to_signed(), expected_u.map(|_| f));
+            assert_eq!(f.checked_to_signed(), Some(f));
+            assert_eq!(u.checked_to_unsigned(), Some(u));
+            assert_eq!(round_trip(f), expected_u.map(|_| f));
+        }
+        assert_eq!(
+            I0F128::max_value().checked_to_unsigned(),
+            Some(U0F128::from_bits(!0 >> 1))
+        );
+        assert_eq!(U0F128::from_bits(1 << 127).checked_to_signed(), None);
+    }
+
+    #[test]
+    fn bits_unsigned() {
+        use crate::types::{I0F128, I4F4, I8F8};
+        for bits in i16::MIN..=i16::MAX {
+            let f = I8F8::from_bits(bits);
+            assert_eq!(f.to_bits_unsigned(), bits as u16);
+            assert_eq!(I8F8::from_bits_unsigned(f.to_bits_unsigned()), f);
+        }
+        assert_eq!(I4F4::min_value().to_bits_unsigned(), 0x80);
+        assert_eq!(I4F4::from_bits_unsigned(0xff), I4F4::from_bits(-1));
+        assert_eq!(I0F128::min_value().to_bits_unsigned(), 1 << 127);
+        assert_eq!(I0F128::from_bits_unsigned(!0 >> 1), I0F128::max_value());
+    }
+
+    #[test]
+    fn const_from_int() {
+        use crate::types::{I0F128, I128F0, I1F7, I4F4, I8F0, U0F8, U128F0, U4F4, U8F0};
+        assert_eq!(U4F4::const_from_int(1), U4F4::from_num(1));
+        assert_eq!(I128F0::const_from_int(i128::MIN), I128F0::min_value());
+        for i in -300..300 {
+            // const_from_int panics exactly when checked_from_num returns None
+            if let Some(f) = I4F4::checked_from_num(i) {
+                assert_eq!(I4F4::const_from_int(i), f);
+            }
+            if let Some(f) = U4F4::checked_from_num(i) {
+                assert_eq!(U4F4::const_from_int(i), f);
+            }
+            if let Some(f) = I8F0::checked_from_num(i) {
+                assert_eq!(I8F0::const_from_int(i), f);
+            }
+            if let Some(f) = U8F0::checked_from_num(i) {
+                assert_eq!(U8F0::const_from_int(i), f);
+            }
+            if let Some(f) = I1F7::checked_from_num(i) {
+                assert_eq!(I1F7::const_from_int(i), f);
+            }
+        }
+        assert_eq!(U0F8::const_from_int(0), U0F8::from_num(0));
+        assert_eq!(I0F128::const_from_int(0), I0F128::from_num(0));
+        assert_eq!(I128F0::const_from_int(i128::MAX), I128F0::max_value());
+        assert_eq!(
+            U128F0::const_from_int(i128::MAX),
+            U128F0::from_num(i128::MAX)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn const_from_int_overflow() {
+        let _ = crate::types::I4F4::const_from_int(8);
+    }
+
+    #[test]
+    fn rsqrt() {
+        use crate::types::{I0F128, I8F8, U0F8, U12F4, U2F126, U64F64, U8F8};
+        for bits in 1..=u16::MAX {
+            // 1/√(bits/256) × 256 = 4096/√bits
+            let exact = 4096.0 / f64::from(bits).sqrt();
+            let u = U8F8::from_bits(bits);
+            match u.checked_rsqrt() {
+                Some(r) => assert!((f64::from(r.to_bits()) - exact).abs() <= 1.0),
+                None => assert!(exact > f64::from(u16::MAX)),
+            }
+            let u = U12F4::from_bits(bits);
+            let exact = 64.0 / f64::from(bits).sqrt();
+            assert!((f64::from(u.rsqrt().to_bits()) - exact).abs() <= 1.0);
+            let i = I8F8::from_bits(bits as i16);
+            if i.to_bits() <= 0 {
+                assert_eq!(i.checked_rsqrt(), None);
+            } else if let Some(r) = i.checked_rsqrt() {
+                assert_eq!(r, U8F8::from_bits(bits).rsqrt().to_num::<I8F8>());
+            }
+        }
+        assert_eq!(U0F8::from_bits(1).checked_rsqrt(), None);
+        assert_eq!(I0F128::max_value().checked_rsqrt(), None);
+        // with no guard bits left the result can be a few units off
+        let ulps = |a: U2F126, b: U2F126| a.max(b).to_bits() - a.min(b).to_bits();
+        assert!(ulps(U2F126::from_num(1).rsqrt(), U2F126::from_num(1)) <= 3);
+        assert!(ulps(U2F126::from_num(0.25).rsqrt(), U2F126::from_num(2)) <= 3);
+        for shift in 0..128 {
+            let x = U64F64::from_bits(1 << shift);
+            let exact = (x.to_num::<f64>()).sqrt().recip();
+            if exact >= 2f64.powi(64) {
+                assert_eq!(x.checked_rsqrt(), None);
+                continue;
+            }
+            let r = x.rsqrt();
+            let tol = exact * 1e-15 + 2f64.powi(-64);
+            assert!((r.to_num::<f64>() - exact).abs() <= tol);
+            if shift % 2 == 0 {
+                // powers of four have an exact result
+                assert_eq!(r.to_bits(), 1 << (96 - shift / 2));
+            }
+            let y = U64F64::from_bits((1u128 << shift) / 2 * 3 + 12345);
+            let exact = (y.to_num::<f64>()).sqrt().recip();
+            let tol = exact * 1e-14 + 2f64.powi(-63);
+            assert!((y.rsqrt().to_num::<f64>() - exact).abs() <= tol);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "non-positive")]
+    fn rsqrt_non_positive() {
+        let _ = crate::types::I8F8::from_num(-1).rsqrt();
+    }
+
+    #[test]
+    fn wrap_unit() {
+        use crate::types::{I0F8, I1F7, I4F4, U0F8, U4F4};
+        for bits in i8::MIN..=i8::MAX {
+            let i = I4F4::from_bits(bits);
+            let w = i.wrap_unit();
+            assert_eq!(w, i.rem_euclid(I4F4::from_num(1)));
+            assert!(!w.is_negative() && w.int() == 0);
+            let u = U4F4::from_bits(bits as u8);
+            assert_eq!(u.wrap_unit(), u.rem_euclid(U4F4::from_num(1)));
+            let i = I1F7::from_bits(bits);
+            let expected = if i < 0 {
+                i.wrapping_add(I1F7::min_value())
+            } else {
+                i
+            };
+            assert_eq!(i.wrap_unit(), expected);
+            if bits >= 0 {
+                assert_eq!(I0F8::from_bits(bits).wrap_unit(), I0F8::from_bits(bits));
+            }
+            assert_eq!(
+                U0F8::from_bits(bits as u8).wrap_unit(),
+                U0F8::from_bits(bits as u8)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn wrap_unit_no_int_bits() {
+        let _ = crate::types::I0F8::from_num(-0.25).wrap_unit();
+    }
+
+    #[test]
+    fn debug_range() {
+        use crate::types::{I4F4, U0F8};
+        let (lo, hi) = (I4F4::from_num(-1), I4F4::from_num(1));
+        for bits in -16..=16 {
+            let x = I4F4::from_bits(bits);
+            assert_eq!(x.debug_range(lo, hi), x);
+        }
+        let x = U0F8::from_bits(7);
+        assert_eq!(x.debug_range(x, x), x);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "1.06 is not in the range -1..=1")]
+    fn debug_range_out_of_range() {
+        let x = crate::types::I4F4::from_bits(17);
+        let _ = x.debug_range(
+            crate::types::I4F4::from_num(-1),
+            crate::types::I4F4::from_num(1),
+        );
+    }
+
+    #[test]
+    fn flush_to_zero() {
+        use crate::{
+            traits::Fixed,
+            types::{I0F8, I4F4, U4F4},
+        };
+        fn flush<F: Fixed>(x: F, threshold: F) -> F {
+            x.flush_to_zero(threshold)
+        }
+        for t in i8::MIN..=i8::MAX {
+            let threshold = I4F4::from_bits(t);
+            for bits in i8::MIN..=i8::MAX {
+                let x = I4F4::from_bits(bits);
+                let flushed = t > 0 && i16::from(bits).abs() < i16::from(t);
+                let expected = if flushed { I4F4::from_num(0) } else { x };
+                assert_eq!(flush(x, threshold), expected);
+                let x = I0F8::from_bits(bits);
+                let expected = if flushed { I0F8::from_num(0) } else { x };
+                assert_eq!(x.flush_to_zero(I0F8::from_bits(t)), expected);
+            }
+        }
+        let threshold = U4F4::from_num(0.5);
+        assert_eq!(U4F4::from_num(0.4375).flush_to_zero(threshold), 0);
+        assert_eq!(
+            U4F4::max_value().flush_to_zero(threshold),
+            U4F4::max_value()
+        );
+        assert_eq!(
+            U4F4::max_value().flush_to_zero(U4F4::max_value()),
+            U4F4::max_value()
+        );
+        assert_eq!(
+            I4F4::min_value().flush_to_zero(I4F4::max_value()),
+            I4F4::min_value()
+        );
+    }
+
+    #[test]
+    fn set_bit_positions() {
+        use crate::types::{I0F128, I128F0, I8F8, U0F8, U8F8};
+        for bits in i16::MIN..=i16::MAX {
+            let positions = I8F8::from_bits(bits).set_bit_positions();
+            let mut rebuilt = 0u16;
+            let mut prev = -9;
+            for p in positions {
+                assert!(prev < p && p < 8);
+                rebuilt |= 1 << (p + 8);
+                prev = p;
+            }
+            assert_eq!(rebuilt, bits as u16);
+            assert_eq!(
+                U8F8::from_bits(bits as u16).set_bit_positions().count(),
+                bits.count_ones() as usize
+            );
+        }
+        let all: [i32; 8] = [-8, -7, -6, -5, -4, -3, -2, -1];
+        assert!(U0F8::max_value()
+            .set_bit_positions()
+            .eq(all.iter().cloned()));
+        assert!(I0F128::min_value().set_bit_positions().eq(Some(-1)));
+        assert!(I0F128::from_bits(1).set_bit_positions().eq(Some(-128)));
+        assert!(I128F0::min_value().set_bit_positions().eq(Some(127)));
+    }
+
+    #[test]
+    fn add_int() {
+        use crate::types::{I0F8, I128F0, I1F7, I4F4, I64F64, U0F8, U128F0, U4F4};
+        for bits in i8::MIN..=i8::MAX {
+            for n in -300..=300 {
+                let exact = i32::from(bits) + n * 16;
+                let x = I4F4::from_bits(bits);
+                let in_range = i32::from(i8::MIN) <= exact && exact <= i32::from(i8::MAX);
+                let wrapped = I4F4::from_bits(exact as i8);
+                assert_eq!(x.overflowing_add_int(n.into()), (wrapped, !in_range));
+                let checked = if in_range { Some(wrapped) } else { None };
+                assert_eq!(x.checked_add_int(n.into()), checked);
+                assert_eq!(x.wrapping_add_int(n.into()), wrapped);
+                let saturated = if in_range {
+                    wrapped
+                } else if n > 0 {
+                    I4F4::max_value()
+                } else {
+                    I4F4::min_value()
+                };
+                assert_eq!(x.saturating_add_int(n.into()), saturated);
+
+                let exact = i32::from(bits as u8) + n * 16;
+                let x = U4F4::from_bits(bits as u8);
+                let in_range = 0 <= exact && exact <= i32::from(u8::MAX);
+                let wrapped = U4F4::from_bits(exact as u8);
+                assert_eq!(x.overflowing_add_int(n.into()), (wrapped, !in_range));
+
+                let exact = i32::from(bits) + n * 128;
+                let x = I1F7::from_bits(bits);
+                let in_range = i32::from(i8::MIN) <= exact && exact <= i32::from(i8::MAX);
+                let wrapped = I1F7::from_bits(exact as i8);
+                assert_eq!(x.overflowing_add_int(n.into()), (wrapped, !in_range));
+
+                assert_eq!(
+                    I0F8::from_bits(bits).checked_add_int(n.into()).is_some(),
+                    n == 0
+                );
+                assert_eq!(
+                    U0F8::from_bits(bits as u8)
+                        .checked_add_int(n.into())
+                        .is_some(),
+                    n == 0
+                );
+            }
+        }
+        // the boundary where adding the shifted integer just overflows
+        let max = I128F0::max_value();
+        assert_eq!(max.checked_add_int(0), Some(max));
+        assert_eq!(
+            max.checked_add_int(-1).unwrap().checked_add_int(1),
+            Some(max)
+        );
+        assert_eq!(max.checked_add_int(1), None);
+        assert_eq!(I128F0::min_value().checked_add_int(-1), None);
+        assert_eq!(I128F0::from_num(-1).checked_add_int(i128::MIN), None);
+        assert_eq!(
+            I128F0::from_num(0).checked_add_int(i128::MIN),
+            Some(I128F0::min_value())
+        );
+        let max = U128F0::max_value();
+        assert_eq!(max.checked_add_int(-1), Some(max - U128F0::from_num(1)));
+        assert_eq!(max.checked_add_int(1), None);
+        assert_eq!(
+            max.checked_add_int(i128::MIN),
+            Some(U128F0::from_bits(u128::MAX >> 1))
+        );
+        let big = U128F0::from_bits(1 << 127);
+        assert_eq!(big.checked_add_int(i128::MAX), Some(max));
+        assert_eq!(U128F0::from_num(0).checked_add_int(-1), None);
+        let x = I64F64::from_num(i64::MAX) + I64F64::from_num(0.5);
+        assert_eq!(x.checked_add_int(1), None);
+        assert_eq!(x.saturating_add_int(1), I64F64::max_value());
+        let min = I64F64::from_num(i64::MIN) + I64F64::from_num(0.5);
+        assert_eq!(x.checked_add_int(-i128::from(u64::MAX)), Some(min));
+        assert_eq!(min.checked_add_int(-1), None);
+        // integers outside the range of the type can still give a sum in range
+        assert_eq!(
+            I64F64::from_num(-1).checked_add_int(1 << 63),
+            Some(I64F64::from_num(i64::MAX))
+        );
+    }
+
+    #[test]
+    fn magnitude() {
+        use crate::types::{I0F128, I128F0, I16F16, U0F128, U128F0, U8F8};
+        for bits in 1..=u16::MAX {
+            let x = U8F8::from_bits(bits);
+            let m = x.magnitude().unwrap();
+            let f = x.to_num::<f64>();
+            assert!(10f64.powi(m) <= f && f < 10f64.powi(m + 1), "{}", x);
+        }
+        assert_eq!(U8F8::from_num(0).magnitude(), None);
+        for &(val, m) in &[
+            (0.001, -3),
+            (0.00999, -3),
+            (0.015, -2),
+            (123.0, 2),
+            (-32768.0, 4),
+        ] {
+            assert_eq!(I16F16::from_num(val).magnitude(), Some(m), "{}", val);
+        }
+        assert_eq!(I16F16::from_bits(1).magnitude(), Some(-5));
+        assert_eq!(U128F0::max_value().magnitude(), Some(38));
+        assert_eq!(I128F0::min_value().magnitude(), Some(38));
+        let pow38 = U128F0::from_bits(10u128.pow(38));
+        assert_eq!(pow38.magnitude(), Some(38));
+        assert_eq!((pow38 - U128F0::from_num(1)).magnitude(), Some(37));
+        // 2^-128 ≈ 2.9 × 10^-39
+        assert_eq!(U0F128::from_bits(1).magnitude(), Some(-39));
+        assert_eq!(I0F128::min_value().magnitude(), Some(-1));
+    }
+
+    #[test]
+    fn fixed_convert() {
+        use crate::{
+            traits::FixedConvert,
+            types::{I16F16, I32F32, I4F4, U16F16, U8F8},
+            ConversionError,
+        };
+        // parse → widen → narrow
+        let val = "12.5"
+            .parse::<I16F16>()
+            .and_then_to::<I32F32>()
+            .and_then_to::<U8F8>()
+            .and_then_to::<U16F16>();
+        assert_eq!(val, Ok(U16F16::from_num(12.5)));
+        // the first failure short-circuits the rest of the chain
+        let err = "-1"
+            .parse::<I16F16>()
+            .and_then_to::<U8F8>()
+            .and_then_to::<I32F32>();
+        assert_eq!(err, Err(ConversionError::Overflow));
+        let parse_err = "1.2.3".parse::<I16F16>().unwrap_err();
+        let err = "1.2.3".parse::<I16F16>().and_then_to::<I32F32>();
+        assert_eq!(err, Err(ConversionError::Parse(parse_err)));
+        // fixed-point values and options start a chain too
+        assert_eq!(
+            I16F16::from_num(7).and_then_to::<I4F4>(),
+            Ok(I4F4::from_num(7))
+        );
+        assert_eq!(
+            I16F16::from_num(8).and_then_to::<I4F4>(),
+            Err(ConversionError::Overflow)
+        );
+        let sum = I4F4::from_num(7).checked_add(I4F4::from_num(1));
+        assert_eq!(sum.and_then_to::<I16F16>(), Err(ConversionError::Overflow));
+        let sum = I4F4::from_num(6).checked_add(I4F4::from_num(1));
+        assert_eq!(sum.and_then_to::<I16F16>(), Ok(I16F16::from_num(7)));
+    }
+
+    #[test]
+    fn q_format() {
+        use crate::{
+            traits::Fixed,
+            types::{extra::U16, I0F128, I16F16, I8F8, U0F8, U128F0, U16F16},
+            AnyFixed, FixedI32, FixedU64,
+        };
+        fn describe<F: Fixed>(x: F) -> (u32, u32, bool) {
+            x.q_format()
+        }
+        assert_eq!(describe(I16F16::from_num(1)), (16, 16, true));
+        assert_eq!(describe(U16F16::from_num(1)), (16, 16, false));
+        assert_eq!(I8F8::from_num(0).q_format(), (8, 8, true));
+        assert_eq!(U0F8::from_num(0).q_format(), (0, 8, false));
+        assert_eq!(I0F128::from_num(0).q_format(), (0, 128, true));
+        assert_eq!(U128F0::from_num(0).q_format(), (128, 0, false));
+        let any = AnyFixed::<U16>::I32(FixedI32::from_num(1));
+        assert_eq!(any.q_format(), I16F16::from_num(1).q_format());
+        assert_eq!(
+            AnyFixed::<U16>::U64(FixedU64::from_num(1)).q_format(),
+            (48, 16, false)
+        );
+    }
+
+    #[test]
+    fn to_fixed_lossy_report() {
+        use crate::types::{I0F8, I16F16, I32F32, I4F4, I8F0, I8F8, U16F16, U8F8};
+        let x = I16F16::from_num(-2.5);
+        assert_eq!(
+            x.to_fixed_lossy_report::<I32F32>(),
+            (I32F32::from_num(-2.5), false)
+        );
+        assert_eq!(
+            x.to_fixed_lossy_report::<I8F8>(),
+            (I8F8::from_num(-2.5), false)
+        );
+        assert_eq!(
+            x.to_fixed_lossy_report::<I4F4>(),
+            (I4F4::from_num(-2.5), false)
+        );
+        // negative into unsigned overflows
+        assert!(x.to_fixed_lossy_report::<U16F16>().1);
+        // -2.5 does not fit in I0F8
+        assert!(x.to_fixed_lossy_report::<I0F8>().1);
+        // 2^-9 is lost in I8F8 but kept in I32F32
+        let tiny = I16F16::from_bits(1 << 7);
+        assert_eq!(
+            tiny.to_fixed_lossy_report::<I8F8>(),
+            (I8F8::from_num(0), true)
+        );
+        assert!(!tiny.to_fixed_lossy_report::<I32F32>().1);
+        // −0.5 is truncated to −1, which overflows when converted back
+        let half = I0F8::from_num(-0.5);
+        assert_eq!(
+            half.to_fixed_lossy_report::<I8F0>(),
+            (I8F0::from_num(-1), true)
+        );
+        // both narrower and coarser, but still exact
+        assert_eq!(
+            U8F8::from_num(3.75).to_fixed_lossy_report::<I4F4>(),
+            (I4F4::from_num(3.75), false)
+        );
+        assert!(!I16F16::min_value().to_fixed_lossy_report::<I32F32>().1);
+        assert!(I16F16::max_value().to_fixed_lossy_report::<I8F8>().1);
+    }
+
+    #[test]
+    fn scale() {
+        use crate::{
+            traits::Fixed,
+            types::{I0F128, I1F7, I2F6, I8F8, U0F128, U0F8, U128F0, U1F7},
+        };
+        fn scale_of<F: Fixed>() -> Option<F::Bits> {
+            F::scale()
+        }
+        assert_eq!(I8F8::SCALE, 256);
+        assert_eq!(U128F0::SCALE, 1);
+        assert_eq!(U0F128::SCALE, 0);
+        assert_eq!(I0F128::SCALE, 0);
+        assert_eq!(scale_of::<I8F8>(), Some(I8F8::from_num(1).to_bits()));
+        assert_eq!(I2F6::scale(), Some(64));
+        assert_eq!(I1F7::scale(), None);
+        assert_eq!(U1F7::scale(), Some(128));
+        assert_eq!(U0F8::scale(), None);
+        assert_eq!(U128F0::scale(), Some(1));
+        assert_eq!(scale_of::<U0F128>(), None);
+    }
+
+    #[test]
+    fn continued_fraction() {
+        use crate::{
+            consts,
+            types::{I0F128, I128F0, I16F16, I8F8, U0F128, U128F0},
+        };
+        fn check<const N: usize>((terms, len): ([i128; N], usize), expected: &[i128]) {
+            assert_eq!(&terms[..len], expected);
+        }
+        // π in I8F8 is 804/256 = 201/64 = 3 + 1/(7 + 1/9)
+        check(
+            I8F8::from_num(consts::PI).continued_fraction_array::<10>(),
+            &[3, 7, 9],
+        );
+        check(
+            I16F16::from_num(consts::PI).continued_fraction_array::<2>(),
+            &[3, 7],
+        );
+        check(
+            I8F8::from_num(-0.5).continued_fraction_array::<10>(),
+            &[-1, 2],
+        );
+        check(I8F8::from_num(0).continued_fraction_array::<10>(), &[0]);
+        check(I8F8::from_num(1.5).continued_fraction_array::<0>(), &[]);
+        check(
+            I0F128::min_value().continued_fraction_array::<10>(),
+            &[-1, 2],
+        );
+        check(
+            U0F128::from_bits(4).continued_fraction_array::<10>(),
+            &[0, 1 << 126],
+        );
+        // 2^127 and 2^128 do not fit in i128
+        check(U0F128::from_bits(2).continued_fraction_array::<10>(), &[0]);
+        check(U0F128::from_bits(1).continued_fraction_array::<10>(), &[0]);
+        // 2^128 = 3 × (2^128 − 1) / 3 + 1
+        let third = (u128::MAX / 3) as i128;
+        check(
+            U0F128::from_bits(3).continued_fraction_array::<10>(),
+            &[0, third, 3],
+        );
+        check(
+            I128F0::min_value().continued_fraction_array::<10>(),
+            &[i128::MIN],
+        );
+        check(U128F0::max_value().continued_fraction_array::<10>(), &[]);
+        let (terms, len) = I8F8::from_num(consts::PI).continued_fraction_array::<4>();
+        assert_eq!((terms, len), ([3, 7, 9, 0], 3));
+    }
+
+    #[test]
+    fn frac_distance_to_int() {
+        use crate::types::{I0F8, I128F0, I4F4, U0F128, U0F8, U4F4};
+        for bits in i8::MIN..=i8::MAX {
+            let x = I4F4::from_bits(bits);
+            let f = x.to_num::<f64>();
+            let expected = (f - f.round()).abs();
+            assert_eq!(x.frac_distance_to_int().to_num::<f64>(), expected);
+            let threshold = I4F4::from_num(0.125);
+            assert_eq!(x.is_near_half(threshold), expected >= 0.375);
+            let u = U4F4::from_bits(bits as u8);
+            let f = u.to_num::<f64>();
+            assert_eq!(
+                u.frac_distance_to_int().to_num::<f64>(),
+                (f - f.round()).abs()
+            );
+        }
+        // 0.5 cannot be represented in I0F8
+        assert_eq!(
+            I0F8::from_num(-0.5).frac_distance_to_int(),
+            I0F8::max_value()
+        );
+        assert_eq!(
+            I0F8::from_num(-0.25).frac_distance_to_int(),
+            I0F8::from_num(0.25)
+        );
+        assert!(I0F8::from_num(-0.5).is_near_half(I0F8::from_num(0)));
+        assert_eq!(
+            U0F8::from_num(0.5).frac_distance_to_int(),
+            U0F8::from_num(0.5)
+        );
+        assert_eq!(
+            U0F128::max_value().frac_distance_to_int(),
+            U0F128::from_bits(1)
+        );
+        assert!(!I4F4::from_num(2.5).is_near_half(I4F4::from_num(-0.0625)));
+        assert!(U4F4::from_num(0).is_near_half(U4F4::max_value()));
+        // integers are 0.5 from halfway
+        assert_eq!(
+            I128F0::from_num(-3).frac_distance_to_int(),
+            I128F0::from_num(0)
+        );
+        assert!(!I128F0::from_num(3).is_near_half(I128F0::from_num(0)));
+        assert!(I128F0::from_num(3).is_near_half(I128F0::from_num(1)));
+    }
+
+    #[test]
+    fn reverse_bits() {
+        use crate::types::{I0F8, I4F4, I8F0, U0F128, U128F0, U4F4};
+        for bits in 0..=u8::MAX {
+            let x = U4F4::from_bits(bits);
+            let frac = bits & 0x0F;
+            let reversed = (bits & 0xF0) | (frac.reverse_bits() >> 4);
+            assert_eq!(x.reverse_frac_bits(), U4F4::from_bits(reversed));
+            assert_eq!(x.reverse_frac_bits().reverse_frac_bits(), x);
+            assert_eq!(x.reverse_all_bits(), U4F4::from_bits(bits.reverse_bits()));
+            assert_eq!(x.reverse_all_bits().reverse_all_bits(), x);
+            let y = I4F4::from_bits(bits as i8);
+            assert_eq!(y.reverse_frac_bits().to_bits() as u8, reversed);
+            assert_eq!(y.reverse_frac_bits().reverse_frac_bits(), y);
+            assert_eq!(y.reverse_all_bits().reverse_all_bits(), y);
+            // with no fractional bits nothing changes
+            assert_eq!(
+                I8F0::from_bits(bits as i8).reverse_frac_bits().to_bits() as u8,
+                bits
+            );
+            // with no integer bits all bits are fractional
+            let z = I0F8::from_bits(bits as i8);
+            assert_eq!(z.reverse_frac_bits(), z.reverse_all_bits());
+        }
+        assert_eq!(
+            U0F128::from_bits(1).reverse_frac_bits(),
+            U0F128::from_bits(1 << 127)
+        );
+        assert_eq!(
+            U128F0::from_bits(1).reverse_all_bits(),
+            U128F0::from_bits(1 << 127)
+        );
+        assert_eq!(
+            U128F0::from_bits(1).reverse_frac_bits(),
+            U128F0::from_bits(1)
+        );
+    }
+
+    #[test]
+    fn unsigned_abs() {
+        use crate::{
+            traits::FixedSigned,
+            types::{I0F128, I0F8, I128F0, I4F4, U0F128, U0F8, U128F0, U4F4},
+        };
+        fn magnitude<F: FixedSigned>(x: F) -> F::Unsigned {
+            x.unsigned_abs()
+        }
+        for bits in i8::MIN..=i8::MAX {
+            let abs = U4F4::from_bits(bits.unsigned_abs());
+            assert_eq!(I4F4::from_bits(bits).unsigned_abs(), abs);
+            assert_eq!(magnitude(I4F4::from_bits(bits)), abs);
+        }
+        assert_eq!(I0F8::min_value().unsigned_abs(), U0F8::from_num(0.5));
+        assert_eq!(
+            I0F128::min_value().unsigned_abs(),
+            U0F128::from_bits(1 << 127)
+        );
+        assert_eq!(
+            I128F0::min_value().unsigned_abs(),
+            U128F0::from_bits(1 << 127)
+        );
+        assert_eq!(
+            I128F0::max_value().unsigned_abs(),
+            U128F0::from_bits(!0 >> 1)
+        );
+    }
+
+    #[cfg(feature = "num-rational")]
+    #[test]
+    fn to_big_rational() {
+        use crate::types::{I0F128, I128F0, I4F4, U0F128, U128F0, U4F4};
+        use num_rational::BigRational;
+        let ratio = |numer: i128, denom: i128| BigRational::new(numer.into(), denom.into());
+        let pow2 = |exp: i32| BigRational::from_integer(2.into()).pow(exp);
+        for bits in i8::MIN..=i8::MAX {
+            assert_eq!(
+                I4F4::from_bits(bits).to_big_rational(),
+                ratio(bits.into(), 16)
+            );
+            let ubits = bits as u8;
+            assert_eq!(
+                U4F4::from_bits(ubits).to_big_rational(),
+                ratio(ubits.into(), 16)
+            );
+        }
+        assert_eq!(I4F4::min_value().to_big_rational(), ratio(-8, 1));
+        assert_eq!(I0F128::min_value().to_big_rational(), ratio(-1, 2));
+        assert_eq!(I0F128::from_bits(-1).to_big_rational(), -pow2(-128));
+        assert_eq!(I128F0::min_value().to_big_rational(), -pow2(127));
+        assert_eq!(
+            I128F0::max_value().to_big_rational(),
+            pow2(127) - ratio(1, 1)
+        );
+        assert_eq!(U0F128::from_bits(1).to_big_rational(), pow2(-128));
+        assert_eq!(
+            U0F128::max_value().to_big_rational(),
+            ratio(1, 1) - pow2(-128)
+        );
+        assert_eq!(
+            U128F0::max_value().to_big_rational(),
+            pow2(128) - ratio(1, 1)
+        );
+    }
+
+    #[test]
+    fn same_layout() {
+        use crate::{
+            traits::{FixedSigned, FixedUnsigned, SameLayout},
+            types::{I0F128, I4F4, I8F8, U0F128, U4F4, U8F8},
+        };
+        for bits in i16::MIN..=i16::MAX {
+            let f = I8F8::from_bits(bits);
+            let u: U8F8 = f.reinterpret();
+            assert_eq!(u.to_bits(), bits as u16);
+            assert_eq!(f.reinterpret_sign(), u);
+            assert_eq!(u.reinterpret_sign(), f);
+            assert_eq!(SameLayout::<I8F8>::reinterpret(f), f);
+        }
+        assert_eq!(
+            I4F4::from_num(-0.5).reinterpret_sign(),
+            U4F4::from_num(15.5)
+        );
+        assert_eq!(U4F4::from_num(8).reinterpret_sign(), I4F4::min_value());
+        assert_eq!(
+            I0F128::min_value().reinterpret_sign(),
+            U0F128::from_num(0.5)
+        );
+        assert_eq!(
+            U0F128::max_value().reinterpret_sign(),
+            I0F128::from_bits(-1)
+        );
+    }
+
+    #[test]
+    fn narrow_frac() {
+        use crate::types::extra::{U0, U3, U4, U7, U8};
+        use crate::types::{I0F8, I8F8, U0F8, U8F8};
+        use crate::{FixedI16, RoundingMode};
+
+        // rounds bits / 2^shift with the given mode
+        fn reference(bits: i32, shift: u32, mode: RoundingMode) -> i32 {
+            let den = 1 << shift;
+            let (floor, rem) = (bits.div_euclid(den), bits.rem_euclid(den));
+            let round_up = match mode {
+                RoundingMode::Nearest => 2 * rem > den || (2 * rem == den && floor % 2 != 0),
+                RoundingMode::Floor => false,
+                RoundingMode::Ceil => rem != 0,
+                RoundingMode::Zero => bits < 0 && rem != 0,
+            };
+            floor + i32::from(round_up)
+        }
+
+        let modes = [
+            RoundingMode::Nearest,
+            RoundingMode::Floor,
+            RoundingMode::Ceil,
+            RoundingMode::Zero,
+        ];
+        for &mode in &modes {
+            for bits in i16::MIN..=i16::MAX {
+                let f = I8F8::from_bits(bits);
+                let b = i32::from(bits);
+                assert_eq!(i32::from(f.narrow_frac::<U8>(mode).to_bits()), b);
+                assert_eq!(
+                    i32::from(f.narrow_frac::<U7>(mode).to_bits()),
+                    reference(b, 1, mode)
+                );
+                assert_eq!(
+                    i32::from(f.narrow_frac::<U4>(mode).to_bits()),
+                    reference(b, 4, mode)
+                );
+                assert_eq!(
+                    i32::from(f.narrow_frac::<U0>(mode).to_bits()),
+                    reference(b, 8, mode)
+                );
+                let u = U8F8::from_bits(bits as u16);
+                let b = i32::from(bits as u16);
+                assert_eq!(
+                    i32::from(u.narrow_frac::<U3>(mode).to_bits()),
+                    reference(b, 5, mode)
+                );
+                assert_eq!(
+                    i32::from(u.narrow_frac::<U0>(mode).to_bits()),
+                    reference(b, 8, mode)
+                );
+            }
+            for bits in i8::MIN..=i8::MAX {
+                // the whole width is fractional
+                let f = I0F8::from_bits(bits);
+                let expected = reference(i32::from(bits), 8, mode);
+                assert_eq!(i32::from(f.narrow_frac::<U0>(mode).to_bits()), expected);
+                let u = U0F8::from_bits(bits as u8);
+                let expected = reference(i32::from(bits as u8), 8, mode);
+                assert_eq!(i32::from(u.narrow_frac::<U0>(mode).to_bits()), expected);
+            }
+        }
+        // ties
+        let tie = I8F8::from_num(-2.5);
+        assert_eq!(tie.narrow_frac::<U0>(RoundingMode::Nearest), -2);
+        assert_eq!(tie.narrow_frac::<U0>(RoundingMode::Floor), -3);
+        assert_eq!(tie.narrow_frac::<U0>(RoundingMode::Ceil), -2);
+        assert_eq!(tie.narrow_frac::<U0>(RoundingMode::Zero), -2);
+        let tie = U8F8::from_num(3.5);
+        assert_eq!(tie.narrow_frac::<U0>(RoundingMode::Nearest), 4);
+        assert_eq!(tie.narrow_frac::<U0>(RoundingMode::Floor), 3);
+        assert_eq!(tie.narrow_frac::<U0>(RoundingMode::Ceil), 4);
+        assert_eq!(tie.narrow_frac::<U0>(RoundingMode::Zero), 3);
+        // rounding the maximum up does not overflow
+        let max = I8F8::max_value().narrow_frac::<U0>(RoundingMode::Ceil);
+        assert_eq!(max, FixedI16::<U0>::from_num(128));
+    }
+
+    #[test]
+    fn quantize() {
+        use crate::types::{I0F8, I1F7, I64F64, I8F8, U0F8, U1F7, U8F8};
+
+        // rounds bits / 2^drop to the nearest, ties to even
+        fn nearest(bits: i128, drop: u32) -> i128 {
+            let den = 1 << drop;
+            let (quot, rem) = (bits.div_euclid(den), bits.rem_euclid(den));
+            let round_up = 2 * rem > den || (2 * rem == den && quot.rem_euclid(2) == 1);
+            (quot + i128::from(round_up)) << drop
+        }
+
+        for bits in i16::MIN..=i16::MAX {
+            let x = I8F8::from_bits(bits);
+            let u = U8F8::from_bits(bits as u16);
+            for keep in 0..10 {
+                let drop = 8u32.saturating_sub(keep);
+                let exact = nearest(i128::from(bits), drop);
+                let fits = i128::from(i16::MIN) <= exact && exact <= i128::from(i16::MAX);
+                let expected = (I8F8::from_bits(exact as i16), !fits);
+                assert_eq!(x.overflowing_quantize(keep), expected, "{} {}", x, keep);
+                let exact = nearest(i128::from(bits as u16), drop);
+                let fits = exact <= i128::from(u16::MAX);
+                let expected = (U8F8::from_bits(exact as u16), !fits);
+                assert_eq!(u.overflowing_quantize(keep), expected, "{} {}", u, keep);
+            }
+        }
+
+        for bits in i8::MIN..=i8::MAX {
+            // I0F8 values in [−0.5, 0.5) all round to 0
+            let x = I0F8::from_bits(bits);
+            assert_eq!(x.checked_quantize(0), Some(I0F8::from_num(0)));
+            let u = U0F8::from_bits(bits as u8);
+            let expected = if bits as u8 > 0x80 {
+                None
+            } else {
+                Some(U0F8::from_num(0))
+            };
+            assert_eq!(u.checked_quantize(0), expected);
+            // I1F7 keeps only −1 and 0, so more than 0.5 overflows
+            let x = I1F7::from_bits(bits);
+            let expected = if x < -0.5 {
+                Some(I1F7::from_num(-1))
+            } else if x <= 0.5 {
+                Some(I1F7::from_num(0))
+            } else {
+                None
+            };
+            assert_eq!(x.checked_quantize(0), expected, "{}", x);
+            let u = U1F7::from_bits(bits as u8);
+            // 1.5 is a tie that rounds to the even 2, which overflows
+            let expected = if u >= 1.5 {
+                None
+            } else {
+                Some(u.round_ties_to_even())
+            };
+            assert_eq!(u.checked_quantize(0), expected, "{}", u);
+        }
+
+        let x = I64F64::from_bits(0x1234_5678_9abc_def0_8000_0000_0000_0001);
+        assert_eq!(x.quantize(0), x.round());
+        assert_eq!(
+            x.quantize(1),
+            I64F64::from_bits(0x1234_5678_9abc_def0_8000_0000_0000_0000)
+        );
+        assert_eq!(I64F64::max_value().checked_quantize(63), None);
+    }
+
+    #[test]
+    fn basis_points() {
+        use crate::types::{I0F128, I64F64, I8F8, U0F128, U8F8};
+
+        for bits in i16::MIN..=i16::MAX {
+            let x = I8F8::from_bits(bits);
+            let expected = x.to_scaled(10_000).map(|bps| bps as i64);
+            assert_eq!(x.to_basis_points(), expected);
+            assert_eq!(I8F8::from_basis_points(expected.unwrap()), x);
+            let u = U8F8::from_bits(bits as u16);
+            let expected = u.to_scaled(10_000).map(|bps| bps as i64);
+            assert_eq!(u.to_basis_points(), expected);
+            assert_eq!(U8F8::from_basis_points(expected.unwrap()), u);
+        }
+        for bps in -1_300_000..=2_600_000 {
+            // round(bps × 256 / 10000) with ties to even
+            let (quot, rem) = (
+                (bps * 256i64).div_euclid(10_000),
+                (bps * 256).rem_euclid(10_000),
+            );
+            let nearest = quot + i64::from(rem > 5_000 || (rem == 5_000 && quot % 2 != 0));
+            let expected = if i64::from(i16::MIN) <= nearest && nearest <= i64::from(i16::MAX) {
+                Some(I8F8::from_bits(nearest as i16))
+            } else {
+                None
+            };
+            assert_eq!(I8F8::checked_from_basis_points(bps), expected, "{}", bps);
+            let expected = if 0 <= nearest && nearest <= i64::from(u16::MAX) {
+                Some(U8F8::from_bits(nearest as u16))
+            } else {
+                None
+            };
+            assert_eq!(U8F8::checked_from_basis_points(bps), expected, "{}", bps);
+        }
+
+        assert_eq!(
+            U0F128::from_basis_points(5_000),
+            U0F128::from_bits(1 << 127)
+        );
+        assert_eq!(U0F128::checked_from_basis_points(10_000), None);
+        assert_eq!(U0F128::from_basis_points(0), U0F128::from_num(0));
+        assert_eq!(U0F128::checked_from_basis_points(-1), None);
+        assert_eq!(I0F128::from_basis_points(-5_000), I0F128::min_value());
+        assert_eq!(I0F128::checked_from_basis_points(5_000), None);
+        // 2^128 / 10000 = 34_028_236_692_093_846_346_337_460_743_176_821.1456
+        let one_bp = 34_028_236_692_093_846_346_337_460_743_176_821;
+        assert_eq!(U0F128::from_basis_points(1), U0F128::from_bits(one_bp));
+        assert_eq!(
+            I0F128::from_basis_points(-1),
+            I0F128::from_bits(-(one_bp as i128))
+        );
+        let min = I64F64::from_basis_points(i64::MIN);
+        assert_eq!(min.to_basis_points(), Some(i64::MIN));
+        assert_eq!(I64F64::from_num(1u64 << 60).to_basis_points(), None);
+    }
+
+    #[test]
+    fn percent_of() {
+        use crate::types::{I0F128, I128F0, I16F16, U0F128, U8F8};
+        for part in (i32::from(i16::MIN)..=i32::from(i16::MAX)).step_by(7) {
+            for &whole in &[1i32, -1, 3, 256, -300, 1000, 32767, -32768] {
+                let (p, w) = (I16F16::from_bits(part), I16F16::from_bits(whole));
+                // exact bits are part × 100 × 2^16 / whole
+                let num = (i64::from(part) * 100) << 16;
+                let den = i64::from(whole);
+                let (quot, rem) = (num.div_euclid(den.abs()), num.rem_euclid(den.abs()));
+                let twice = 2 * rem;
+                let quot = if twice > den.abs() || (twice == den.abs() && quot % 2 != 0) {
+                    quot + 1
+                } else {
+                    quot
+                };
+                let quot = if den < 0 { -quot } else { quot };
+                let expected = if i64::from(i32::MIN) <= quot && quot <= i64::from(i32::MAX) {
+                    Some(I16F16::from_bits(quot as i32))
+                } else {
+                    None
+                };
+                assert_eq!(p.percent_of(w), expected, "{} {}", p, w);
+            }
+        }
+        assert_eq!(U8F8::from_num(1).percent_of(U8F8::from_num(0)), None);
+        assert_eq!(U8F8::from_num(3).percent_of(U8F8::from_num(1)), None);
+        assert_eq!(
+            U8F8::from_num(1).percent_of(U8F8::from_num(4)),
+            Some(U8F8::from_num(25))
+        );
+        // the intermediate exceeds 128 bits, but the result fits
+        let max = I128F0::max_value();
+        assert_eq!(max.percent_of(max), Some(I128F0::from_num(100)));
+        assert_eq!(max.percent_of(-max), Some(I128F0::from_num(-100)));
+        assert_eq!(I128F0::min_value().percent_of(I128F0::from_num(1)), None);
+        let tiny = U0F128::from_bits(1);
+        assert_eq!(
+            tiny.percent_of(U0F128::max_value()),
+            Some(U0F128::from_bits(100))
+        );
+        assert_eq!(U0F128::max_value().percent_of(U0F128::max_value()), None);
+        let half = I0F128::min_value();
+        assert_eq!(
+            I0F128::from_bits(-1).percent_of(half),
+            Some(I0F128::from_bits(200))
+        );
+    }
+
+    #[test]
+    fn mantissa_exp() {
+        use crate::types::{I0F128, I128F0, I8F8, U0F128, U8F8};
+        for bits in i16::MIN..=i16::MAX {
+            let f = I8F8::from_bits(bits);
+            let (mantissa, exp) = f.to_mantissa_exp();
+            assert!(bits == 0 || mantissa % 2 != 0);
+            assert_eq!(I8F8::from_mantissa_exp(mantissa, exp), f);
+            let u = U8F8::from_bits(bits as u16);
+            let (mantissa, exp) = u.to_mantissa_exp();
+            assert_eq!(U8F8::from_mantissa_exp(mantissa, exp), u);
+        }
+        assert_eq!(I0F128::min_value().to_mantissa_exp(), (-1, -1));
+        assert_eq!(I128F0::min_value().to_mantissa_exp(), (-1, 127));
+        assert_eq!(U0F128::from_bits(1).to_mantissa_exp(), (1, -128));
+        assert_eq!(
+            I128F0::checked_from_mantissa_exp(-1, 127),
+            Some(I128F0::min_value())
+        );
+        assert_eq!(I128F0::checked_from_mantissa_exp(1, 127), None);
+        assert_eq!(I0F128::checked_from_mantissa_exp(-1, 0), None);
+        assert_eq!(
+            I0F128::checked_from_mantissa_exp(-1, -1),
+            Some(I0F128::min_value())
+        );
+
+        // rounding
+        assert_eq!(I8F8::from_mantissa_exp(3, -9), I8F8::from_bits(2));
+        assert_eq!(I8F8::from_mantissa_exp(-3, -9), I8F8::from_bits(-2));
+        assert_eq!(I8F8::from_mantissa_exp(5, -9), I8F8::from_bits(2));
+        assert_eq!(I8F8::from_mantissa_exp(1, -9), I8F8::from_num(0));
+        assert_eq!(U0F128::from_mantissa_exp(3, -129), U0F128::from_bits(2));
+        assert_eq!(
+            U0F128::from_mantissa_exp(u128::MAX, -256),
+            U0F128::from_bits(1)
+        );
+        assert_eq!(
+            U0F128::from_mantissa_exp(u128::MAX, -257),
+            U0F128::from_num(0)
+        );
+        assert_eq!(I8F8::from_mantissa_exp(i16::MAX, i32::MIN), 0);
+        assert_eq!(U0F128::checked_from_mantissa_exp(u128::MAX, -127), None);
+
+        // large exponents
+        assert_eq!(
+            I8F8::checked_from_mantissa_exp(1, 6),
+            Some(I8F8::from_num(64))
+        );
+        assert_eq!(I8F8::checked_from_mantissa_exp(1, 7), None);
+        assert_eq!(
+            I8F8::checked_from_mantissa_exp(-1, 7),
+            Some(I8F8::min_value())
+        );
+        assert_eq!(I8F8::checked_from_mantissa_exp(1, i32::MAX), None);
+        assert_eq!(
+            I8F8::checked_from_mantissa_exp(0, i32::MAX),
+            Some(I8F8::from_num(0))
+        );
+        assert_eq!(
+            U8F8::checked_from_mantissa_exp(-1i16 as u16, -8),
+            Some(U8F8::max_value())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn from_mantissa_exp_overflow() {
+        use crate::types::U8F8;
+        let _ = U8F8::from_mantissa_exp(1, 8);
+    }
+
+    #[test]
+    fn accumulator() {
+        use crate::{
+            types::{I0F128, I128F0, I8F8, U128F0, U8F8},
+            FixedAccumulator,
+        };
+
+        let mut acc = FixedAccumulator::<I8F8>::default();
+        assert_eq!((acc.count(), acc.range(), acc.mean()), (0, None, None));
+        assert_eq!(acc.checked_sum(), Some(I8F8::from_num(0)));
+        let mut sum = 0i64;
+        for bits in (i16::MIN..=i16::MAX).step_by(7) {
+            acc.push(I8F8::from_bits(bits));
+            sum += i64::from(bits);
+        }
+        assert_eq!(acc.count(), 9363);
+        assert_eq!(acc.min(), Some(I8F8::min_value()));
+        assert_eq!(acc.max(), Some(I8F8::from_bits(32766)));
+        let fits = i64::from(i16::MIN) <= sum && sum <= i64::from(i16::MAX);
+        assert_eq!(
+            acc.checked_sum(),
+            if fits {
+                Some(I8F8::from_bits(sum as i16))
+            } else {
+                None
+            }
+        );
+        // round(sum / count) with ties to even
+        let count = acc.count() as i64;
+        let (quot, rem) = (sum.div_euclid(count), sum.rem_euclid(count));
+        let mean = quot + i64::from(2 * rem > count || (2 * rem == count && quot % 2 != 0));
+        assert_eq!(acc.mean(), Some(I8F8::from_bits(mean as i16)));
+
+        // ties are rounded to even
+        let mut acc = FixedAccumulator::new();
+        acc.extend([1, 2].iter().map(|&b| U8F8::from_bits(b)));
+        assert_eq!(acc.mean(), Some(U8F8::from_bits(2)));
+        acc.extend([U8F8::from_bits(4), U8F8::from_bits(0)].iter().cloned());
+        assert_eq!(acc.checked_sum(), Some(U8F8::from_bits(7)));
+        // 7 / 4 = 1.75
+        assert_eq!(acc.mean(), Some(U8F8::from_bits(2)));
+        let mut acc = FixedAccumulator::new();
+        acc.extend([-1, -2].iter().map(|&b| I8F8::from_bits(b)));
+        assert_eq!(acc.mean(), Some(I8F8::from_bits(-2)));
+        acc.push(I8F8::max_value());
+        acc.push(I8F8::max_value());
+        assert_eq!(acc.checked_sum(), None);
+
+        // sums far beyond 128 bits
+        let mut acc = FixedAccumulator::new();
+        for _ in 0..1000 {
+            acc.push(U128F0::max_value());
+        }
+        assert_eq!(acc.mean(), Some(U128F0::max_value()));
+        assert_eq!(acc.checked_sum(), None);
+        let mut acc = FixedAccumulator::new();
+        for _ in 0..1000 {
+            acc.push(I128F0::min_value());
+            acc.push(I128F0::max_value());
+        }
+        acc.push(I128F0::min_value());
+        acc.push(I128F0::min_value());
+        // (1002 × min + 1000 × max) / 2002 = (−2^128 − 1000) / 2002 ≈ …601.37
+        let expected = I128F0::from_bits(-169_971_212_248_221_010_720_966_337_378_505_601);
+        assert_eq!(acc.mean(), Some(expected));
+        let mut acc = FixedAccumulator::new();
+        acc.push(I0F128::min_value());
+        acc.push(I0F128::from_bits(1));
+        assert_eq!(acc.checked_sum(), Some(I0F128::from_bits(i128::MIN + 1)));
+        assert_eq!(acc.mean(), Some(I0F128::from_bits(i128::MIN / 2)));
+    }
+
+    #[test]
+    fn wide_sum() {
+        use crate::{
+            types::{I0F128, I128F0, I32F32, U128F0},
+            WideSum,
+        };
+
+        let mut sum = WideSum::<I32F32>::default();
+        assert_eq!(sum.finish(), Some(I32F32::from_num(0)));
+        let mut expected = 0i128;
+        for i in 0..1000i64 {
+            let bits = i * 0x1234_5678_9abc;
+            sum.add(I32F32::from_bits(bits));
+            expected += i128::from(bits);
+        }
+        // the sum is exactly the sum of the bits, which overflows i64
+        assert!(expected > i128::from(i64::MAX));
+        assert_eq!(sum.finish(), None);
+        for i in 0..1000i64 {
+            sum.add(I32F32::from_bits(-i * 0x1234_5678_9abc));
+        }
+        assert_eq!(sum.finish(), Some(I32F32::from_num(0)));
+
+        // intermediate sums far out of range are fine
+        let mut sum = WideSum::new();
+        sum.extend([U128F0::max_value(); 4].iter().cloned());
+        assert_eq!(sum.finish(), None);
+        let mut neg = WideSum::new();
+        neg.extend([I128F0::min_value(); 3].iter().cloned());
+        neg.extend([I128F0::max_value(); 3].iter().cloned());
+        assert_eq!(neg.finish(), Some(I128F0::from_num(-3)));
+        let mut tiny = WideSum::new();
+        tiny.extend([I0F128::min_value(); 2].iter().cloned());
+        assert_eq!(tiny.finish(), None);
+        tiny.add(I0F128::max_value());
+        tiny.add(I0F128::from_bits(1));
+        assert_eq!(tiny.finish(), Some(I0F128::min_value()));
+    }
+
+    #[test]
+    fn try_fold_add() {
+        use crate::types::{I0F8, I8F8, U8F8};
+        use std::format;
+
+        let empty: [I8F8; 0] = [];
+        assert_eq!(
+            I8F8::try_fold_add(empty.iter().cloned()),
+            Ok(I8F8::from_num(0))
+        );
+        let values = [
+            I8F8::from_num(-100),
+            I8F8::from_num(-28),
+            I8F8::from_bits(-1),
+        ];
+        let err = I8F8::try_fold_add(values.iter().cloned()).unwrap_err();
+        assert_eq!(err.index(), 2);
+        assert_eq!(err.partial_sum(), I8F8::min_value());
+        assert_eq!(format!("{}", err), "overflow at index 2");
+        // the first overflow is reported even if later values would bring the sum back
+        let values = [U8F8::from_num(200), U8F8::from_num(100), U8F8::from_num(0)];
+        let err = U8F8::try_fold_add(values.iter().cloned()).unwrap_err();
+        assert_eq!((err.index(), err.partial_sum()), (1, U8F8::from_num(200)));
+        let values = [
+            I0F8::from_num(0.25),
+            I0F8::from_num(-0.5),
+            I0F8::from_num(0.25),
+        ];
+        assert_eq!(
+            I0F8::try_fold_add(values.iter().cloned()),
+            Ok(I0F8::from_num(0))
+        );
+        for a in i8::MIN..=i8::MAX {
+            let (a, b) = (I0F8::from_bits(a), I0F8::from_bits(100));
+            let result = I0F8::try_fold_add([a, b].iter().cloned());
+            match a.checked_add(b) {
+                Some(sum) => assert_eq!(result, Ok(sum)),
+                None => assert_eq!(result.map_err(|err| err.index()), Err(1)),
+            }
+        }
+    }
 }
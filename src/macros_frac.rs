@@ -19,7 +19,7 @@ macro_rules! fixed_frac {
         $Fixed:ident[$s_fixed:expr](
             $Inner:ty[$s_inner:expr], $LeEqU:tt, $s_nbits:expr, $s_nbits_m4:expr
         ),
-        $UInner:ty, $Signedness:tt
+        $UFixed:ident[$s_ufixed:expr]($UInner:ty), $Signedness:tt
     ) => {
         impl<Frac: $LeEqU> $Fixed<Frac> {
             comment! {
@@ -50,50 +50,2222 @@ assert_eq!(Fix::FRAC_NBITS, 6);
                 pub const FRAC_NBITS: u32 = Frac::U32;
             }
 
+            comment! {
+                "The smallest positive value that can be represented.
+
+This is the value with only the least significant bit set, that is
+2<sup>−[`FRAC_NBITS`]</sup>. For unsigned types
+[`min_value`] is zero, so this is the smallest value that is still
+greater than zero.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::MIN_POSITIVE, Fix::from_bits(1));
+assert_eq!(Fix::MIN_POSITIVE, 0.0625);
+assert!(Fix::MIN_POSITIVE > 0);
+```
+
+[`FRAC_NBITS`]: #associatedconstant.FRAC_NBITS
+[`min_value`]: #method.min_value
+";
+                pub const MIN_POSITIVE: $Fixed<Frac> = $Fixed::from_bits(1);
+            }
+
+            comment! {
+                "The scaling factor between the bits and the value,
+that is 2<sup>[`FRAC_NBITS`]</sup>.
+
+A number is equal to its [`to_bits`] divided by `SCALE`. If
+[`FRAC_NBITS`] is 128 the factor does not fit in a [`u128`], and
+`SCALE` is zero.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::SCALE, 16);
+let x = Fix::from_num(2.5);
+assert_eq!(x.to_bits() as u128, 5 * Fix::SCALE / 2);
+```
+
+[`FRAC_NBITS`]: #associatedconstant.FRAC_NBITS
+[`to_bits`]: #method.to_bits
+[`u128`]: https://doc.rust-lang.org/nightly/std/primitive.u128.html
+";
+                pub const SCALE: u128 =
+                    1 << (Self::FRAC_NBITS / 2) << (Self::FRAC_NBITS - Self::FRAC_NBITS / 2);
+            }
+
             // some other useful constants for internal use:
 
-            const INT_MASK: $Inner =
-                !0 << (Self::FRAC_NBITS / 2) << (Self::FRAC_NBITS - Self::FRAC_NBITS / 2);
-            const FRAC_MASK: $Inner = !Self::INT_MASK;
+            const INT_MASK: $Inner =
+                !0 << (Self::FRAC_NBITS / 2) << (Self::FRAC_NBITS - Self::FRAC_NBITS / 2);
+            const FRAC_MASK: $Inner = !Self::INT_MASK;
+
+            // 0 when FRAC_NBITS = 0
+            const INT_LSB: $Inner = Self::INT_MASK ^ (Self::INT_MASK << 1);
+
+            // 0 when INT_NBITS = 0
+            const FRAC_MSB: $Inner =
+                Self::FRAC_MASK ^ ((Self::FRAC_MASK as $UInner) >> 1) as $Inner;
+
+            comment! {
+                "Returns the number of integer bits.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U6, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U6>;
+assert_eq!(Fix::int_nbits(), ", $s_nbits, " - 6);
+```
+";
+                #[inline]
+                pub fn int_nbits() -> u32 {
+                    Self::INT_NBITS
+                }
+            }
+
+            comment! {
+                "Returns the number of fractional bits.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U6, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U6>;
+assert_eq!(Fix::frac_nbits(), 6);
+```
+";
+                #[inline]
+                pub fn frac_nbits() -> u32 {
+                    Self::FRAC_NBITS
+                }
+            }
+
+            comment! {
+                "Returns the scaling factor 2<sup>[`FRAC_NBITS`]</sup>
+as the underlying integer type, which is the bit pattern of one.
+
+Returns [`None`] if one cannot be represented, that is if there are no
+integer bits", if_signed_else_empty_str!($Signedness, " other than the sign bit"), ".
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::{U4, U", $s_nbits, "}, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::scale(), Some(Fix::from_num(1).to_bits()));
+assert_eq!(Fix::scale(), Some(16));
+assert_eq!(", $s_fixed, "::<U", $s_nbits, ">::scale(), None);
+```
+
+[`FRAC_NBITS`]: #associatedconstant.FRAC_NBITS
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+                #[inline]
+                pub fn scale() -> Option<$Inner> {
+                    // INT_LSB is zero or the sign bit if one does not fit
+                    if Self::INT_LSB > 0 {
+                        Some(Self::INT_LSB)
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the Q format of the number as a [tuple] of the
+number of integer bits, the number of fractional bits, and whether the
+type is signed.
+
+This is the same for all values of a type, but taking `self` makes it
+usable where only a value is at hand, for example to display
+“Q", $s_nbits, ".0 ", if_signed_unsigned!($Signedness, "signed", "unsigned"), "”
+for an `", $s_fixed, "<U0>`.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U6, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U6>;
+let (int_nbits, frac_nbits, signed) = Fix::from_num(1).q_format();
+assert_eq!((int_nbits, frac_nbits), (", $s_nbits, " - 6, 6));
+assert_eq!(signed, ", if_signed_unsigned!($Signedness, "true", "false"), ");
+```
+
+[tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+";
+                #[inline]
+                pub fn q_format(self) -> (u32, u32, bool) {
+                    let signed = if_signed_unsigned!($Signedness, true, false);
+                    (Self::INT_NBITS, Self::FRAC_NBITS, signed)
+                }
+            }
+
+            comment! {
+                "Returns a number with all the integer bits set and all
+the fractional bits cleared.
+
+Combined with the bitwise operators, this can be used to build masks
+over the raw representation; for example `x & Fix::int_mask_value()`
+clears the fractional bits of `x`.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::int_mask_value().to_bits(), !0 << 4);
+let x = Fix::from_bits(0b101_1011);
+assert_eq!(x & Fix::int_mask_value(), Fix::from_bits(0b101_0000));
+```
+";
+                #[inline]
+                pub fn int_mask_value() -> $Fixed<Frac> {
+                    Self::from_bits(Self::INT_MASK)
+                }
+            }
+
+            comment! {
+                "Returns a number with all the fractional bits set and
+all the integer bits cleared.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::frac_mask_value().to_bits(), 0b1111);
+let x = Fix::from_bits(0b101_1011);
+assert_eq!(x & Fix::frac_mask_value(), Fix::from_bits(0b1011));
+```
+";
+                #[inline]
+                pub fn frac_mask_value() -> $Fixed<Frac> {
+                    Self::from_bits(Self::FRAC_MASK)
+                }
+            }
+
+            comment! {
+                "Checks that the number is in the inclusive range
+`lo..=hi` when debug assertions are enabled, and returns the number
+unchanged.
+
+This is meant to be sprinkled through numeric code to catch scaling
+mistakes early, for example after constructing a number with
+[`from_bits`]. When debug assertions are not enabled, this is a no-op.
+
+# Panics
+
+When debug assertions are enabled, panics if the number is outside the
+range.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (zero, one) = (Fix::from_num(0), Fix::from_num(1));
+let ratio = Fix::from_bits(0b1100).debug_range(zero, one);
+assert_eq!(ratio, Fix::from_num(0.75));
+```
+
+[`from_bits`]: #method.from_bits
+";
+                #[inline]
+                pub fn debug_range(self, lo: $Fixed<Frac>, hi: $Fixed<Frac>) -> $Fixed<Frac> {
+                    debug_assert!(
+                        lo <= self && self <= hi,
+                        "{} is not in the range {}..={}",
+                        self,
+                        lo,
+                        hi
+                    );
+                    self
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Returns the bits sign-extended to [`i128`], together with the
+number of fractional bits, so that the value is the returned bits
+divided by 2<sup><i>scale</i></sup>.
+
+Many values can be accumulated exactly in [`i128`] and converted back
+once using [`from_wide_bits`], without intermediate overflow or
+rounding.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (bits, scale) = Fix::from_num(2.5).to_wide_bits();
+assert_eq!((bits, scale), (0b10_1000, 4));
+// accumulate in the wide integer, and convert back once
+let values = [Fix::from_num(1.5), Fix::from_num(2.25), Fix::from_num(3)];
+let sum: i128 = values.iter().map(|x| x.to_wide_bits().0).sum();
+assert_eq!(Fix::from_wide_bits(sum, scale), Fix::from_num(6.75));
+```
+
+[`from_wide_bits`]: #method.from_wide_bits
+[`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+";
+                    #[inline]
+                    pub fn to_wide_bits(self) -> (i128, u32) {
+                        (i128::from(self.to_bits()), Self::FRAC_NBITS)
+                    }
+                }
+
+                comment! {
+                    "Creates a fixed-point number from a [`i128`] with
+`frac_nbits` fractional bits, as returned by [`to_wide_bits`].
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if the value does not fit.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (a, scale) = Fix::from_num(1.25).to_wide_bits();
+let (b, _) = Fix::from_num(2.5).to_wide_bits();
+// the product has twice the fractional bits, and is rounded once
+assert_eq!(Fix::from_wide_bits(a * b, 2 * scale), Fix::from_num(3.125));
+// 3/32 lies halfway between 1/16 and 2/16, and is rounded to even
+assert_eq!(Fix::from_wide_bits(3, 5), Fix::from_bits(2));
+let neg = Fix::from_num(-2.5);
+assert_eq!(Fix::from_wide_bits(neg.to_wide_bits().0 * 3, 4), Fix::from_num(-7.5));
+```
+
+[`to_wide_bits`]: #method.to_wide_bits
+[`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+";
+                    #[inline]
+                    pub fn from_wide_bits(bits: i128, frac_nbits: u32) -> $Fixed<Frac> {
+                        match Self::checked_from_wide_bits(bits, frac_nbits) {
+                            Some(val) => val,
+                            None => panic!("overflow"),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Creates a fixed-point number from a [`i128`] with
+`frac_nbits` fractional bits, as returned by [`to_wide_bits`], or
+returns [`None`] if the value does not fit.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (max, scale) = Fix::max_value().to_wide_bits();
+assert_eq!(Fix::checked_from_wide_bits(max, scale), Some(Fix::max_value()));
+// with one fractional bit less, the value is doubled
+assert_eq!(Fix::checked_from_wide_bits(max, scale - 1), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`to_wide_bits`]: #method.to_wide_bits
+[`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+";
+                    #[inline]
+                    pub fn checked_from_wide_bits(
+                        bits: i128,
+                        frac_nbits: u32,
+                    ) -> Option<$Fixed<Frac>> {
+                        let (neg, abs) = bits.neg_abs();
+                        let wide = if frac_nbits >= Self::FRAC_NBITS {
+                            // abs < 2^128, so shifting right by more than
+                            // 128 always rounds to zero
+                            match frac_nbits - Self::FRAC_NBITS {
+                                shr @ 0..=128 => helpers::mul_shr_round(neg, abs, 1, shr)?,
+                                _ => 0,
+                            }
+                        } else {
+                            match Self::FRAC_NBITS - frac_nbits {
+                                shl @ 0..=127 => helpers::mul_shr_round(neg, abs, 1 << shl, 0)?,
+                                _ if bits == 0 => 0,
+                                _ => return None,
+                            }
+                        };
+                        let inner = wide as $Inner;
+                        if i128::from(inner) == wide {
+                            Some(Self::from_bits(inner))
+                        } else {
+                            None
+                        }
+                    }
+                }
+            }
+
+            if_unsigned! {
+                $Signedness;
+                comment! {
+                    "Returns the bits widened to [`u128`], together with the
+number of fractional bits, so that the value is the returned bits
+divided by 2<sup><i>scale</i></sup>.
+
+Many values can be accumulated exactly in [`u128`] and converted back
+once using [`from_wide_bits`], without intermediate overflow or
+rounding.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (bits, scale) = Fix::from_num(2.5).to_wide_bits();
+assert_eq!((bits, scale), (0b10_1000, 4));
+// accumulate in the wide integer, and convert back once
+let values = [Fix::from_num(1.5), Fix::from_num(2.25), Fix::from_num(3)];
+let sum: u128 = values.iter().map(|x| x.to_wide_bits().0).sum();
+assert_eq!(Fix::from_wide_bits(sum, scale), Fix::from_num(6.75));
+```
+
+[`from_wide_bits`]: #method.from_wide_bits
+[`u128`]: https://doc.rust-lang.org/nightly/std/primitive.u128.html
+";
+                    #[inline]
+                    pub fn to_wide_bits(self) -> (u128, u32) {
+                        (u128::from(self.to_bits()), Self::FRAC_NBITS)
+                    }
+                }
+
+                comment! {
+                    "Creates a fixed-point number from a [`u128`] with
+`frac_nbits` fractional bits, as returned by [`to_wide_bits`].
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if the value does not fit.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (a, scale) = Fix::from_num(1.25).to_wide_bits();
+let (b, _) = Fix::from_num(2.5).to_wide_bits();
+// the product has twice the fractional bits, and is rounded once
+assert_eq!(Fix::from_wide_bits(a * b, 2 * scale), Fix::from_num(3.125));
+// 3/32 lies halfway between 1/16 and 2/16, and is rounded to even
+assert_eq!(Fix::from_wide_bits(3, 5), Fix::from_bits(2));
+```
+
+[`to_wide_bits`]: #method.to_wide_bits
+[`u128`]: https://doc.rust-lang.org/nightly/std/primitive.u128.html
+";
+                    #[inline]
+                    pub fn from_wide_bits(bits: u128, frac_nbits: u32) -> $Fixed<Frac> {
+                        match Self::checked_from_wide_bits(bits, frac_nbits) {
+                            Some(val) => val,
+                            None => panic!("overflow"),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Creates a fixed-point number from a [`u128`] with
+`frac_nbits` fractional bits, as returned by [`to_wide_bits`], or
+returns [`None`] if the value does not fit.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (max, scale) = Fix::max_value().to_wide_bits();
+assert_eq!(Fix::checked_from_wide_bits(max, scale), Some(Fix::max_value()));
+// with one fractional bit less, the value is doubled
+assert_eq!(Fix::checked_from_wide_bits(max, scale - 1), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`to_wide_bits`]: #method.to_wide_bits
+[`u128`]: https://doc.rust-lang.org/nightly/std/primitive.u128.html
+";
+                    #[inline]
+                    pub fn checked_from_wide_bits(
+                        bits: u128,
+                        frac_nbits: u32,
+                    ) -> Option<$Fixed<Frac>> {
+                        let wide = if frac_nbits >= Self::FRAC_NBITS {
+                            // bits < 2^128, so shifting right by more than
+                            // 128 always rounds to zero
+                            match frac_nbits - Self::FRAC_NBITS {
+                                shr @ 0..=128 => helpers::mul_shr_round_abs(bits, 1, shr)?,
+                                _ => 0,
+                            }
+                        } else {
+                            match Self::FRAC_NBITS - frac_nbits {
+                                shl @ 0..=127 => helpers::mul_shr_round_abs(bits, 1 << shl, 0)?,
+                                _ if bits == 0 => 0,
+                                _ => return None,
+                            }
+                        };
+                        let inner = wide as $Inner;
+                        if u128::from(inner) == wide {
+                            Some(Self::from_bits(inner))
+                        } else {
+                            None
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from raw bits that have
+`SrcFrac` fractional bits instead of `Frac` fractional bits.
+
+This is useful when reading data stored with a different Q format
+than the current type. If `SrcFrac` is larger than `Frac`, the
+extra fractional bits are rounded to the nearest, with ties rounded
+to even.
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not
+fit. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`from_bits`] and
+[`wrapping_shl`] instead.
+
+# Examples
+
+```rust
+use substrate_fixed::{
+    types::extra::{U2, U4, U6},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+// 1.5 with two fractional bits
+assert_eq!(Fix::rescale_from::<U2>(0b110), Fix::from_bits(0b1_1000));
+// 1.5 with six fractional bits
+assert_eq!(Fix::rescale_from::<U6>(0b1_100000), Fix::from_bits(0b1_1000));
+// 0b11_1010 / 4 = 14.5 is rounded to 14 (even)
+assert_eq!(Fix::rescale_from::<U6>(0b11_1010), Fix::from_bits(14));
+// 0b11_1011 / 4 = 14.75 is rounded to 15
+assert_eq!(Fix::rescale_from::<U6>(0b11_1011), Fix::from_bits(15));
+```
+
+[`from_bits`]: #method.from_bits
+[`wrapping_shl`]: #method.wrapping_shl
+";
+                #[inline]
+                pub fn rescale_from<SrcFrac: $LeEqU>(bits: $Inner) -> $Fixed<Frac> {
+                    let src_frac_nbits = SrcFrac::U32;
+                    if src_frac_nbits <= Self::FRAC_NBITS {
+                        return Self::from_num($Fixed::<SrcFrac>::from_bits(bits));
+                    }
+                    // 1 ≤ shift ≤ nbits, so split shifts to avoid overflow
+                    let shift = src_frac_nbits - Self::FRAC_NBITS;
+                    let nbits = Self::INT_NBITS + Self::FRAC_NBITS;
+                    let floor = bits >> (shift - 1) >> 1;
+                    let rem = bits as $UInner & (!0 as $UInner >> (nbits - shift));
+                    let half = 1 as $UInner << (shift - 1);
+                    let round_up = rem > half || (rem == half && floor & 1 != 0);
+                    // cannot overflow as floor ≤ MAX / 2
+                    Self::from_bits(floor + round_up as $Inner)
+                }
+            }
+
+            comment! {
+                "Multiplies the number by the integer `denom` and returns
+the result as an [`i128`], or [`None`] if the result does not fit.
+
+This can be used to convert a number to an integer count of `1/denom`
+units, for example to basis points with `denom` = 10000. The product is
+computed without intermediate overflow, and rounding is to the nearest,
+with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(1.75).to_scaled(100), Some(175));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-1.75).to_scaled(100), Some(-175));
+",
+                },
+                "// 0.5 is rounded to 0 (even), 1.5 is rounded to 2 (even)
+assert_eq!(Fix::from_num(0.5).to_scaled(1), Some(0));
+assert_eq!(Fix::from_num(1.5).to_scaled(1), Some(2));
+assert_eq!(Fix::max_value().to_scaled(u128::max_value()), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+";
+                #[inline]
+                pub fn to_scaled(self, denom: u128) -> Option<i128> {
+                    let (neg, abs) = self.to_bits().neg_abs();
+                    helpers::mul_shr_round(neg, abs.into(), denom, Self::FRAC_NBITS)
+                }
+            }
+
+            comment! {
+                "Converts the number to basis points, that is
+hundredths of a percent, returning [`None`] if the result does not fit
+in an [`i64`].
+
+This is [`to_scaled`] with `denom` = 10000, so rounding is to the
+nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(1.25).to_basis_points(), Some(12_500));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-0.0625).to_basis_points(), Some(-625));
+",
+                },
+                "```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`i64`]: https://doc.rust-lang.org/nightly/std/primitive.i64.html
+[`to_scaled`]: #method.to_scaled
+";
+                #[inline]
+                pub fn to_basis_points(self) -> Option<i64> {
+                    let scaled = self.to_scaled(10_000)?;
+                    let bps = scaled as i64;
+                    if i128::from(bps) == scaled {
+                        Some(bps)
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from basis points, that is
+hundredths of a percent.
+
+This is the inverse of [`to_basis_points`]. The value `bps`/10000 is
+rounded to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if the value does not fit.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_basis_points(12_500), Fix::from_num(1.25));
+// 1 basis point is much less than 1/16, and is rounded to zero
+assert_eq!(Fix::from_basis_points(1), Fix::from_num(0));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_basis_points(-625), Fix::from_num(-0.0625));
+",
+                },
+                "```
+
+[`to_basis_points`]: #method.to_basis_points
+";
+                #[inline]
+                pub fn from_basis_points(bps: i64) -> $Fixed<Frac> {
+                    match Self::checked_from_basis_points(bps) {
+                        Some(val) => val,
+                        None => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from basis points, that is
+hundredths of a percent, returning [`None`] if the value does not fit.
+
+See [`from_basis_points`] for details.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::checked_from_basis_points(12_500), Some(Fix::from_num(1.25)));
+// without integer bits, 2 = 20000 basis points cannot be represented
+use substrate_fixed::types::extra::U", $s_nbits, ";
+type Ratio = ", $s_fixed, "<U", $s_nbits, ">;
+assert_eq!(Ratio::checked_from_basis_points(20_000), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`from_basis_points`]: #method.from_basis_points
+";
+                #[inline]
+                pub fn checked_from_basis_points(bps: i64) -> Option<$Fixed<Frac>> {
+                    let (neg, abs) = bps.neg_abs();
+                    let abs = helpers::shl_div_round_abs(abs.into(), Self::FRAC_NBITS, 10_000)?;
+                    Self::private_checked_from_neg_abs(neg, abs)
+                }
+            }
+
+            comment! {
+                "Returns what percentage of `whole` the number is,
+that is `self` / `whole` × 100, or [`None`] if `whole` is zero or if
+the result does not fit.
+
+The result is computed in a wide intermediate and rounded only once, to
+the nearest with ties rounded to even, so it is more accurate than
+dividing and then multiplying, and it does not overflow in between.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 0.125 is 6.25% of 2
+assert_eq!(Fix::from_num(0.125).percent_of(Fix::from_num(2)), Some(Fix::from_num(6.25)));
+// 1/16 is 2.0833…% of 3, which is rounded to 33/16
+assert_eq!(Fix::from_bits(1).percent_of(Fix::from_num(3)), Some(Fix::from_bits(33)));
+assert_eq!(Fix::from_num(1).percent_of(Fix::from_num(0)), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+                #[inline]
+                pub fn percent_of(self, whole: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                    let (neg, abs) = self.to_bits().neg_abs();
+                    let (whole_neg, whole_abs) = whole.to_bits().neg_abs();
+                    if whole_abs == 0 {
+                        return None;
+                    }
+                    let abs = helpers::mul_shl_div_round_abs(
+                        abs.into(),
+                        100,
+                        Self::FRAC_NBITS,
+                        whole_abs.into(),
+                    )?;
+                    Self::private_checked_from_neg_abs(neg != whole_neg, abs)
+                }
+            }
+
+            comment! {
+                "Returns the number as an odd mantissa and a binary
+exponent, such that the mantissa × 2<sup>exponent</sup> is exactly
+equal to the number.
+
+The mantissa is [`to_bits`] with its trailing zero bits removed, and
+the exponent is the number of removed bits minus [`FRAC_NBITS`]. Zero
+is returned as (0, 0). This is the inverse of [`from_mantissa_exp`].
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 2.5 is 5 × 2^−1
+assert_eq!(Fix::from_num(2.5).to_mantissa_exp(), (5, -1));
+// 0.0625 is 1 × 2^−4
+assert_eq!(Fix::from_bits(1).to_mantissa_exp(), (1, -4));
+assert_eq!(Fix::from_num(4).to_mantissa_exp(), (1, 2));
+assert_eq!(Fix::from_num(0).to_mantissa_exp(), (0, 0));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-0.75).to_mantissa_exp(), (-3, -2));
+",
+                },
+                "```
+
+[`FRAC_NBITS`]: #associatedconstant.FRAC_NBITS
+[`from_mantissa_exp`]: #method.from_mantissa_exp
+[`to_bits`]: #method.to_bits
+";
+                #[inline]
+                pub fn to_mantissa_exp(self) -> ($Inner, i32) {
+                    let bits = self.to_bits();
+                    if bits == 0 {
+                        return (0, 0);
+                    }
+                    let zeros = bits.trailing_zeros();
+                    (bits >> zeros, zeros as i32 - Self::FRAC_NBITS as i32)
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from a mantissa and a
+binary exponent, that is the value mantissa × 2<sup>exponent</sup>.
+
+This is the inverse of [`to_mantissa_exp`], but the mantissa does not
+have to be odd. If the value has more fractional bits than the type,
+it is rounded to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if the value does not fit.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_mantissa_exp(5, -1), Fix::from_num(2.5));
+let val = Fix::from_bits(0b10_1100);
+let (mantissa, exp) = val.to_mantissa_exp();
+assert_eq!(Fix::from_mantissa_exp(mantissa, exp), val);
+// 3 × 2^−5 = 1.5 × 2^−4, which is rounded to 2 × 2^−4 (even)
+assert_eq!(Fix::from_mantissa_exp(3, -5), Fix::from_bits(2));
+```
+
+[`to_mantissa_exp`]: #method.to_mantissa_exp
+";
+                #[inline]
+                pub fn from_mantissa_exp(mantissa: $Inner, exp: i32) -> $Fixed<Frac> {
+                    match Self::checked_from_mantissa_exp(mantissa, exp) {
+                        Some(val) => val,
+                        None => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from a mantissa and a
+binary exponent, returning [`None`] if the value does not fit.
+
+See [`from_mantissa_exp`] for details.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::checked_from_mantissa_exp(5, -1), Some(Fix::from_num(2.5)));
+assert_eq!(Fix::checked_from_mantissa_exp(1, ", $s_nbits, "), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`from_mantissa_exp`]: #method.from_mantissa_exp
+";
+                #[inline]
+                pub fn checked_from_mantissa_exp(
+                    mantissa: $Inner,
+                    exp: i32,
+                ) -> Option<$Fixed<Frac>> {
+                    let (neg, abs) = mantissa.neg_abs();
+                    let abs = u128::from(abs);
+                    let shift = i64::from(exp) + i64::from(Self::FRAC_NBITS);
+                    let abs = if abs == 0 {
+                        0
+                    } else if shift >= 0 {
+                        if shift >= 128 || i64::from(abs.leading_zeros()) < shift {
+                            return None;
+                        }
+                        abs << shift
+                    } else if shift >= -128 {
+                        helpers::mul_shr_round_abs(abs, 1, (-shift) as u32)?
+                    } else {
+                        // less than half the least significant bit
+                        0
+                    };
+                    Self::private_checked_from_neg_abs(neg, abs)
+                }
+            }
+
+            comment! {
+                "Writes the decimal representation of the number with
+exactly `frac_digits` fractional digits into a [`Write`] implementor.
+
+This does not allocate, so it can be used to format into a fixed-size
+buffer in `no_std` environments. The output is the same as formatting
+with [`Display`] and a precision of `frac_digits`, so rounding is to the
+nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let mut s = String::new();
+Fix::from_num(1.75).write_to(&mut s, 3).unwrap();
+assert_eq!(s, \"1.750\");
+s.clear();
+// 1.75 is rounded to 1.8 (even)
+Fix::from_num(1.75).write_to(&mut s, 1).unwrap();
+assert_eq!(s, \"1.8\");
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "s.clear();
+Fix::from_num(-1.75).write_to(&mut s, 0).unwrap();
+assert_eq!(s, \"-2\");
+",
+                },
+                "```
+
+[`Display`]: https://doc.rust-lang.org/nightly/core/fmt/trait.Display.html
+[`Write`]: https://doc.rust-lang.org/nightly/core/fmt/trait.Write.html
+";
+                #[inline]
+                pub fn write_to<W: fmt::Write>(self, w: &mut W, frac_digits: usize) -> fmt::Result {
+                    w.write_fmt(format_args!("{:.*}", frac_digits, self))
+                }
+            }
+
+            comment! {
+                "Returns an iterator over the exact decimal digits of the
+fractional part of the absolute value.
+
+Every binary fraction has a terminating decimal expansion, so the
+iterator is finite: it yields at most [`FRAC_NBITS`] digits, and stops
+after the last non-zero digit. No digits are yielded if the fractional
+part is zero.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1/16 = 0.0625
+let digits: Vec<u8> = Fix::from_bits(1).decimal_digits().collect();
+assert_eq!(digits, [0, 6, 2, 5]);
+assert_eq!(Fix::from_num(2.5).decimal_digits().collect::<Vec<_>>(), [5]);
+assert_eq!(Fix::from_num(3).decimal_digits().count(), 0);
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-2.75).decimal_digits().collect::<Vec<_>>(), [7, 5]);
+",
+                },
+                "```
+
+[`FRAC_NBITS`]: #associatedconstant.FRAC_NBITS
+";
+                #[inline]
+                pub fn decimal_digits(self) -> impl Iterator<Item = u8> {
+                    let (_, abs) = self.to_bits().neg_abs();
+                    // align the fractional bits to the most significant bit, so
+                    // that multiplying by 10 pushes the next digit out at the top
+                    let mut frac = abs.checked_shl(Self::INT_NBITS).unwrap_or(0);
+                    iter::from_fn(move || {
+                        if frac == 0 {
+                            None
+                        } else {
+                            Some(frac.mul10_assign())
+                        }
+                    })
+                }
+            }
+
+            comment! {
+                "Formats the number in engineering notation with
+`sig_figs` significant digits.
+
+The exponent is a multiple of three and is written as an SI prefix, for
+example `k` for 10<sup>3</sup> and `m` for 10<sup>−3</sup>, with `µ`
+used for 10<sup>−6</sup>. Exponents outside the range of SI prefixes
+are written as `e` followed by the exponent. Trailing zeros are kept to
+show the number of significant digits. Rounding is to the nearest, with
+ties rounded to even.
+
+This method is only available when the `alloc` feature is enabled.
+
+# Panics
+
+Panics if `sig_figs` is zero.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.5).to_eng_string(3), \"2.50\");
+assert_eq!(Fix::from_num(0.0625).to_eng_string(3), \"62.5m\");
+assert_eq!(Fix::from_num(0.0625).to_eng_string(1), \"60m\");
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-0.75).to_eng_string(2), \"-750m\");
+",
+                },
+                "```
+";
+                #[cfg(feature = "alloc")]
+                #[inline]
+                pub fn to_eng_string(self, sig_figs: usize) -> String {
+                    let (neg, abs) = self.to_bits().neg_abs();
+                    let int_abs = abs.checked_shr(Self::FRAC_NBITS).unwrap_or(0);
+                    display::eng_string(neg, int_abs.into(), self.decimal_digits(), sig_figs)
+                }
+            }
+
+            comment! {
+                "Formats the number with the fewest decimal digits that
+still parse back to the same number.
+
+Unlike the exact decimal expansion, which can have as many fractional
+digits as there are fractional bits, this keeps only the digits needed
+to identify the number, so it is suitable for compact and lossless
+serialization. Among the candidates with the fewest digits, the one
+nearest to the number is chosen.
+
+This method is only available when the `alloc` feature is enabled.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 0.1 is not exact, but parses back to the same number
+let x: Fix = \"0.1\".parse().unwrap();
+assert_eq!(x, Fix::from_num(0.125));
+assert_eq!(x.to_shortest_string(), \"0.1\");
+assert_eq!(Fix::from_num(2).to_shortest_string(), \"2\");
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-2.5).to_shortest_string(), \"-2.5\");
+",
+                },
+                "```
+";
+                #[cfg(feature = "alloc")]
+                #[inline]
+                pub fn to_shortest_string(self) -> String {
+                    let (neg, abs) = self.to_bits().neg_abs();
+                    let int_abs = abs.checked_shr(Self::FRAC_NBITS).unwrap_or(0);
+                    let frac_digits: Vec<u8> = self.decimal_digits().collect();
+                    let round_trips = |s: &str| s.parse::<$Fixed<Frac>>() == Ok(self);
+                    display::shortest_string(neg, int_abs.into(), &frac_digits, round_trips)
+                }
+            }
+
+            comment! {
+                "Converts the fixed-point number to an arbitrary-precision
+rational number exactly.
+
+The result is the bitwise representation divided by
+2<sup>[`FRAC_NBITS`]</sup>, so no rounding takes place.
+
+This method is only available when the `num-rational` feature is
+enabled.
+
+# Examples
+
+```rust
+use num_rational::BigRational;
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 2.5 is 0010.1000, that is 40/16 = 5/2
+let expected = BigRational::new(5.into(), 2.into());
+assert_eq!(Fix::from_num(2.5).to_big_rational(), expected);
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-2.5).to_big_rational(), -expected);
+",
+                },
+                "```
+
+[`FRAC_NBITS`]: #associatedconstant.FRAC_NBITS
+";
+                #[cfg(feature = "num-rational")]
+                #[inline]
+                pub fn to_big_rational(self) -> BigRational {
+                    let bits = BigRational::from_integer(self.to_bits().into());
+                    bits / BigRational::from_integer(2.into()).pow(Self::FRAC_NBITS as i32)
+                }
+            }
+
+            comment! {
+                "Returns the first terms of the continued fraction of the
+number, together with the number of terms.
+
+The number is exactly
+<i>a</i><sub>0</sub> + 1/(<i>a</i><sub>1</sub> + 1/(<i>a</i><sub>2</sub> + …)),
+where <i>a</i><sub>0</sub> is the floor of the number and the later
+terms are positive. As the number is rational the expansion is finite;
+if it has more than `N` terms only the first `N` are stored, and
+truncating the expansion gives the best rational approximations, such
+as 22/7 for π. The expansion also stops early if a term does not fit in
+an [`i128`], which can only happen for numbers of very large or very
+small magnitude.
+
+See also [`continued_fraction`], which returns a vector when the
+`alloc` feature is enabled.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 3.25 = 3 + 1/4
+let (terms, len) = Fix::from_num(3.25).continued_fraction_array::<4>();
+assert_eq!(&terms[..len], &[3, 4]);
+// 0.6875 = 11/16 = 0 + 1/(1 + 1/(2 + 1/5))
+let (terms, len) = Fix::from_num(0.6875).continued_fraction_array::<2>();
+assert_eq!(&terms[..len], &[0, 1]);
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "// −2.75 = −3 + 1/4
+let (terms, len) = Fix::from_num(-2.75).continued_fraction_array::<4>();
+assert_eq!(&terms[..len], &[-3, 4]);
+",
+                },
+                "```
+
+[`continued_fraction`]: #method.continued_fraction
+[`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+";
+                #[inline]
+                pub fn continued_fraction_array<const N: usize>(self) -> ([i128; N], usize) {
+                    let mut terms = [0; N];
+                    let mut len = 0;
+                    for (term, val) in terms.iter_mut().zip(self.continued_fraction_terms()) {
+                        *term = val;
+                        len += 1;
+                    }
+                    (terms, len)
+                }
+            }
+
+            comment! {
+                "Returns at most `max_terms` terms of the continued
+fraction of the number.
+
+The terms are the same as for [`continued_fraction_array`].
+
+This method is only available when the `alloc` feature is enabled.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 0.6875 = 11/16 = 0 + 1/(1 + 1/(2 + 1/5))
+assert_eq!(Fix::from_num(0.6875).continued_fraction(8), [0, 1, 2, 5]);
+assert_eq!(Fix::from_num(0.6875).continued_fraction(2), [0, 1]);
+assert_eq!(Fix::from_num(2).continued_fraction(8), [2]);
+```
+
+[`continued_fraction_array`]: #method.continued_fraction_array
+";
+                #[cfg(feature = "alloc")]
+                #[inline]
+                pub fn continued_fraction(self, max_terms: usize) -> Vec<i128> {
+                    self.continued_fraction_terms().take(max_terms).collect()
+                }
+            }
+
+            #[inline]
+            fn continued_fraction_terms(self) -> impl Iterator<Item = i128> {
+                let bits = self.to_bits();
+                let frac = u128::from((bits & Self::FRAC_MASK) as $UInner);
+                let int = if_signed_unsigned! {
+                    $Signedness,
+                    {
+                        // the floor is −1 or 0 if all bits are fractional
+                        let bits = i128::from(bits);
+                        Some(bits.checked_shr(Self::FRAC_NBITS).unwrap_or(bits >> 127))
+                    },
+                    {
+                        let int = u128::from(bits).checked_shr(Self::FRAC_NBITS).unwrap_or(0);
+                        if int <= i128::MAX as u128 {
+                            Some(int as i128)
+                        } else {
+                            None
+                        }
+                    },
+                };
+                helpers::continued_fraction(int, frac, Self::FRAC_NBITS)
+            }
+
+            comment! {
+                "Returns an iterator over the positions of the set bits
+in the representation, from the least significant to the most
+significant.
+
+Positions are relative to the binary point: bit <i>i</i> of
+[`to_bits`] is at position <i>i</i> − [`FRAC_NBITS`], so the bit for 1
+is at position 0, the bit for 2 is at position 1, and the bit for ½ is at
+position −1. A set bit at position <i>p</i> thus stands for
+2<sup><i>p</i></sup>",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    ", except for the sign bit, which stands for
+−2<sup><i>p</i></sup> as the representation is two’s complement",
+                },
+                ".
+
+The number can be rebuilt by setting the bits at the yielded positions,
+which makes this useful for sparse encodings.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 5.25 is 0101.0100
+let positions: Vec<i32> = Fix::from_num(5.25).set_bit_positions().collect();
+assert_eq!(positions, [-2, 0, 2]);
+assert_eq!(Fix::from_num(0).set_bit_positions().count(), 0);
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "// −0.5 is 1…1111.1000, with every bit from −1 upwards set
+let positions = Fix::from_num(-0.5).set_bit_positions();
+assert!(positions.eq(-1..", $s_nbits, " - 4));
+",
+                },
+                "```
+
+[`FRAC_NBITS`]: #associatedconstant.FRAC_NBITS
+[`to_bits`]: #method.to_bits
+";
+                #[inline]
+                pub fn set_bit_positions(self) -> impl Iterator<Item = i32> {
+                    let mut bits = self.to_bits() as $UInner;
+                    iter::from_fn(move || {
+                        if bits == 0 {
+                            return None;
+                        }
+                        let index = bits.trailing_zeros();
+                        // clear the lowest set bit
+                        bits &= bits - 1;
+                        Some(index as i32 - Self::FRAC_NBITS as i32)
+                    })
+                }
+            }
+
+            comment! {
+                "Reverses the order of the fractional bits, leaving the
+integer bits unchanged.
+
+The fractional bit for 2<sup>−1</sup> is swapped with the one for
+2<sup>−[`FRAC_NBITS`]</sup>, the bit for 2<sup>−2</sup> with the one for
+2<sup>−([`FRAC_NBITS`] − 1)</sup>, and so on. This is useful for formats
+that store the fraction with the most significant bit last. Reversing
+twice gives the original number.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 5.25 is 0101.0100, and 0101.0010 is 5.125
+let x = Fix::from_num(5.25);
+assert_eq!(x.reverse_frac_bits(), Fix::from_num(5.125));
+assert_eq!(x.reverse_frac_bits().reverse_frac_bits(), x);
+```
+
+[`FRAC_NBITS`]: #associatedconstant.FRAC_NBITS
+";
+                #[inline]
+                pub fn reverse_frac_bits(self) -> $Fixed<Frac> {
+                    let bits = self.to_bits();
+                    let frac = (bits & Self::FRAC_MASK) as $UInner;
+                    // reversing all the bits moves the fractional bits to
+                    // the top, so they are shifted back down
+                    let reversed = frac.reverse_bits().checked_shr(Self::INT_NBITS).unwrap_or(0);
+                    Self::from_bits((bits & Self::INT_MASK) | reversed as $Inner)
+                }
+            }
+
+            comment! {
+                "Reverses the order of all the bits of the representation,
+ignoring the position of the binary point.
+
+This is [`from_bits`] applied to the reversed bits of [`to_bits`], so the
+least significant bit becomes the most significant bit",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    ", which is the sign bit",
+                },
+                ". Reversing twice gives the original number.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let x = Fix::from_bits(1).reverse_all_bits();
+assert_eq!(x.to_bits().leading_zeros(), 0);
+assert_eq!(x.to_bits().count_ones(), 1);
+assert_eq!(x.reverse_all_bits(), Fix::from_bits(1));
+```
+
+[`from_bits`]: #method.from_bits
+[`to_bits`]: #method.to_bits
+";
+                #[inline]
+                pub fn reverse_all_bits(self) -> $Fixed<Frac> {
+                    Self::from_bits(self.to_bits().reverse_bits())
+                }
+            }
+
+            comment! {
+                "Returns the number of digits in the integer part of the
+absolute value when written in the given radix.
+
+This is at least one, as a zero integer part is written as one digit.
+The sign is not counted.
+
+# Panics
+
+Panics if `radix` is not in the range from 2 to 36 inclusive.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(0.5).int_digits(10), 1);
+assert_eq!(Fix::from_num(7.5).int_digits(10), 1);
+assert_eq!(Fix::from_num(7.5).int_digits(2), 3);
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-4).int_digits(2), 3);
+",
+                },
+                "assert_eq!(Fix::max_value().int_digits(2), ",
+                if_signed_unsigned! {
+                    $Signedness,
+                    "Fix::INT_NBITS - 1",
+                    "Fix::INT_NBITS",
+                },
+                ");
+```
+";
+                #[inline]
+                pub fn int_digits(self, radix: u32) -> u32 {
+                    assert!(
+                        (2..=36).contains(&radix),
+                        "radix must be in the range 2..=36, not {}",
+                        radix
+                    );
+                    let (_, abs) = self.to_bits().neg_abs();
+                    let mut int = abs.checked_shr(Self::FRAC_NBITS).unwrap_or(0);
+                    let radix = radix as $UInner;
+                    let mut digits = 1;
+                    while int >= radix {
+                        int /= radix;
+                        digits += 1;
+                    }
+                    digits
+                }
+            }
+
+            comment! {
+                "Returns the decimal order of magnitude of the absolute
+value, or [`None`] if the number is zero.
+
+The order of magnitude is the exponent <i>m</i> for which
+10<sup><i>m</i></sup> ≤ |`self`| < 10<sup><i>m</i>+1</sup>. For
+numbers of one or larger, this is one less than the number of decimal
+digits of the integer part. For numbers below one, it is negative: the
+first non-zero digit after the decimal point is in position −<i>m</i>,
+so for example 0.05 has an order of magnitude of −2. The result is
+exact, as it does not go through a floating-point logarithm.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(1).magnitude(), Some(0));
+assert_eq!(Fix::from_num(7.5).magnitude(), Some(0));
+assert_eq!(Fix::from_num(0.5).magnitude(), Some(-1));
+// 1/16 = 0.0625
+assert_eq!(Fix::from_bits(1).magnitude(), Some(-2));
+assert_eq!(Fix::from_num(0).magnitude(), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-0.5).magnitude(), Some(-1));
+",
+                },
+                "```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+                #[inline]
+                pub fn magnitude(self) -> Option<i32> {
+                    let (_, abs) = self.to_bits().neg_abs();
+                    if abs == 0 {
+                        return None;
+                    }
+                    if abs.checked_shr(Self::FRAC_NBITS).unwrap_or(0) != 0 {
+                        return Some(self.int_digits(10) as i32 - 1);
+                    }
+                    let zeros = self.decimal_digits().take_while(|&d| d == 0).count();
+                    Some(-(zeros as i32) - 1)
+                }
+            }
+
+            comment! {
+                "Linearly interpolates `x` in a lookup table of
+`(input, output)` pairs.
+
+The table must be sorted by input in ascending order. The segment
+bracketing `x` is found using a binary search, and the output is
+interpolated linearly between the two end points of the segment, with
+rounding to the nearest and ties rounded to even. If `x` is equal to an
+input in the table, the corresponding output is returned exactly.
+
+The interpolation cannot overflow, as the result always lies between
+the outputs of the two end points.
+
+If `x` lies outside the range of the table inputs, the first or last
+output is returned if `clamp` is [`true`], otherwise [`None`] is
+returned. [`None`] is also returned if the table is empty.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let table = [
+    (Fix::from_num(0), Fix::from_num(2)),
+    (Fix::from_num(4), Fix::from_num(6)),
+    (Fix::from_num(6), Fix::from_num(5)),
+];
+assert_eq!(Fix::interpolate(&table, Fix::from_num(1), false), Some(Fix::from_num(3)));
+assert_eq!(Fix::interpolate(&table, Fix::from_num(4), false), Some(Fix::from_num(6)));
+assert_eq!(Fix::interpolate(&table, Fix::from_num(5), false), Some(Fix::from_num(5.5)));
+assert_eq!(Fix::interpolate(&table, Fix::from_num(7), false), None);
+assert_eq!(Fix::interpolate(&table, Fix::from_num(7), true), Some(Fix::from_num(5)));
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`true`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+";
+                #[inline]
+                pub fn interpolate(
+                    table: &[($Fixed<Frac>, $Fixed<Frac>)],
+                    x: $Fixed<Frac>,
+                    clamp: bool,
+                ) -> Option<$Fixed<Frac>> {
+                    let index = match table.binary_search_by(|entry| entry.0.cmp(&x)) {
+                        Ok(index) => return Some(table[index].1),
+                        Err(index) => index,
+                    };
+                    if index == 0 || index == table.len() {
+                        if !clamp {
+                            return None;
+                        }
+                        return table.get(index.saturating_sub(1)).map(|entry| entry.1);
+                    }
+                    let (x0, y0) = table[index - 1];
+                    let (x1, y1) = table[index];
+                    // x0 < x < x1, so both differences fit in the unsigned type
+                    let span = x1.to_bits().wrapping_sub(x0.to_bits()) as $UInner;
+                    let dx = x.to_bits().wrapping_sub(x0.to_bits()) as $UInner;
+                    let (neg, dy) = if y1 >= y0 {
+                        (false, y1.to_bits().wrapping_sub(y0.to_bits()) as $UInner)
+                    } else {
+                        (true, y0.to_bits().wrapping_sub(y1.to_bits()) as $UInner)
+                    };
+                    // delta ≤ dy, so the result lies between y0 and y1
+                    let delta = helpers::mul_div_round(dy as u128, dx as u128, span as u128) as $Inner;
+                    let bits = if neg {
+                        y0.to_bits().wrapping_sub(delta)
+                    } else {
+                        y0.to_bits().wrapping_add(delta)
+                    };
+                    Some(Self::from_bits(bits))
+                }
+            }
+
+            comment! {
+                "Returns an iterator over `n` values evenly spaced from
+`start` to `end`.
+
+The first value is `start` and the last value is exactly `end`. The
+values in between are separated by a step of
+(`end` − `start`) / (`n` − 1) rounded towards zero, so when the step is
+not exact the gap before `end` is slightly larger than the others. If
+`n` is 1, only `start` is yielded.
+",
+                if_signed_unsigned! {
+                    $Signedness,
+                    "
+If `end` < `start`, the values decrease.
+",
+                    "
+# Panics
+
+Panics if `n` > 2 and `end` < `start`, since the step of unsigned
+numbers cannot be negative.
+",
+                },
+                "
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let v: Vec<f32> = Fix::linspace(Fix::from_num(1), Fix::from_num(3), 5)
+    .map(Fix::to_num)
+    .collect();
+assert_eq!(v, [1.0, 1.5, 2.0, 2.5, 3.0]);
+// the step 1/3 is rounded down to 5/16
+let v: Vec<Fix> = Fix::linspace(Fix::from_num(0), Fix::from_num(1), 4).collect();
+assert_eq!(v, [Fix::from_num(0), Fix::from_bits(5), Fix::from_bits(10), Fix::from_num(1)]);
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "let v: Vec<Fix> = Fix::linspace(Fix::from_num(1), Fix::from_num(-1), 3).collect();
+assert_eq!(v, [1, 0, -1]);
+",
+                },
+                "```
+";
+                #[inline]
+                pub fn linspace(
+                    start: $Fixed<Frac>,
+                    end: $Fixed<Frac>,
+                    n: usize,
+                ) -> FixedRange<$Fixed<Frac>> {
+                    let (start_bits, end_bits) = (start.to_bits(), end.to_bits());
+                    let ascending = start_bits <= end_bits;
+                    // the distance between any two values fits in the unsigned type
+                    let diff = if ascending {
+                        (end_bits as $UInner).wrapping_sub(start_bits as $UInner)
+                    } else {
+                        (start_bits as $UInner).wrapping_sub(end_bits as $UInner)
+                    };
+                    // only the steps before the last value are taken, and
+                    // with n > 2 the step is at most diff / 2, which fits
+                    let step_abs = match n.checked_sub(1) {
+                        Some(steps) if steps > 1 => (diff as u128 / steps as u128) as $UInner,
+                        _ => 0,
+                    };
+                    let step = if ascending {
+                        step_abs as $Inner
+                    } else {
+                        if_signed_unsigned! {
+                            $Signedness,
+                            (step_abs as $Inner).wrapping_neg(),
+                            {
+                                assert!(n <= 2, "overflow");
+                                0
+                            },
+                        }
+                    };
+                    if n > 1 {
+                        FixedRange::with_last(start, Self::from_bits(step), n, end)
+                    } else {
+                        FixedRange::new(start, Self::from_bits(step), n)
+                    }
+                }
+            }
+
+            comment! {
+                "Returns an iterator over `n` shares of the number that sum
+exactly to it.
+
+The number is divided into `n` equal shares rounded towards zero, and
+the remainder is distributed one smallest step, that is
+[`MIN_POSITIVE`], at a time to the first shares. So the shares differ
+by at most [`MIN_POSITIVE`], and their sum is bit for bit the original
+number. This iterator does not allocate; [`split_evenly`] collects the
+shares into a vector.
+
+# Panics
+
+Panics if `n` is zero.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1 = 16/16 is split into 6/16 + 5/16 + 5/16
+let shares: Vec<Fix> = Fix::from_num(1).split_evenly_iter(3).collect();
+assert_eq!(shares, [Fix::from_bits(6), Fix::from_bits(5), Fix::from_bits(5)]);
+assert_eq!(shares.iter().fold(Fix::from_num(0), |a, &b| a + b), Fix::from_num(1));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "// for negative numbers the first shares are more negative
+let shares: Vec<Fix> = Fix::from_bits(-7).split_evenly_iter(2).collect();
+assert_eq!(shares, [Fix::from_bits(-4), Fix::from_bits(-3)]);
+",
+                },
+                "```
+
+[`MIN_POSITIVE`]: #associatedconstant.MIN_POSITIVE
+[`split_evenly`]: #method.split_evenly
+";
+                #[inline]
+                pub fn split_evenly_iter(self, n: u32) -> SplitEvenly<$Fixed<Frac>> {
+                    assert!(n != 0, "cannot split into zero shares");
+                    let (neg, abs) = self.to_bits().neg_abs();
+                    let n_wide = u128::from(n);
+                    // both fit: the quotient is at most abs, and the remainder is less than n
+                    let quot = (abs as u128 / n_wide) as $UInner;
+                    let rem = (abs as u128 % n_wide) as u32;
+                    // larger is only yielded when rem > 0, and then quot + 1 ≤ abs,
+                    // so wrapping only happens for a share that is never yielded
+                    let larger = quot.wrapping_add(1);
+                    let (base, larger) = if neg {
+                        ((quot as $Inner).wrapping_neg(), (larger as $Inner).wrapping_neg())
+                    } else {
+                        (quot as $Inner, larger as $Inner)
+                    };
+                    SplitEvenly::new(Self::from_bits(base), Self::from_bits(larger), n, rem)
+                }
+            }
+
+            comment! {
+                "Splits the number into `n` shares that sum exactly to it.
+
+The shares differ by at most [`MIN_POSITIVE`], with the remainder of
+the division going to the first shares. This is equivalent to collecting
+[`split_evenly_iter`].
+
+This method is only available when the `alloc` feature is enabled.
+
+# Panics
+
+Panics if `n` is zero.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let total = Fix::from_num(5);
+let shares = total.split_evenly(3);
+assert_eq!(shares.len(), 3);
+assert_eq!(shares.iter().fold(Fix::from_num(0), |a, &b| a + b), total);
+assert_eq!(shares[0] - shares[2], Fix::from_bits(1));
+```
+
+[`MIN_POSITIVE`]: #associatedconstant.MIN_POSITIVE
+[`split_evenly_iter`]: #method.split_evenly_iter
+";
+                #[cfg(feature = "alloc")]
+                #[inline]
+                pub fn split_evenly(self, n: u32) -> Vec<$Fixed<Frac>> {
+                    self.split_evenly_iter(n).collect()
+                }
+            }
+
+            comment! {
+                "Clamps the value to the range from 0 to 1 inclusive.
+
+This is useful for ratios such as probabilities and blend factors.
+If 1 cannot be represented, that is if there are fewer than ",
+                if_signed_unsigned!($Signedness, "two integer bits", "one integer bit"),
+                ", the
+value is clamped to [`max_value`] instead, which is the largest value
+below 1.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(0.75).clamp01(), Fix::from_num(0.75));
+assert_eq!(Fix::from_num(3).clamp01(), Fix::from_num(1));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-0.5).clamp01(), Fix::from_num(0));
+",
+                },
+                "
+// without enough integer bits, 1 cannot be represented
+use substrate_fixed::types::extra::U", $s_nbits, ";
+type Ratio = ", $s_fixed, "<U", $s_nbits, ">;
+assert_eq!(Ratio::max_value().clamp01(), Ratio::max_value());
+```
+
+[`max_value`]: #method.max_value
+";
+                #[inline]
+                pub fn clamp01(self) -> $Fixed<Frac> {
+                    let zero = Self::from_bits(0);
+                    let one = Self::saturating_from_num(1);
+                    if self < zero {
+                        zero
+                    } else if self > one {
+                        one
+                    } else {
+                        self
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the distance from the number to the nearest
+integer, which is in the range [0, 0.5].
+
+This is the smaller of the fractional part and one minus the fractional
+part, so it is the rounding error of [`round`]. ",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "The distance is the same for negative numbers as for their
+negation, for example both −2.25 and 2.25 are 0.25 from the nearest
+integer. If there are no integer bits, that is for `", $s_fixed, "<U", $s_nbits, ">`,
+0.5 cannot be represented, and the distance of −0.5 is returned as
+[`max_value`] instead. ",
+                },
+                "Numbers with a distance near 0.5 are the ones where
+rounding is most sensitive to small errors; see [`is_near_half`].
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.25).frac_distance_to_int(), Fix::from_num(0.25));
+assert_eq!(Fix::from_num(2.75).frac_distance_to_int(), Fix::from_num(0.25));
+assert_eq!(Fix::from_num(2.5).frac_distance_to_int(), Fix::from_num(0.5));
+assert_eq!(Fix::from_num(3).frac_distance_to_int(), Fix::from_num(0));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-2.25).frac_distance_to_int(), Fix::from_num(0.25));
+",
+                },
+                "```
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "
+[`max_value`]: #method.max_value",
+                },
+                "
+[`is_near_half`]: #method.is_near_half
+[`round`]: #method.round
+";
+                #[inline]
+                pub fn frac_distance_to_int(self) -> $Fixed<Frac> {
+                    let dist = self.frac_distance_bits();
+                    if dist > Self::max_value().to_bits() as $UInner {
+                        Self::max_value()
+                    } else {
+                        Self::from_bits(dist as $Inner)
+                    }
+                }
+            }
+
+            comment! {
+                "Returns [`true`] if the number is within `threshold` of
+halfway between two integers.
+
+That is, [`frac_distance_to_int`] is at least 0.5 − `threshold`. Such
+numbers are the ones where rounding is “risky”, as a small error can
+change the rounding direction. A negative `threshold` always gives
+[`false`].
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let threshold = Fix::from_num(0.125);
+assert!(Fix::from_num(2.5).is_near_half(threshold));
+assert!(Fix::from_num(2.375).is_near_half(threshold));
+assert!(!Fix::from_num(2.25).is_near_half(threshold));
+assert!(Fix::from_num(2.5).is_near_half(Fix::from_num(0)));
+```
+
+[`false`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+[`frac_distance_to_int`]: #method.frac_distance_to_int
+[`true`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+";
+                #[inline]
+                pub fn is_near_half(self, threshold: $Fixed<Frac>) -> bool {
+                    if_signed! {
+                        $Signedness;
+                        if threshold.is_negative() {
+                            return false;
+                        }
+                    }
+                    let threshold = threshold.to_bits() as $UInner;
+                    if Self::FRAC_NBITS == 0 {
+                        // integers are 0.5 from halfway, and a positive
+                        // threshold is at least one
+                        return threshold > 0;
+                    }
+                    let half: $UInner = 1 << (Self::FRAC_NBITS - 1);
+                    self.frac_distance_bits().saturating_add(threshold) >= half
+                }
+            }
+
+            // The distance to the nearest integer in units of the least
+            // significant bit, which is at most 2^(FRAC_NBITS - 1).
+            #[inline]
+            fn frac_distance_bits(self) -> $UInner {
+                let frac_mask = Self::FRAC_MASK as $UInner;
+                let frac = self.to_bits() as $UInner & frac_mask;
+                frac.min(frac.wrapping_neg() & frac_mask)
+            }
+
+            comment! {
+                "Rounds to the nearest value with only `keep_frac_bits`
+fractional bits, with ties rounded to even.
+
+The dropped fractional bits are cleared. If `keep_frac_bits` is at
+least [`FRAC_NBITS`], the value is returned unchanged. Rounding up can
+carry into the integer part.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped result can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`overflowing_quantize`] instead.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 10.1011 rounds up to 10.11
+assert_eq!(Fix::from_num(2.6875).quantize(2), Fix::from_num(2.75));
+// 10.1010 is a tie, which rounds to the even 10.10
+assert_eq!(Fix::from_num(2.625).quantize(2), Fix::from_num(2.5));
+// 1.1111 carries into the integer part
+assert_eq!(Fix::from_num(1.9375).quantize(2), Fix::from_num(2));
+assert_eq!(Fix::from_num(2.6875).quantize(4), Fix::from_num(2.6875));
+```
+
+[`FRAC_NBITS`]: #associatedconstant.FRAC_NBITS
+[`overflowing_quantize`]: #method.overflowing_quantize
+";
+                #[inline]
+                pub fn quantize(self, keep_frac_bits: u32) -> $Fixed<Frac> {
+                    let (val, overflow) = self.overflowing_quantize(keep_frac_bits);
+                    debug_assert!(!overflow, "overflow");
+                    let _ = overflow;
+                    val
+                }
+            }
+
+            comment! {
+                "Checked quantization. Rounds to the nearest value with
+only `keep_frac_bits` fractional bits, with ties rounded to even,
+returning [`None`] on overflow.
+
+See [`quantize`] for details.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.6875).checked_quantize(2), Some(Fix::from_num(2.75)));
+assert_eq!(Fix::max_value().checked_quantize(2), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`quantize`]: #method.quantize
+";
+                #[inline]
+                pub fn checked_quantize(self, keep_frac_bits: u32) -> Option<$Fixed<Frac>> {
+                    match self.overflowing_quantize(keep_frac_bits) {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing quantization. Rounds to the nearest value
+with only `keep_frac_bits` fractional bits, with ties rounded to even.
+
+Returns a [tuple] of the rounded value and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned. See [`quantize`] for details.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let val = Fix::from_num(2.6875);
+assert_eq!(val.overflowing_quantize(2), (Fix::from_num(2.75), false));
+assert_eq!(Fix::max_value().overflowing_quantize(2), (Fix::min_value(), true));
+```
+
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+[`quantize`]: #method.quantize
+[tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+";
+                #[inline]
+                pub fn overflowing_quantize(self, keep_frac_bits: u32) -> ($Fixed<Frac>, bool) {
+                    let drop = Self::FRAC_NBITS.saturating_sub(keep_frac_bits);
+                    if drop == 0 {
+                        return (self, false);
+                    }
+                    let bits = self.to_bits() as $UInner;
+                    let half: $UInner = 1 << (drop - 1);
+                    if Self::INT_NBITS == 0 && drop == Self::FRAC_NBITS {
+                        // Only zero is left. An unsigned value above one
+                        // half rounds up to one, which overflows to zero.
+                        let overflow = if_signed_unsigned!($Signedness, false, bits > half);
+                        return (Self::from_bits(0), overflow);
+                    }
+                    let mask: $UInner = !0 << drop;
+                    let floor = Self::from_bits((bits & mask) as $Inner);
+                    let rem = bits & !mask;
+                    let odd = bits & (1 << drop) != 0;
+                    if rem < half || (rem == half && !odd) {
+                        return (floor, false);
+                    }
+                    let one: $UInner = 1;
+                    let increment = Self::from_bits((one << drop) as $Inner);
+                    if_signed_unsigned! {
+                        $Signedness,
+                        // If drop is one less than the number of bits,
+                        // increment is negative, so subtract it.
+                        if increment.to_bits() < 0 {
+                            floor.overflowing_sub(increment)
+                        } else {
+                            floor.overflowing_add(increment)
+                        },
+                        floor.overflowing_add(increment),
+                    }
+                }
+            }
+
+            comment! {
+                "Converts to a fixed-point number of the same width with
+`DstFrac` fractional bits, rounding the discarded fractional bits
+according to the given [`RoundingMode`].
+
+As the destination has at most as many fractional bits, it has at
+least as many integer bits, so the conversion cannot overflow.
+
+# Examples
+
+```rust
+use substrate_fixed::{
+    types::extra::{U2, U4},
+    ", $s_fixed, ", RoundingMode,
+};
+type Fix = ", $s_fixed, "<U4>;
+type Dst = ", $s_fixed, "<U2>;
+// 2.375 lies between 2.25 and 2.5, and is a tie
+let x = Fix::from_num(2.375);
+assert_eq!(x.narrow_frac::<U2>(RoundingMode::Nearest), Dst::from_num(2.5));
+assert_eq!(x.narrow_frac::<U2>(RoundingMode::Floor), Dst::from_num(2.25));
+assert_eq!(x.narrow_frac::<U2>(RoundingMode::Ceil), Dst::from_num(2.5));
+assert_eq!(x.narrow_frac::<U2>(RoundingMode::Zero), Dst::from_num(2.25));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!((-x).narrow_frac::<U2>(RoundingMode::Zero), Dst::from_num(-2.25));
+",
+                },
+                "```
+
+[`RoundingMode`]: enum.RoundingMode.html
+";
+                #[inline]
+                pub fn narrow_frac<DstFrac>(self, mode: RoundingMode) -> $Fixed<DstFrac>
+                where
+                    DstFrac: $LeEqU + IsLessOrEqual<Frac, Output = True>,
+                {
+                    let nbits = Self::INT_NBITS + Self::FRAC_NBITS;
+                    let shift = Self::FRAC_NBITS - DstFrac::U32;
+                    let bits = self.to_bits();
+                    if shift == 0 {
+                        return $Fixed::from_bits(bits);
+                    }
+                    // shift can be the whole width, so shift in two steps
+                    let floor = bits >> (shift - 1) >> 1;
+                    let rem = bits as $UInner & (!0 >> (nbits - shift));
+                    let half: $UInner = 1 << (shift - 1);
+                    let neg = if_signed_unsigned! { $Signedness, bits < 0, false };
+                    let round_up = match mode {
+                        RoundingMode::Nearest => rem > half || (rem == half && floor & 1 != 0),
+                        RoundingMode::Floor => false,
+                        RoundingMode::Ceil => rem != 0,
+                        RoundingMode::Zero => neg && rem != 0,
+                    };
+                    // floor is at most max >> shift, so adding one cannot overflow
+                    $Fixed::from_bits(floor + <$Inner>::from(round_up))
+                }
+            }
+
+            comment! {
+                "Returns the cube root.
+
+The root is computed exactly from the underlying bits and then rounded
+to the nearest; ties cannot occur.",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    " The cube root of a negative number is negative.",
+                },
+                "
+
+# Panics
+
+Panics if the result does not fit, which can only happen if there are
+less than two integer bits.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(0).cbrt(), 0);
+assert_eq!(Fix::from_num(1).cbrt(), 1);
+assert_eq!(Fix::from_num(3.375).cbrt(), Fix::from_num(1.5));
+// the cube root of 2 is 1.2599…, which is rounded to 1.25
+assert_eq!(Fix::from_num(2).cbrt(), Fix::from_num(1.25));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-3.375).cbrt(), Fix::from_num(-1.5));
+",
+                },
+                "```
+";
+                #[inline]
+                pub fn cbrt(self) -> $Fixed<Frac> {
+                    self.checked_cbrt().expect("overflow")
+                }
+            }
+
+            comment! {
+                "Checked cube root. Returns the cube root, or [`None`]
+if the result does not fit.
+
+The result can only overflow if there are less than two integer bits,
+as the cube root of a number smaller than one is larger than the number.
+
+# Examples
+
+```rust
+use substrate_fixed::{
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(3.375).checked_cbrt(), Some(Fix::from_num(1.5)));
+type AllFrac = ", $s_fixed, "<U", $s_nbits, ">;
+assert_eq!(AllFrac::from_num(0.015625).checked_cbrt(), Some(AllFrac::from_num(0.25)));
+",
+                if_signed_unsigned! {
+                    $Signedness,
+                    "// the cube root of 1/8 is 1/2, which is too large
+assert_eq!(AllFrac::from_num(0.125).checked_cbrt(), None);",
+                    "assert_eq!(AllFrac::from_num(0.125).checked_cbrt(), Some(AllFrac::from_num(0.5)));
+// the cube root of the maximum is rounded up to one, which is too large
+assert_eq!(AllFrac::max_value().checked_cbrt(), None);",
+                },
+                "
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+                #[inline]
+                pub fn checked_cbrt(self) -> Option<$Fixed<Frac>> {
+                    let (neg, abs) = self.to_bits().neg_abs();
+                    let root = helpers::cbrt_shl_round(abs.into(), 2 * Self::FRAC_NBITS)?;
+                    Self::private_checked_from_neg_abs(neg, root)
+                }
+            }
 
-            // 0 when FRAC_NBITS = 0
-            const INT_LSB: $Inner = Self::INT_MASK ^ (Self::INT_MASK << 1);
+            comment! {
+                "Returns the reciprocal square root, that is 1/√`self`.
 
-            // 0 when INT_NBITS = 0
-            const FRAC_MSB: $Inner =
-                Self::FRAC_MASK ^ ((Self::FRAC_MASK as $UInner) >> 1) as $Inner;
+The result is not always correctly rounded, but it is within a few units
+in the last place of the exact result.
+
+# Panics
+
+Panics if the number is not positive, or if the result does not fit.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(4).rsqrt(), Fix::from_num(0.5));
+assert_eq!(Fix::from_num(0.25).rsqrt(), Fix::from_num(2));
+// 1/√2 is 0.7071…, which is rounded to 0.6875
+assert_eq!(Fix::from_num(2).rsqrt(), Fix::from_num(0.6875));
+```
+";
+                #[inline]
+                pub fn rsqrt(self) -> $Fixed<Frac> {
+                    assert!(self.to_bits() > 0, "non-positive number");
+                    self.checked_rsqrt().expect("overflow")
+                }
+            }
 
             comment! {
-                "Returns the number of integer bits.
+                "Checked reciprocal square root. Returns 1/√`self`, or
+[`None`] if the number is not positive or if the result does not fit.
+
+See [`rsqrt`] for details on the accuracy.
 
 # Examples
 
 ```rust
-use substrate_fixed::{types::extra::U6, ", $s_fixed, "};
-type Fix = ", $s_fixed, "<U6>;
-assert_eq!(Fix::int_nbits(), ", $s_nbits, " - 6);
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(4).checked_rsqrt(), Some(Fix::from_num(0.5)));
+assert_eq!(Fix::from_num(0).checked_rsqrt(), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-4).checked_rsqrt(), None);
+",
+                },
+                "assert_eq!(Fix::from_bits(1).checked_rsqrt(), Some(Fix::from_num(4)));
 ```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`rsqrt`]: #method.rsqrt
 ";
                 #[inline]
-                pub fn int_nbits() -> u32 {
-                    Self::INT_NBITS
+                pub fn checked_rsqrt(self) -> Option<$Fixed<Frac>> {
+                    let (neg, abs) = self.to_bits().neg_abs();
+                    if neg || abs == 0 {
+                        return None;
+                    }
+                    let root = helpers::rsqrt_round(abs.into(), Self::FRAC_NBITS)?;
+                    Self::private_checked_from_neg_abs(false, root)
                 }
             }
 
             comment! {
-                "Returns the number of fractional bits.
+                "Converts an angle in radians to degrees.
+
+The angle is multiplied by a 128-bit approximation of 180/π, with
+rounding to the nearest and ties rounded to even. As the constant is
+not exact, the result can be one [`MIN_POSITIVE`] away from the
+correctly rounded angle when the exact product is very close to a
+rounding boundary.
+
+# Panics
+
+Panics if the result does not fit. The largest angle that can be
+converted is about ", $s_fixed, "::max_value() × π/180, so an angle
+uses about six integer bits more in degrees than in radians.
 
 # Examples
 
 ```rust
-use substrate_fixed::{types::extra::U6, ", $s_fixed, "};
-type Fix = ", $s_fixed, "<U6>;
-assert_eq!(Fix::frac_nbits(), 6);
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 0.125 rad is 7.16… degrees, which is rounded to 7.1875
+assert_eq!(Fix::from_num(0.125).to_degrees(), Fix::from_num(7.1875));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-0.125).to_degrees(), Fix::from_num(-7.1875));
+",
+                },
+                "```
+
+[`MIN_POSITIVE`]: #associatedconstant.MIN_POSITIVE
+";
+                #[inline]
+                pub fn to_degrees(self) -> $Fixed<Frac> {
+                    self.checked_to_degrees().expect("overflow")
+                }
+            }
+
+            comment! {
+                "Checked conversion of an angle in radians to degrees.
+Returns the angle in degrees, or [`None`] if the result does not fit.
+
+The angle is multiplied by a 128-bit approximation of 180/π, with
+rounding to the nearest and ties rounded to even. As the constant is
+not exact, the result can be one [`MIN_POSITIVE`] away from the
+correctly rounded angle when the exact product is very close to a
+rounding boundary.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(0.125).checked_to_degrees(), Some(Fix::from_num(7.1875)));
+assert_eq!(Fix::max_value().checked_to_degrees(), None);
 ```
+
+[`MIN_POSITIVE`]: #associatedconstant.MIN_POSITIVE
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
 ";
                 #[inline]
-                pub fn frac_nbits() -> u32 {
-                    Self::FRAC_NBITS
+                pub fn checked_to_degrees(self) -> Option<$Fixed<Frac>> {
+                    let (neg, abs) = self.to_bits().neg_abs();
+                    let deg = helpers::mul_shr_round_abs(
+                        abs.into(),
+                        helpers::DEG_PER_RAD,
+                        helpers::DEG_PER_RAD_FRAC_NBITS,
+                    )?;
+                    Self::private_checked_from_neg_abs(neg, deg)
+                }
+            }
+
+            comment! {
+                "Converts an angle in degrees to radians.
+
+The angle is multiplied by a 128-bit approximation of π/180, with
+rounding to the nearest and ties rounded to even. As the constant is
+not exact, the result can be one [`MIN_POSITIVE`] away from the
+correctly rounded angle when the exact product is very close to a
+rounding boundary. Since π/180 < 1, this cannot overflow.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 7 degrees is 0.122… rad, which is rounded to 0.125
+assert_eq!(Fix::from_num(7).to_radians(), Fix::from_num(0.125));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-7).to_radians(), Fix::from_num(-0.125));
+",
+                },
+                "```
+
+[`MIN_POSITIVE`]: #associatedconstant.MIN_POSITIVE
+";
+                #[inline]
+                pub fn to_radians(self) -> $Fixed<Frac> {
+                    let (neg, abs) = self.to_bits().neg_abs();
+                    let rad = helpers::mul_shr_round_abs(
+                        abs.into(),
+                        helpers::RAD_PER_DEG,
+                        helpers::RAD_PER_DEG_FRAC_NBITS,
+                    );
+                    // rad ≤ abs, so it always fits
+                    Self::from_bits(<$Inner>::from_neg_abs(neg, rad.expect("overflow") as $UInner))
                 }
             }
 
@@ -288,6 +2460,114 @@ assert_eq!(Fix::max_value().checked_mul(Fix::from_num(2)), None);
                 }
             }
 
+            comment! {
+                "Checked square. Returns `self` × `self`, or [`None`] on overflow.
+
+Unlike [`checked_mul`], which truncates, the square is rounded to the
+nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U", $s_nbits_m4, ", ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U", $s_nbits_m4, ">;
+assert_eq!(Fix::from_num(1.5).checked_square(), Some(Fix::from_num(2.25)));
+assert_eq!(Fix::from_num(4).checked_square(), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-1.5).checked_square(), Some(Fix::from_num(2.25)));
+",
+                },
+                "```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`checked_mul`]: #method.checked_mul
+";
+                #[inline]
+                pub fn checked_square(self) -> Option<$Fixed<Frac>> {
+                    let (_, abs) = self.to_bits().neg_abs();
+                    let square = helpers::mul_shr_round_abs(abs.into(), abs.into(), Frac::U32)?;
+                    // the square is never negative
+                    if square > <$Inner>::max_value() as u128 {
+                        None
+                    } else {
+                        Some(Self::from_bits(square as $Inner))
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Returns the absolute value as the unsigned type with
+the same number of bits.
+
+Unlike [`abs`], this cannot overflow, as the absolute value of
+[`min_value`] fits in the unsigned type.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, ", ", $s_ufixed, "};
+type Fix = ", $s_fixed, "<U4>;
+type UFix = ", $s_ufixed, "<U4>;
+assert_eq!(Fix::from_num(-5.5).unsigned_abs(), UFix::from_num(5.5));
+assert_eq!(Fix::from_num(5.5).unsigned_abs(), UFix::from_num(5.5));
+assert_eq!(Fix::min_value().checked_abs(), None);
+assert_eq!(Fix::min_value().unsigned_abs(), UFix::from_num(1) << (", $s_nbits, " - 5));
+```
+
+[`abs`]: #method.abs
+[`min_value`]: #method.min_value
+";
+                    #[inline]
+                    pub fn unsigned_abs(self) -> $UFixed<Frac> {
+                        let (_, abs) = self.to_bits().neg_abs();
+                        $UFixed::from_bits(abs)
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Checked square into the unsigned type with the same
+number of bits. Returns `self` × `self`, or [`None`] on overflow.
+
+As the square is never negative, this has one more integer bit
+available than [`checked_square`], so it can return squares that would
+overflow the signed type. The square is rounded to the nearest, with
+ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U", $s_nbits_m4, ", ", $s_fixed, ", ", $s_ufixed, "};
+type Fix = ", $s_fixed, "<U", $s_nbits_m4, ">;
+type UFix = ", $s_ufixed, "<U", $s_nbits_m4, ">;
+let x = Fix::from_num(-3.5);
+assert_eq!(x.checked_square(), None);
+assert_eq!(x.checked_square_unsigned(), Some(UFix::from_num(12.25)));
+assert_eq!(Fix::min_value().checked_square_unsigned(), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`checked_square`]: #method.checked_square
+";
+                    #[inline]
+                    pub fn checked_square_unsigned(self) -> Option<$UFixed<Frac>> {
+                        let (_, abs) = self.to_bits().neg_abs();
+                        let square = helpers::mul_shr_round_abs(abs.into(), abs.into(), Frac::U32)?;
+                        if square > <$UInner>::max_value() as u128 {
+                            None
+                        } else {
+                            Some($UFixed::from_bits(square as $UInner))
+                        }
+                    }
+                }
+            }
+
             comment! {
                 "Checked division. Returns the quotient, or [`None`] if
 the divisor is zero or on overflow.
@@ -501,6 +2781,43 @@ assert_eq!(Fix::from_num(-7.5).checked_rem_euclid_int(20), None);
                 }
             }
 
+            comment! {
+                "Checked addition of an integer. Returns the sum, or
+[`None`] on overflow.
+
+The integer is shifted into place and added directly, so there is no
+need to convert it to a fixed-point number first, and it may be outside
+the range of the fixed-point type as long as the sum is not.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.5).checked_add_int(3), Some(Fix::from_num(5.5)));
+assert_eq!(Fix::max_value().checked_add_int(1), None);
+assert_eq!(Fix::from_num(2.5).checked_add_int(-2), Some(Fix::from_num(0.5)));
+",
+                if_signed_unsigned! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(2.5).checked_add_int(-3), Some(Fix::from_num(-0.5)));
+",
+                    "assert_eq!(Fix::from_num(2.5).checked_add_int(-3), None);
+",
+                },
+                "```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+                #[inline]
+                pub fn checked_add_int(self, rhs: i128) -> Option<$Fixed<Frac>> {
+                    match self.overflowing_add_int(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
             comment! {
                 "Saturating multiplication. Returns the product, saturating on overflow.
 
@@ -528,6 +2845,36 @@ assert_eq!(Fix::max_value().saturating_mul(Fix::from_num(2)), Fix::max_value());
                 }
             }
 
+            comment! {
+                "Saturating square. Returns `self` × `self`, saturating to
+[`max_value`] on overflow, as the square is never negative.
+
+The square is rounded as in [`checked_square`].
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U", $s_nbits_m4, ", ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U", $s_nbits_m4, ">;
+assert_eq!(Fix::from_num(1.5).saturating_square(), Fix::from_num(2.25));
+assert_eq!(Fix::from_num(4).saturating_square(), Fix::max_value());
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::min_value().saturating_square(), Fix::max_value());
+",
+                },
+                "```
+
+[`checked_square`]: #method.checked_square
+[`max_value`]: #method.max_value
+";
+                #[inline]
+                pub fn saturating_square(self) -> $Fixed<Frac> {
+                    self.checked_square().unwrap_or_else(Self::max_value)
+                }
+            }
+
             comment! {
                 "Saturating division. Returns the quotient, saturating on overflow.
 
@@ -601,6 +2948,30 @@ assert_eq!(Fix::min_value().saturating_div_euclid(Fix::from_num(0.25)), Fix::min
                 }
             }
 
+            comment! {
+                "Saturating addition of an integer. Returns the sum,
+saturating on overflow.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.5).saturating_add_int(3), Fix::from_num(5.5));
+assert_eq!(Fix::from_num(2.5).saturating_add_int(i128::max_value()), Fix::max_value());
+assert_eq!(Fix::from_num(2.5).saturating_add_int(i128::min_value()), Fix::min_value());
+```
+";
+                #[inline]
+                pub fn saturating_add_int(self, rhs: i128) -> $Fixed<Frac> {
+                    match self.overflowing_add_int(rhs) {
+                        (ans, false) => ans,
+                        (_, true) if rhs > 0 => Self::max_value(),
+                        (_, true) => Self::min_value(),
+                    }
+                }
+            }
+
             comment! {
                 "Wrapping multiplication. Returns the product, wrapping on overflow.
 
@@ -749,6 +3120,25 @@ assert_eq!(Fix::from_num(-7.5).wrapping_rem_euclid_int(20), Fix::from_num(-3.5))
                 }
             }
 
+            comment! {
+                "Wrapping addition of an integer. Returns the sum,
+wrapping on overflow.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.5).wrapping_add_int(3), Fix::from_num(5.5));
+assert_eq!(Fix::max_value().wrapping_add_int(1), Fix::min_value() + Fix::frac_mask_value());
+```
+";
+                #[inline]
+                pub fn wrapping_add_int(self, rhs: i128) -> $Fixed<Frac> {
+                    self.overflowing_add_int(rhs).0
+                }
+            }
+
             comment! {
                 "Overflowing multiplication.
 
@@ -991,6 +3381,62 @@ assert_eq!(Fix::from_num(-7.5).overflowing_rem_euclid_int(20), (Fix::from_num(-3
                 }
             }
 
+            comment! {
+                "Overflowing addition of an integer.
+
+Returns a [tuple] of the sum and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.5).overflowing_add_int(3), (Fix::from_num(5.5), false));
+let wrapped = Fix::min_value() + Fix::frac_mask_value();
+assert_eq!(Fix::max_value().overflowing_add_int(1), (wrapped, true));
+```
+
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+[tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+";
+                #[inline]
+                pub fn overflowing_add_int(self, rhs: i128) -> ($Fixed<Frac>, bool) {
+                    if Self::INT_NBITS == 0 {
+                        // rhs × 2^FRAC_NBITS wraps to zero, and any non-zero
+                        // integer takes the sum out of the range
+                        return (self, rhs != 0);
+                    }
+                    let bits = self.to_bits();
+                    let wrapped = bits.wrapping_add((rhs as $Inner) << Self::FRAC_NBITS);
+                    // Every integer in the range of integer parts can be
+                    // combined with any fraction, so only the integer
+                    // parts need to be checked.
+                    let int = bits >> Self::FRAC_NBITS;
+                    let max_int = Self::max_value().to_bits() >> Self::FRAC_NBITS;
+                    let overflow = if_signed_unsigned!(
+                        $Signedness,
+                        {
+                            let min_int = Self::min_value().to_bits() >> Self::FRAC_NBITS;
+                            match i128::from(int).checked_add(rhs) {
+                                Some(sum) => sum < i128::from(min_int) || sum > i128::from(max_int),
+                                None => true,
+                            }
+                        },
+                        {
+                            let int = u128::from(int);
+                            if rhs >= 0 {
+                                int.checked_add(rhs as u128)
+                                    .map_or(true, |sum| sum > u128::from(max_int))
+                            } else {
+                                int < rhs.unsigned_abs()
+                            }
+                        },
+                    );
+                    (Self::from_bits(wrapped), overflow)
+                }
+            }
+
             /// Remainder for division by an integer.
             ///
             /// # Panics
@@ -97,6 +97,69 @@ assert_eq!(Fix::from_num(",
             }
         }
 
+        comment! {
+            "Creates a fixed-point number from an integer.
+
+This is [`from_num`] restricted to [`i128`] sources. It is not a
+`const fn`, as methods that need the `Frac` bounds cannot be `const` on
+the minimum supported Rust version; constants have to be defined with
+[`from_bits`] instead.
+
+# Panics
+
+Panics if the value does not fit.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::const_from_int(5), Fix::from_num(5));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::const_from_int(-3), Fix::from_num(-3));
+",
+            },
+            "```
+
+The following panics as the value does not fit.
+
+```should_panic
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let _ = Fix::const_from_int(i128::MAX);
+```
+
+[`from_bits`]: #method.from_bits
+[`from_num`]: #method.from_num
+[`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+";
+            #[inline]
+            pub fn const_from_int(src: i128) -> $Fixed<Frac> {
+                let int_nbits = Self::INT_NBITS;
+                let fits = if int_nbits == 0 {
+                    src == 0
+                } else {
+                    if_signed_unsigned! {
+                        $Signedness,
+                        {
+                            let hi = src >> (int_nbits - 1);
+                            hi == 0 || hi == -1
+                        },
+                        src >= 0 && (int_nbits == 128 || (src as u128) >> int_nbits == 0),
+                    }
+                };
+                if !fits {
+                    panic!("overflow");
+                }
+                if int_nbits == 0 {
+                    return Self::from_bits(0);
+                }
+                Self::from_bits((src as $Inner) << Self::FRAC_NBITS)
+            }
+        }
+
         comment! {
             "Converts a fixed-point number to another number.
 
@@ -183,6 +246,224 @@ assert_eq!(",
             }
         }
 
+        comment! {
+            "Creates a fixed-point number from an [`f64`], and returns it
+together with the quantization error.
+
+The error is `src` minus the returned number converted back to
+[`f64`], so adding the error to the returned number gives back `src`.
+This can be used to compare how well different fixed-point types
+represent a value. Rounding is to the nearest, with ties rounded to
+even.
+
+# Panics
+
+Panics if `src` is not [finite].
+
+When debug assertions are enabled, also panics if the value does not
+fit. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1.28125 is 20.5/16, which is rounded to 20/16 (even)
+let (val, err) = Fix::from_num_with_error(1.28125);
+assert_eq!(val, Fix::from_bits(20));
+assert_eq!(err, 0.03125);
+// 1.3 × 16 = 20.8 is rounded to 21
+let (val, err) = Fix::from_num_with_error(1.3);
+assert_eq!(val, Fix::from_bits(21));
+assert!((err + 0.0125).abs() < 1e-12);
+```
+
+[`f64`]: https://doc.rust-lang.org/nightly/std/primitive.f64.html
+[finite]: https://doc.rust-lang.org/nightly/std/primitive.f64.html#method.is_finite
+";
+            #[inline]
+            pub fn from_num_with_error(src: f64) -> ($Fixed<Frac>, f64) {
+                let val = Self::from_num(src);
+                (val, src - val.to_num::<f64>())
+            }
+        }
+
+        comment! {
+            "Creates a fixed-point number from an [`f64`] using the
+given [`RoundingMode`].
+
+This is like [`from_num`] with an [`f64`] source, which always
+rounds to the nearest, but it can also round towards −∞, towards +∞,
+or towards zero; for example rounding with [`Floor`] gives a
+conservative lower bound that never exceeds `src`. Values that are
+exactly representable are returned unchanged in all modes.
+
+# Panics
+
+Panics if `src` is not [finite].
+
+When debug assertions are enabled, also panics if the rounded value
+does not fit. When debug assertions are not enabled, the wrapped value
+can be returned, but it is not considered a breaking change if in the
+future it panics.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, ", RoundingMode};
+type Fix = ", $s_fixed, "<U4>;
+// 1.3 × 16 = 20.8
+assert_eq!(Fix::from_num_round(1.3, RoundingMode::Nearest), Fix::from_bits(21));
+assert_eq!(Fix::from_num_round(1.3, RoundingMode::Floor), Fix::from_bits(20));
+assert_eq!(Fix::from_num_round(1.3, RoundingMode::Ceil), Fix::from_bits(21));
+assert_eq!(Fix::from_num_round(1.3, RoundingMode::Zero), Fix::from_bits(20));
+// 1.28125 × 16 = 20.5 is a tie, which rounds to even for Nearest
+assert_eq!(Fix::from_num_round(1.28125, RoundingMode::Nearest), Fix::from_bits(20));
+assert_eq!(Fix::from_num_round(1.28125, RoundingMode::Ceil), Fix::from_bits(21));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::from_num_round(-1.3, RoundingMode::Floor), Fix::from_bits(-21));
+assert_eq!(Fix::from_num_round(-1.3, RoundingMode::Zero), Fix::from_bits(-20));
+",
+            },
+            "```
+
+[`Floor`]: enum.RoundingMode.html#variant.Floor
+[`RoundingMode`]: enum.RoundingMode.html
+[`f64`]: https://doc.rust-lang.org/nightly/std/primitive.f64.html
+[`from_num`]: #method.from_num
+[finite]: https://doc.rust-lang.org/nightly/std/primitive.f64.html#method.is_finite
+";
+            #[inline]
+            pub fn from_num_round(src: f64, mode: RoundingMode) -> $Fixed<Frac> {
+                let kind = src.to_float_kind_round(Self::FRAC_NBITS, Self::INT_NBITS, mode);
+                let helper = FromFloatHelper { kind };
+                let (wrapped, overflow) = Self::private_overflowing_from_float_helper(helper);
+                debug_assert!(!overflow, "{} overflows", src);
+                let _ = overflow;
+                wrapped
+            }
+        }
+
+        comment! {
+            "Creates a fixed-point number from an [`f64`] if it can be
+represented exactly, otherwise returns [`None`].
+
+Unlike [`checked_from_num`], which rounds to the nearest, this returns
+[`None`] if any precision would be lost, so that a constant like `0.1`
+that is not exactly representable is caught instead of being silently
+rounded. [`None`] is also returned if `src` is not [finite] or if the
+value does not fit.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1.25 is 20/16
+assert_eq!(Fix::from_num_exact(1.25), Some(Fix::from_bits(20)));
+// 1/32 needs five fractional bits
+assert_eq!(Fix::from_num_exact(1.0 / 32.0), None);
+assert_eq!(Fix::from_num_exact(0.1), None);
+assert_eq!(Fix::from_num_exact(f64::NAN), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`checked_from_num`]: #method.checked_from_num
+[`f64`]: https://doc.rust-lang.org/nightly/std/primitive.f64.html
+[finite]: https://doc.rust-lang.org/nightly/std/primitive.f64.html#method.is_finite
+";
+            #[inline]
+            pub fn from_num_exact(src: f64) -> Option<$Fixed<Frac>> {
+                if !src.is_finite() {
+                    return None;
+                }
+                // src is mant × 2^exp for an integer mant, so it is
+                // exact if mant × 2^(exp + FRAC_NBITS) is an integer.
+                let (_, exp, mant) = src.parts();
+                let (exp, mant) = if exp < <f64 as FloatHelper>::EXP_MIN {
+                    // subnormal, no implicit one
+                    (<f64 as FloatHelper>::EXP_MIN, mant)
+                } else {
+                    (exp, mant | (1 << (<f64 as FloatHelper>::PREC - 1)))
+                };
+                if mant != 0 {
+                    let lsb_exp = exp - (<f64 as FloatHelper>::PREC as i32 - 1)
+                        + mant.trailing_zeros() as i32;
+                    if lsb_exp + (Self::FRAC_NBITS as i32) < 0 {
+                        return None;
+                    }
+                }
+                // the bits check above decides exactness; the round trip
+                // guards the boundary cases of the conversion itself
+                let val = Self::checked_from_num(src)?;
+                if val.to_num::<f64>() == src {
+                    Some(val)
+                } else {
+                    None
+                }
+            }
+        }
+
+        comment! {
+            "Returns the largest representable value ≤ `src` and the
+smallest representable value ≥ `src`.
+
+These are `src` rounded towards −∞ and towards +∞, so they bracket the
+exact value; if `src` is exactly representable, both are equal to it.
+This can be used for directed rounding, for example in interval
+arithmetic.
+
+If `src` is out of range, the bounds are clamped: if `src` is less than
+[`min_value`] both bounds are [`min_value`], and if `src` is greater
+than [`max_value`] both bounds are [`max_value`], so that they no longer
+bracket `src`. Infinite values are clamped in the same way.
+
+# Panics
+
+Panics if `src` is [NaN].
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1.3 × 16 = 20.8
+assert_eq!(Fix::bracket_f64(1.3), (Fix::from_bits(20), Fix::from_bits(21)));
+// 1.25 is exactly 20/16
+assert_eq!(Fix::bracket_f64(1.25), (Fix::from_bits(20), Fix::from_bits(20)));
+// out of range values are clamped
+let (min, max) = (Fix::min_value(), Fix::max_value());
+assert_eq!(Fix::bracket_f64(1e50), (max, max));
+assert_eq!(Fix::bracket_f64(f64::NEG_INFINITY), (min, min));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::bracket_f64(-1.3), (Fix::from_bits(-21), Fix::from_bits(-20)));
+",
+            },
+            "```
+
+[NaN]: https://doc.rust-lang.org/nightly/std/primitive.f64.html#method.is_nan
+[`max_value`]: #method.max_value
+[`min_value`]: #method.min_value
+";
+            #[inline]
+            pub fn bracket_f64(src: f64) -> ($Fixed<Frac>, $Fixed<Frac>) {
+                if src.is_nan() {
+                    panic!("NaN");
+                }
+                let bound = |mode| {
+                    let kind = src.to_float_kind_round(Self::FRAC_NBITS, Self::INT_NBITS, mode);
+                    Self::private_saturating_from_float_helper(FromFloatHelper { kind })
+                };
+                (bound(RoundingMode::Floor), bound(RoundingMode::Ceil))
+            }
+        }
+
         comment! {
             "Creates a fixed-point number from another number if it
 fits, otherwise returns [`None`].
@@ -269,6 +550,86 @@ assert!(Fix::checked_from_num(std::f64::NAN).is_none());
             }
         }
 
+        comment! {
+            "Creates an array of fixed-point numbers from an array of
+other numbers, converting each element as by [`from_num`].
+
+This is convenient for initializing lookup tables. Floating-point
+elements are rounded to the nearest, with ties rounded to even.
+
+# Panics
+
+For floating-point elements, panics if an element is not [finite].
+
+When debug assertions are enabled, also panics if an element does not
+fit. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`wrapping_from_num`] instead.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+const TABLE: [f64; 3] = [0.5, 1.25, 3.0];
+let table = Fix::from_num_array(TABLE);
+assert_eq!(table, [Fix::from_num(0.5), Fix::from_num(1.25), Fix::from_num(3)]);
+```
+
+[`from_num`]: #method.from_num
+[`wrapping_from_num`]: #method.wrapping_from_num
+[finite]: https://doc.rust-lang.org/nightly/std/primitive.f64.html#method.is_finite
+";
+            #[inline]
+            pub fn from_num_array<Src: ToFixed, const N: usize>(
+                src: [Src; N],
+            ) -> [$Fixed<Frac>; N] {
+                let mut dst = [Self::from_bits(0); N];
+                // array::map needs rustc 1.55, so move the elements out
+                // with array::IntoIter, which is deprecated in newer versions
+                #[allow(deprecated)]
+                let src = core::array::IntoIter::new(src);
+                for (dst, src) in dst.iter_mut().zip(src) {
+                    *dst = Self::from_num(src);
+                }
+                dst
+            }
+        }
+
+        comment! {
+            "Creates an array of fixed-point numbers from an array of
+other numbers, converting each element as by [`checked_from_num`].
+
+Returns the index of the first element that does not fit, or that is
+not [finite] for floating-point elements, as the error.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let table = Fix::try_from_num_array([0.5, 1.25]);
+assert_eq!(table, Ok([Fix::from_num(0.5), Fix::from_num(1.25)]));
+assert_eq!(Fix::try_from_num_array([0.5, 1e40, f64::NAN]), Err(1));
+```
+
+[`checked_from_num`]: #method.checked_from_num
+[finite]: https://doc.rust-lang.org/nightly/std/primitive.f64.html#method.is_finite
+";
+            #[inline]
+            pub fn try_from_num_array<Src: ToFixed, const N: usize>(
+                src: [Src; N],
+            ) -> Result<[$Fixed<Frac>; N], usize> {
+                let mut dst = [Self::from_bits(0); N];
+                #[allow(deprecated)]
+                let src = core::array::IntoIter::new(src);
+                for (index, (dst, src)) in dst.iter_mut().zip(src).enumerate() {
+                    *dst = Self::checked_from_num(src).ok_or(index)?;
+                }
+                Ok(dst)
+            }
+        }
+
         comment! {
             "Converts a fixed-point number to another number if it
 fits, otherwise returns [`None`].
@@ -863,6 +1224,55 @@ assert_eq!(one_point_625.overflowing_to_num::<f32>(), (1.625f32, false));
             }
         }
 
+        comment! {
+            "Converts the number to another fixed-point type, and
+reports whether the conversion changed the value.
+
+Returns a [tuple] of the converted number and a [`bool`] that is
+[`true`][`bool`] if any precision was lost. This happens if fractional
+bits that are not zero are truncated because the destination has fewer
+fractional bits, or if the value does not fit in the destination, in
+which case the wrapped value is returned as by
+[`overflowing_to_num`]. A conversion that reports no loss is exact, so
+converting back gives the original number.
+
+This is useful for checking whether changing the storage type of a
+column of numbers would alter any of them.
+
+# Examples
+
+```rust
+use substrate_fixed::{
+    types::extra::U4,
+    types::{I16F0, I16F16, U2F6},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+let x = Fix::from_num(2.5);
+assert_eq!(x.to_fixed_lossy_report::<I16F16>(), (I16F16::from_num(2.5), false));
+// the fractional part 0.5 is truncated
+assert_eq!(x.to_fixed_lossy_report::<I16F0>(), (I16F0::from_num(2), true));
+// 5 does not fit in two integer bits
+assert!(Fix::from_num(5).to_fixed_lossy_report::<U2F6>().1);
+```
+
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+[`overflowing_to_num`]: #method.overflowing_to_num
+[tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+";
+            #[inline]
+            pub fn to_fixed_lossy_report<Dst: Fixed>(self) -> (Dst, bool) {
+                let (dst, overflow) = Dst::overflowing_from_fixed(self);
+                // Converting back can overflow too, for example −0.5 in
+                // I0F8 is truncated to −1 in I8F0, which does not fit
+                // I0F8. The lost part is less than one, the range of the
+                // narrowest type, so the wrapped value still differs from
+                // self and the comparison catches it.
+                let (back, _) = Self::overflowing_from_fixed(dst);
+                (dst, overflow || back != self)
+            }
+        }
+
         comment! {
             "Parses a string slice containing binary digits to return a fixed-point number.
 
@@ -950,6 +1360,577 @@ assert_eq!(neg, Ok(-check));
             }
         }
 
+        comment! {
+            "Parses a string slice containing digits in the given radix
+to return a fixed-point number.
+
+The radix can be from 2 to 36 inclusive. Digits larger than nine are
+represented by the letters `a` to `z` or `A` to `Z`.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if `radix` is not in the range from 2 to 36 inclusive.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1.75 is 1.11 in binary, 1.O in base 32
+let f = Fix::from_str_radix(\"1.O\", 32);
+let check = Fix::from_bits(0b111 << (4 - 2));
+assert_eq!(f, Ok(check));
+assert_eq!(Fix::from_str_radix(\"1.o\", 32), Ok(check));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "let neg = Fix::from_str_radix(\"-1.O\", 32);
+assert_eq!(neg, Ok(-check));
+",
+            },
+            "// 0.1 in base 3 is 1/3, which is rounded to 5/16
+assert_eq!(Fix::from_str_radix(\"0.1\", 3), Ok(Fix::from_bits(5)));
+```
+";
+            #[inline]
+            pub fn from_str_radix(src: &str, radix: u32) -> Result<$Fixed<Frac>, ParseFixedError> {
+                assert!(
+                    (2..=36).contains(&radix),
+                    "radix must be in the range 2..=36, not {}",
+                    radix
+                );
+                FromStrRadix::from_str_radix(src, radix)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing decimal digits to return a fixed-point number,
+returning an error without parsing if the string is longer than
+`max_len` bytes.
+
+This can be used to cheaply reject overly long untrusted input.
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1.75 is 1.11 in binary
+let check = Fix::from_bits(0b111 << (4 - 2));
+assert_eq!(Fix::from_str_bounded(\"1.75\", 4), Ok(check));
+assert!(Fix::from_str_bounded(\"1.750\", 4).is_err());
+```
+";
+            #[inline]
+            pub fn from_str_bounded(
+                src: &str,
+                max_len: usize,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::bounded_from_str_radix(src, 10, max_len)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing digits in the given radix
+and returns the underlying bits of the fixed-point number.
+
+This is equivalent to parsing a fixed-point number and then calling
+[`to_bits`]. Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if `radix` is not in the range from 2 to 36 inclusive.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1.75 is 1.11 in binary
+assert_eq!(Fix::parse_to_bits(\"1.75\", 10), Ok(0b111 << (4 - 2)));
+assert_eq!(Fix::parse_to_bits(\"1.C\", 16), Ok(0b111 << (4 - 2)));
+assert!(Fix::parse_to_bits(\"1.C\", 10).is_err());
+```
+
+[`to_bits`]: #method.to_bits
+";
+            #[inline]
+            pub fn parse_to_bits(src: &str, radix: u32) -> Result<$Inner, ParseFixedError> {
+                Self::from_str_radix(src, radix).map(Self::to_bits)
+            }
+        }
+
+        comment! {
+            "Returns [`true`] if a string slice containing decimal
+digits can be parsed exactly, that is without overflow and without
+rounding.
+
+The string is parsed only once, and the decimal expansion of the
+result is compared against its fractional digits. This can be used to
+validate input that must be stored exactly.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1.75 and 0.0625 are multiples of 1/16
+assert!(Fix::is_representable(\"1.75\"));
+assert!(Fix::is_representable(\"0.062500\"));
+// 0.1 would be rounded
+assert!(!Fix::is_representable(\"0.1\"));
+// 10^40 would overflow
+assert!(!Fix::is_representable(\"10000000000000000000000000000000000000000\"));
+assert!(!Fix::is_representable(\"1.2.3\"));
+```
+
+[`true`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+";
+            #[inline]
+            pub fn is_representable(src: &str) -> bool {
+                let (val, overflow, frac) =
+                    match from_str::overflowing_from_str_with_frac::<$Fixed<Frac>>(src, 10) {
+                        Some(parsed) => parsed,
+                        None => return false,
+                    };
+                // If the fractional digits match exactly, the integer
+                // part cannot have been changed by rounding either.
+                !overflow && val.decimal_digits().map(|d| b'0' + d).eq(frac.iter().cloned())
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing decimal digits to return a
+fixed-point number, together with the direction in which it was
+rounded.
+
+Rounding is to the nearest, with ties rounded to even, as for
+[`FromStr`]. The returned [`Ordering`] compares the stored value with
+the exact decimal value of the string: it is [`Less`] if the value was
+rounded down, [`Greater`] if it was rounded up, and [`Equal`] if the
+string was parsed exactly.
+
+# Examples
+
+```rust
+use core::cmp::Ordering;
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 0.1 × 16 = 1.6 is rounded up to 2
+assert_eq!(Fix::from_str_rounded(\"0.1\"), Ok((Fix::from_num(0.125), Ordering::Greater)));
+// 0.03 × 16 = 0.48 is rounded down to 0
+assert_eq!(Fix::from_str_rounded(\"0.03\"), Ok((Fix::from_num(0), Ordering::Less)));
+assert_eq!(Fix::from_str_rounded(\"1.75\"), Ok((Fix::from_num(1.75), Ordering::Equal)));
+// rounding can carry into the integer part
+assert_eq!(Fix::from_str_rounded(\"0.99\"), Ok((Fix::from_num(1), Ordering::Greater)));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "// rounding away from zero makes negative numbers smaller
+assert_eq!(Fix::from_str_rounded(\"-0.1\"), Ok((Fix::from_num(-0.125), Ordering::Less)));
+",
+            },
+            "assert!(Fix::from_str_rounded(\"1.2.3\").is_err());
+```
+
+[`Equal`]: https://doc.rust-lang.org/nightly/core/cmp/enum.Ordering.html#variant.Equal
+[`Greater`]: https://doc.rust-lang.org/nightly/core/cmp/enum.Ordering.html#variant.Greater
+[`Less`]: https://doc.rust-lang.org/nightly/core/cmp/enum.Ordering.html#variant.Less
+[`Ordering`]: https://doc.rust-lang.org/nightly/core/cmp/enum.Ordering.html
+[`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+";
+            #[inline]
+            pub fn from_str_rounded(src: &str) -> Result<($Fixed<Frac>, Ordering), ParseFixedError> {
+                let val: $Fixed<Frac> = FromStrRadix::from_str_radix(src, 10)?;
+                let (neg, int, frac) = from_str::trimmed_digits(src, 10)?;
+                let frac_ord = val.decimal_digits().map(|d| b'0' + d).cmp(frac.iter().cloned());
+                let abs_ord = if frac_ord == Ordering::Equal || val.frac() != 0 {
+                    // The value lies within half an ulp of the input, so
+                    // if the value has a fractional part, the integer
+                    // parts are equal.
+                    frac_ord
+                } else {
+                    // An integer value was rounded either down to the
+                    // integer part of the input, or up to the next integer,
+                    // and these two have different parities.
+                    let (_, abs) = val.to_bits().neg_abs();
+                    let val_odd = abs.checked_shr(Self::FRAC_NBITS).map_or(false, |i| i & 1 != 0);
+                    let src_odd = int.last().map_or(false, |&d| (d - b'0') & 1 != 0);
+                    if val_odd == src_odd {
+                        Ordering::Less
+                    } else {
+                        Ordering::Greater
+                    }
+                };
+                Ok((val, if neg { abs_ord.reverse() } else { abs_ord }))
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing decimal digits followed by
+a Q-format tag, for example `\"1.5Q8.8\"`.
+
+The tag <code>Q<i>m</i>.<i>n</i></code> must have <i>m</i> equal to
+[`INT_NBITS`] and <i>n</i> equal to [`FRAC_NBITS`], where the integer
+bits include the sign bit as in the type names such as [`I8F8`].
+This catches scale mismatches at parse time. An error is returned if
+the tag is missing or malformed, or if it does not match the type.
+
+The number itself is parsed as by [`FromStr`], which does not accept a
+Q-format tag. Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// Fix has ", $s_nbits, " − 4 integer bits and 4 fractional bits
+let src = format!(\"1.5Q{}.4\", Fix::INT_NBITS);
+assert_eq!(Fix::from_str_q(&src), Ok(Fix::from_num(1.5)));
+// wrong number of fractional bits
+let mismatch = format!(\"1.5Q{}.5\", Fix::INT_NBITS);
+assert!(Fix::from_str_q(&mismatch).is_err());
+// missing tag
+assert!(Fix::from_str_q(\"1.5\").is_err());
+```
+
+[`FRAC_NBITS`]: #associatedconstant.FRAC_NBITS
+[`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+[`I8F8`]: types/type.I8F8.html
+[`INT_NBITS`]: #associatedconstant.INT_NBITS
+";
+            #[inline]
+            pub fn from_str_q(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                let num = from_str::strip_q_format(src, Self::INT_NBITS, Self::FRAC_NBITS)?;
+                FromStrRadix::from_str_radix(num, 10)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing decimal digits, where the
+digits of the integer part may be grouped in threes by commas, for
+example `\"1,234.5\"`.
+
+The commas are optional, but if any are present they must separate
+every group of three digits, with the first group having one to three
+digits. An error is returned for misplaced commas and for commas in the
+fractional part. Otherwise the number is parsed as by [`FromStr`], with
+rounding to the nearest and ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, types::I16F16, ", $s_fixed, "};
+assert_eq!(I16F16::from_str_grouped(\"-1,234.5\"), Ok(I16F16::from_num(-1234.5)));
+assert_eq!(I16F16::from_str_grouped(\"1234.5\"), Ok(I16F16::from_num(1234.5)));
+// misplaced commas and commas in the fractional part
+assert!(I16F16::from_str_grouped(\"12,34.5\").is_err());
+assert!(I16F16::from_str_grouped(\"1,234.500,1\").is_err());
+
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_str_grouped(\"0,005.25\"), Ok(Fix::from_num(5.25)));
+```
+
+[`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+";
+            #[inline]
+            pub fn from_str_grouped(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                from_str::from_str_grouped(src)
+            }
+        }
+
+        comment! {
+            "Parses a price such as `\"$1,234.56\"` or `\"€1.000,50\"`,
+with the given currency symbol, decimal point and group separator.
+
+The string can have an optional sign, followed by an optional
+`symbol`, followed by a decimal number. The decimal point is `point`,
+and the digits of the integer part may be grouped in threes by `group`.
+As for [`from_str_grouped`], group separators are optional, but if any
+are present they must separate every group of three digits. Misplaced
+separators, stray symbols and any other characters give an error.
+Otherwise the number is parsed as by [`FromStr`], with rounding to the
+nearest and ties rounded to even.
+
+This is separate from [`FromStr`], which stays strict.
+
+# Panics
+
+Panics if `point` and `group` are equal.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, types::I32F32, ", $s_fixed, "};
+let dollars = I32F32::from_str_currency(\"$1,234.56\", \"$\", '.', ',');
+assert_eq!(dollars, \"1234.56\".parse::<I32F32>());
+let euros = I32F32::from_str_currency(\"-€1.000,50\", \"€\", ',', '.');
+assert_eq!(euros, Ok(I32F32::from_num(-1000.5)));
+// misplaced group separators
+assert!(I32F32::from_str_currency(\"$12,34.5\", \"$\", '.', ',').is_err());
+
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_str_currency(\"$5.25\", \"$\", '.', ','), Ok(Fix::from_num(5.25)));
+```
+
+[`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+[`from_str_grouped`]: #method.from_str_grouped
+";
+            #[inline]
+            pub fn from_str_currency(
+                src: &str,
+                symbol: &str,
+                point: char,
+                group: char,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                from_str::from_str_currency(src, symbol, point, group)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing only integer digits in the
+given radix, optionally preceded by a sign, into the integer part of a
+fixed-point number.
+
+Since there is no fractional part, no decimal point is accepted and no
+rounding is needed; the parsed integer is shifted into place directly.
+An error is returned if the string has no digits, if it contains
+anything other than digits of the given radix after the optional sign,
+or if the value does not fit.
+
+# Panics
+
+Panics if `radix` is not in the range from 2 to 36.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_integer_str(\"5\", 10), Ok(Fix::from_num(5)));
+assert_eq!(Fix::from_integer_str(\"+7\", 8), Ok(Fix::from_num(7)));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::from_integer_str(\"-101\", 2), Ok(Fix::from_num(-5)));
+",
+            },
+            "// no fractional part is allowed
+assert!(Fix::from_integer_str(\"5.0\", 10).is_err());
+assert!(Fix::from_integer_str(\"\", 10).is_err());
+```
+";
+            #[inline]
+            pub fn from_integer_str(
+                src: &str,
+                radix: u32,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                from_str::from_integer_str(src, radix)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing integer digits in the
+given base, with the point implied `implied_frac_digits` digits from
+the right.
+
+This is useful for numbers stored as plain integers with a known
+scale, for example `\"1234\"` with two implied fractional digits is
+parsed as 12.34. If there are fewer digits than `implied_frac_digits`,
+the number is padded with zeros on the left, so `\"5\"` with two
+implied fractional digits is 0.05. The string can have an optional
+sign, but not a point.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Errors
+
+Returns an error if the string has no digits, has a character that is
+not a digit in the given base, or if the number does not fit. When the
+point falls to the left of the digits, the zero-padded fraction cannot
+be longer than 256 digits.
+
+# Panics
+
+Panics if `radix` is not in the range from 2 to 36 inclusive.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_scaled_str(\"15\", 10, 1), Ok(Fix::from_num(1.5)));
+assert_eq!(Fix::from_scaled_str(\"625\", 10, 4), Ok(Fix::from_num(0.0625)));
+assert_eq!(Fix::from_scaled_str(\"24\", 16, 1), Ok(Fix::from_num(2.25)));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::from_scaled_str(\"-75\", 10, 2), Ok(Fix::from_num(-0.75)));
+",
+            },
+            "assert!(Fix::from_scaled_str(\"1.5\", 10, 1).is_err());
+```
+";
+            #[inline]
+            pub fn from_scaled_str(
+                src: &str,
+                radix: u32,
+                implied_frac_digits: u32,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                from_str::from_scaled_str(src, radix, implied_frac_digits)
+            }
+        }
+
+        comment! {
+            "Parses the longest prefix of a string slice that is a
+decimal number, and returns the number together with the rest of the
+string.
+
+The number can have an optional sign and a point. The point is part of
+the number if there is a digit on either side of it, so `\"12.\"` is
+parsed completely, but in `\"12.x\"` the point is also consumed and only
+`\"x\"` is returned. Rounding is to the nearest, with ties rounded to
+even.
+
+# Errors
+
+Returns an error if the string does not start with a number, for
+example if it starts with a sign or a point that is not followed by a
+digit, or if the number does not fit.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_str_prefix(\"1.5 + 2\"), Ok((Fix::from_num(1.5), \" + 2\")));
+assert_eq!(Fix::from_str_prefix(\"7.,\"), Ok((Fix::from_num(7), \",\")));
+assert_eq!(Fix::from_str_prefix(\".25\"), Ok((Fix::from_num(0.25), \"\")));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::from_str_prefix(\"-3x\"), Ok((Fix::from_num(-3), \"x\")));
+",
+            },
+            "assert!(Fix::from_str_prefix(\"-x\").is_err());
+assert!(Fix::from_str_prefix(\".\").is_err());
+```
+";
+            #[inline]
+            pub fn from_str_prefix(src: &str) -> Result<($Fixed<Frac>, &str), ParseFixedError> {
+                from_str::from_str_prefix(src)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing decimal digits, where
+the fractional part may end with a repeating group in parentheses, to
+return a fixed-point number.
+
+For example `\"0.(3)\"` is one third and `\"1.1(6)\"` is seven sixths.
+The result is the exact repeating value rounded to the nearest, with
+ties rounded to even.
+
+# Errors
+
+Returns an error if the parentheses are malformed, if the repeating
+group is not after the decimal point, or if the number does not fit.
+A string with a repeating group also returns an error if its
+fractional digits and the expanded group need more than 256 digits.
+Without a group the string is parsed as by [`FromStr`], with no limit
+on its length.
+
+# Examples
+
+```rust
+use substrate_fixed::{
+    types::extra::U4,
+    types::U0F16,
+    ", $s_fixed, ",
+};
+// 2^16 / 3 rounded to the nearest is 21845
+assert_eq!(U0F16::from_str_repeating(\"0.(3)\"), Ok(U0F16::from_bits(21845)));
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_str_repeating(\"1.(9)\"), Ok(Fix::from_num(2)));
+assert_eq!(Fix::from_str_repeating(\"2.5\"), Ok(Fix::from_num(2.5)));
+assert!(Fix::from_str_repeating(\"0.(3\").is_err());
+assert!(Fix::from_str_repeating(\"(3).0\").is_err());
+```
+
+[`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+";
+            #[inline]
+            pub fn from_str_repeating(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                from_str::from_str_repeating(src)
+            }
+        }
+
+        comment! {
+            "Parses each string slice containing decimal digits to
+return a vector of fixed-point numbers.
+
+On the first string that cannot be parsed, returns its index together
+with the error. Rounding is to the nearest, with ties rounded to even.
+
+This method is only available when the `alloc` feature is enabled.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let column = \"1.5,2,0.25\";
+let v = Fix::parse_all(column.split(','));
+assert_eq!(v, Ok(vec![Fix::from_num(1.5), Fix::from_num(2), Fix::from_num(0.25)]));
+let (index, _) = Fix::parse_all(vec![\"1\", \"x\", \"2\"]).unwrap_err();
+assert_eq!(index, 1);
+```
+";
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn parse_all<'a, I>(src: I) -> Result<Vec<$Fixed<Frac>>, (usize, ParseFixedError)>
+            where
+                I: IntoIterator<Item = &'a str>,
+            {
+                src.into_iter()
+                    .enumerate()
+                    .map(|(index, s)| FromStrRadix::from_str_radix(s, 10).map_err(|e| (index, e)))
+                    .collect()
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing decimal digits and
+stores the fixed-point number in `out`.
+
+On error, `out` is left unchanged. This is useful when parsing many
+strings into the slots of an existing buffer. Rounding is to the
+nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let mut slots = [Fix::from_num(0); 3];
+for (slot, s) in slots.iter_mut().zip(\"1.5,x,0.25\".split(',')) {
+    let _ = Fix::parse_into(s, slot);
+}
+assert_eq!(slots, [Fix::from_num(1.5), Fix::from_num(0), Fix::from_num(0.25)]);
+assert!(Fix::parse_into(\"1.2.3\", &mut slots[0]).is_err());
+assert_eq!(slots[0], Fix::from_num(1.5));
+```
+";
+            #[inline]
+            pub fn parse_into(src: &str, out: &mut $Fixed<Frac>) -> Result<(), ParseFixedError> {
+                *out = FromStrRadix::from_str_radix(src, 10)?;
+                Ok(())
+            }
+        }
+
         comment! {
             "Parses a string slice containing decimal digits to return a fixed-point number,
 saturating on overflow.
@@ -1066,6 +2047,59 @@ assert_eq!(U8F8::saturating_from_str_hex(\"-1\"), Ok(U8F8::from_num(0)));
             }
         }
 
+        comment! {
+            "Parses a string slice containing digits in the given radix
+to return a fixed-point number, saturating on overflow.
+
+This is like [`from_str_radix`], but if the value does not fit it is
+clamped to [`min_value`] or [`max_value`] instead of returning an
+error. Invalid digits are still reported as an error.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if `radix` is not in the range from 2 to 36 inclusive.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned! {
+                $Signedness,
+                "use substrate_fixed::types::I8F8;
+assert_eq!(I8F8::saturating_from_str_radix(\"7F.8\", 16), Ok(I8F8::from_num(127.5)));
+assert_eq!(I8F8::saturating_from_str_radix(\"FFFF\", 16), Ok(I8F8::max_value()));
+assert_eq!(I8F8::saturating_from_str_radix(\"-11111111111\", 2), Ok(I8F8::min_value()));
+assert!(I8F8::saturating_from_str_radix(\"12\", 2).is_err());
+",
+                "use substrate_fixed::types::U8F8;
+assert_eq!(U8F8::saturating_from_str_radix(\"7F.8\", 16), Ok(U8F8::from_num(127.5)));
+assert_eq!(U8F8::saturating_from_str_radix(\"FFFF\", 16), Ok(U8F8::max_value()));
+assert_eq!(U8F8::saturating_from_str_radix(\"-7\", 8), Ok(U8F8::from_num(0)));
+assert!(U8F8::saturating_from_str_radix(\"12\", 2).is_err());
+",
+            },
+            "```
+
+[`from_str_radix`]: #method.from_str_radix
+[`max_value`]: #method.max_value
+[`min_value`]: #method.min_value
+";
+            #[inline]
+            pub fn saturating_from_str_radix(
+                src: &str,
+                radix: u32,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                assert!(
+                    (2..=36).contains(&radix),
+                    "radix must be in the range 2..=36, not {}",
+                    radix
+                );
+                FromStrRadix::saturating_from_str_radix(src, radix)
+            }
+        }
+
         comment! {
             "Parses a string slice containing decimal digits to return a fixed-point number,
 wrapping on overflow.
@@ -77,6 +77,117 @@ assert_eq!(Fix::from_bits(0b10_0000), 2);
                 }
             }
 
+            comment! {
+                "Creates a fixed-point number that has a bitwise
+representation identical to the given integer if the number lies in the
+inclusive range from `min` to `max`, otherwise returns [`None`].
+
+This can be used to validate raw bits read from storage against the
+range of values that can legitimately be stored.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (min, max) = (Fix::from_num(0), Fix::from_num(1));
+// 0000.1000 == 0.5
+assert_eq!(Fix::try_from_bits_in_range(0b1000, min, max), Some(Fix::from_num(0.5)));
+// 0001.0000 == 1
+assert_eq!(Fix::try_from_bits_in_range(0b1_0000, min, max), Some(max));
+// 0001.0001 > 1
+assert_eq!(Fix::try_from_bits_in_range(0b1_0001, min, max), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "// 1111.1111 == −0.0625
+assert_eq!(Fix::try_from_bits_in_range(-1, min, max), None);
+",
+                },
+                "```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+                #[inline]
+                pub fn try_from_bits_in_range(
+                    bits: $Inner,
+                    min: $Fixed<Frac>,
+                    max: $Fixed<Frac>,
+                ) -> Option<$Fixed<Frac>> {
+                    if min.to_bits() <= bits && bits <= max.to_bits() {
+                        Some(Self::from_bits(bits))
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Creates a fixed-point number from unsigned raw bits,
+or returns [`None`] if the most significant bit is set.
+
+Reinterpreting such bits with [`from_bits`] would silently give a
+negative number, so this guards against sign flips when reading data
+stored as unsigned into a signed type.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_unsigned_bits_checked(0b10_1000), Some(Fix::from_num(2.5)));
+assert_eq!(Fix::from_unsigned_bits_checked(!0 >> 1), Some(Fix::max_value()));
+assert_eq!(Fix::from_unsigned_bits_checked(!(!0 >> 1)), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`from_bits`]: #method.from_bits
+";
+                    #[inline]
+                    pub fn from_unsigned_bits_checked(bits: $UInner) -> Option<$Fixed<Frac>> {
+                        let bits = bits as $Inner;
+                        if bits < 0 {
+                            None
+                        } else {
+                            Some(Self::from_bits(bits))
+                        }
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Creates a fixed-point number that has a bitwise
+representation identical to the given unsigned integer.
+
+The bits are reinterpreted as two’s complement, so this is the inverse
+of [`to_bits_unsigned`]. Unlike [`from_unsigned_bits_checked`], a set
+most significant bit gives a negative number.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 0010.1000 == 2.5
+assert_eq!(Fix::from_bits_unsigned(0b10_1000), Fix::from_num(2.5));
+// all ones is −1 in two’s complement, that is −0.0625
+assert_eq!(Fix::from_bits_unsigned(!0), Fix::from_bits(-1));
+```
+
+[`from_unsigned_bits_checked`]: #method.from_unsigned_bits_checked
+[`to_bits_unsigned`]: #method.to_bits_unsigned
+";
+                    #[inline]
+                    pub const fn from_bits_unsigned(bits: $UInner) -> $Fixed<Frac> {
+                        Self::from_bits(bits as $Inner)
+                    }
+                }
+            }
+
             comment! {
                 "Creates an integer that has a bitwise representation
 identical to the given fixed-point number.
@@ -96,6 +207,94 @@ assert_eq!(Fix::from_num(2).to_bits(), 0b10_0000);
                 }
             }
 
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Creates an unsigned integer that has a bitwise
+representation identical to the given fixed-point number.
+
+The two’s-complement bits are kept unchanged, so this is the inverse of
+[`from_bits_unsigned`] and avoids a cast to an unsigned integer of the
+wrong width.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 2 is 0010.0000
+assert_eq!(Fix::from_num(2).to_bits_unsigned(), 0b10_0000);
+// −0.0625 is all ones in two’s complement
+assert_eq!(Fix::from_bits(-1).to_bits_unsigned(), !0);
+```
+
+[`from_bits_unsigned`]: #method.from_bits_unsigned
+";
+                    #[inline]
+                    pub const fn to_bits_unsigned(self) -> $UInner {
+                        self.bits as $UInner
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the sign and the bitwise representation of the
+absolute value.
+
+The first element is [`true`] if the number is negative. The second
+element contains both the integer and fractional bits of the absolute
+value",
+                if_signed_unsigned! {
+                    $Signedness,
+                    ", so it is an unsigned integer that can also hold the
+absolute value of [`min_value`].",
+                    ", which for unsigned numbers is just [`to_bits`].",
+                },
+                "
+
+The value is exactly the second element divided by
+2<sup>[`FRAC_NBITS`]</sup>, negated if the first element is [`true`].
+This can be used to convert to an arbitrary-precision rational type
+without any rounding.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 2.5 is 0010.1000
+assert_eq!(Fix::from_num(2.5).to_sign_bits(), (false, 0b10_1000));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-2.5).to_sign_bits(), (true, 0b10_1000));
+// the absolute value of the minimum has only the most significant bit set
+let (neg, abs) = Fix::min_value().to_sign_bits();
+assert!(neg);
+assert_eq!((abs.leading_zeros(), abs.count_ones()), (0, 1));
+",
+                },
+                "```
+",
+                if_signed_unsigned! {
+                    $Signedness,
+                    "
+[`FRAC_NBITS`]: #associatedconstant.FRAC_NBITS
+[`min_value`]: #method.min_value
+[`true`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+",
+                    "
+[`FRAC_NBITS`]: #associatedconstant.FRAC_NBITS
+[`to_bits`]: #method.to_bits
+[`true`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+",
+                };
+                #[inline]
+                pub fn to_sign_bits(self) -> (bool, $UInner) {
+                    self.to_bits().neg_abs()
+                }
+            }
+
             comment! {
                 "Creates a fixed-point number from its representation
 as a byte array in big endian.
@@ -446,6 +645,206 @@ assert_eq!(Fix::from_num(7.5).rem_euclid(Fix::from_num(2)), Fix::from_num(1.5));
                 }
             }
 
+            comment! {
+                "Returns the midpoint of `self` and `other`, that is
+their average, without any intermediate overflow.
+
+The exact average may need one more fractional bit than can be
+represented; in that case the result is rounded towards −∞.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(1).midpoint(Fix::from_num(4)), Fix::from_num(2.5));
+assert_eq!(Fix::max_value().midpoint(Fix::max_value()), Fix::max_value());
+// the exact midpoint of 0 and 1/16 is 1/32, which is rounded down to 0
+assert_eq!(Fix::from_num(0).midpoint(Fix::from_bits(1)), Fix::from_num(0));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::min_value().midpoint(Fix::max_value()), -Fix::from_bits(1));
+// the exact midpoint of −1/16 and 0 is −1/32, which is rounded down to −1/16
+assert_eq!(Fix::from_bits(-1).midpoint(Fix::from_num(0)), Fix::from_bits(-1));
+",
+                },
+                "```
+";
+                #[inline]
+                pub const fn midpoint(self, other: $Fixed<Frac>) -> $Fixed<Frac> {
+                    let (a, b) = (self.to_bits(), other.to_bits());
+                    // the shared bits plus half the differing bits cannot overflow
+                    Self::from_bits((a & b) + ((a ^ b) >> 1))
+                }
+            }
+
+            comment! {
+                "Returns the ordering between `self` and `other`.
+
+Fixed-point numbers have no NaN or signed zeros, so unlike for
+floating-point numbers this is the same total order as [`Ord`],
+and signed numbers compare by value rather than by bit pattern. It is
+also available through the [`Fixed`] trait so that generic code can
+use a single method to compare fixed-point numbers.
+
+# Examples
+
+```rust
+use core::cmp::Ordering;
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (a, b) = (Fix::from_num(1.5), Fix::from_num(2));
+assert_eq!(a.total_cmp(&b), Ordering::Less);
+assert_eq!(b.total_cmp(&a), Ordering::Greater);
+assert_eq!(a.total_cmp(&a), Ordering::Equal);
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-1).total_cmp(&Fix::from_num(0.5)), Ordering::Less);
+",
+                },
+                "```
+
+[`Fixed`]: traits/trait.Fixed.html
+[`Ord`]: https://doc.rust-lang.org/nightly/core/cmp/trait.Ord.html
+";
+                #[inline]
+                pub const fn total_cmp(&self, other: &$Fixed<Frac>) -> Ordering {
+                    let (a, b) = (self.to_bits(), other.to_bits());
+                    if a < b {
+                        Ordering::Less
+                    } else if a == b {
+                        Ordering::Equal
+                    } else {
+                        Ordering::Greater
+                    }
+                }
+            }
+
+            comment! {
+                "Flushes tiny values to zero. Returns zero if the
+absolute value of `self` is less than `threshold`, otherwise returns
+`self` unchanged.
+
+This is useful to stop integrators and filters from chattering around
+zero. The threshold is typically a small multiple of
+[`MIN_POSITIVE`]. A threshold that is not positive never flushes
+anything.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let threshold = Fix::from_bits(3);
+assert_eq!(Fix::from_bits(2).flush_to_zero(threshold), 0);
+assert_eq!(Fix::from_bits(3).flush_to_zero(threshold), Fix::from_bits(3));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_bits(-2).flush_to_zero(threshold), 0);
+assert_eq!(Fix::from_bits(-3).flush_to_zero(threshold), Fix::from_bits(-3));
+",
+                },
+                "```
+
+[`MIN_POSITIVE`]: #associatedconstant.MIN_POSITIVE
+";
+                #[inline]
+                pub fn flush_to_zero(self, threshold: $Fixed<Frac>) -> $Fixed<Frac> {
+                    // compare magnitudes as unsigned, so that the minimum
+                    // value of signed numbers does not overflow
+                    let (_, abs) = self.to_bits().neg_abs();
+                    let (threshold_neg, threshold_abs) = threshold.to_bits().neg_abs();
+                    if !threshold_neg && abs < threshold_abs {
+                        Self::from_bits(0)
+                    } else {
+                        self
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Finds a root of `f` in the interval between `lo`
+and `hi` using bisection.
+
+The function `f` must change sign over the interval, that is `f(lo)`
+and `f(hi)` must have opposite signs, otherwise [`None`] is returned.
+If either end is already a root, it is returned directly. Otherwise
+the interval is halved using [`midpoint`] at most `iters` times, each
+time keeping the half over which `f` changes sign, and the midpoint
+of the final interval is returned. The search stops early if an exact
+root is found or if the interval cannot be narrowed any further.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let two = Fix::from_num(2);
+// √2 ≈ 1.414, which lies between 1.375 and 1.4375
+let root = Fix::find_root(|x| x * x - two, Fix::from_num(0), two, 16);
+assert_eq!(root, Some(Fix::from_num(1.375)));
+// exact roots are found
+let root = Fix::find_root(|x| x - Fix::from_num(1.5), -two, two, 16);
+assert_eq!(root, Some(Fix::from_num(1.5)));
+// no sign change
+assert_eq!(Fix::find_root(|x| x * x + two, -two, two, 16), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`midpoint`]: #method.midpoint
+";
+                    #[inline]
+                    pub fn find_root<F>(
+                        f: F,
+                        lo: $Fixed<Frac>,
+                        hi: $Fixed<Frac>,
+                        iters: u32,
+                    ) -> Option<$Fixed<Frac>>
+                    where
+                        F: Fn($Fixed<Frac>) -> $Fixed<Frac>,
+                    {
+                        let (mut lo, mut hi) = if lo.to_bits() <= hi.to_bits() {
+                            (lo, hi)
+                        } else {
+                            (hi, lo)
+                        };
+                        let f_lo = f(lo).to_bits();
+                        if f_lo == 0 {
+                            return Some(lo);
+                        }
+                        let f_hi = f(hi).to_bits();
+                        if f_hi == 0 {
+                            return Some(hi);
+                        }
+                        let lo_neg = f_lo < 0;
+                        if lo_neg == (f_hi < 0) {
+                            return None;
+                        }
+                        for _ in 0..iters {
+                            let mid = lo.midpoint(hi);
+                            if mid.to_bits() == lo.to_bits() {
+                                break;
+                            }
+                            let f_mid = f(mid).to_bits();
+                            if f_mid == 0 {
+                                return Some(mid);
+                            }
+                            if (f_mid < 0) == lo_neg {
+                                lo = mid;
+                            } else {
+                                hi = mid;
+                            }
+                        }
+                        Some(lo.midpoint(hi))
+                    }
+                }
+            }
+
             if_signed! {
                 $Signedness;
                 comment! {
@@ -560,6 +959,46 @@ assert_eq!(Fix::max_value().checked_add(one), None);
                 }
             }
 
+            comment! {
+                "Adds up the values of an iterator, reporting where an
+overflow occurs.
+
+Returns the sum, or an [`OverflowAt`] error on overflow. The error
+contains the index of the value that could not be added and the
+partial sum of the values before it. The sum of no values is zero.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let ok = [1, 2, 3].iter().map(|&x| Fix::from_num(x));
+assert_eq!(Fix::try_fold_add(ok), Ok(Fix::from_num(6)));
+let one = Fix::from_num(1);
+let values = [Fix::max_value() - one, one, one];
+let err = Fix::try_fold_add(values.iter().cloned()).unwrap_err();
+assert_eq!(err.index(), 2);
+assert_eq!(err.partial_sum(), Fix::max_value());
+```
+
+[`OverflowAt`]: struct.OverflowAt.html
+";
+                #[inline]
+                pub fn try_fold_add<I>(iter: I) -> Result<$Fixed<Frac>, OverflowAt<$Fixed<Frac>>>
+                where
+                    I: IntoIterator<Item = $Fixed<Frac>>,
+                {
+                    let mut sum = Self::from_bits(0);
+                    for (index, value) in iter.into_iter().enumerate() {
+                        sum = match sum.checked_add(value) {
+                            Some(sum) => sum,
+                            None => return Err(OverflowAt::new(index, sum)),
+                        };
+                    }
+                    Ok(sum)
+                }
+            }
+
             comment! {
                 "Checked subtraction. Returns the difference, or [`None`] on overflow.
 
@@ -636,6 +1075,48 @@ assert_eq!(Fix::max_value().checked_mul_int(2), None);
                 }
             }
 
+            comment! {
+                "Checked multiplication by an [`i128`] integer. Returns
+the product, or [`None`] on overflow.
+
+Unlike [`checked_mul_int`], the integer can be wider than the
+underlying representation, so that a count does not need to be
+converted first.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(1.5).checked_mul_i128(3), Some(Fix::from_num(4.5)));
+assert_eq!(Fix::from_num(0).checked_mul_i128(i128::MAX), Some(Fix::from_num(0)));
+assert_eq!(Fix::from_num(2).checked_mul_i128(i128::MAX), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(1.5).checked_mul_i128(-3), Some(Fix::from_num(-4.5)));
+",
+                },
+                if_unsigned_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(1.5).checked_mul_i128(-3), None);
+",
+                },
+                "```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`checked_mul_int`]: #method.checked_mul_int
+[`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+";
+                #[inline]
+                pub fn checked_mul_i128(self, rhs: i128) -> Option<$Fixed<Frac>> {
+                    match self.overflowing_mul_i128(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
             comment! {
                 "Checked division by an integer. Returns the quotient, or
 [`None`] if the divisor is zero",
@@ -669,6 +1150,86 @@ assert_eq!(Fix::from_num(1).checked_div_int(0), None);
                 }
             }
 
+            comment! {
+                "Checked division by an [`i128`] integer. Returns the
+quotient, or [`None`] if the divisor is zero or if the quotient
+overflows.
+
+Unlike [`checked_div_int`], which truncates, the quotient is rounded to
+the nearest, with ties rounded to even, and the integer can be wider
+than the underlying representation. This makes it suitable for
+splitting a total into equal shares.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(6).checked_div_i128(4), Some(Fix::from_num(1.5)));
+// 1/3 is 0.0101…, which rounds to 0.0101 = 0.3125
+assert_eq!(Fix::from_num(1).checked_div_i128(3), Some(Fix::from_num(0.3125)));
+// 5/32 is a tie between 0.125 and 0.1875, which rounds to even
+assert_eq!(Fix::from_num(5).checked_div_i128(32), Some(Fix::from_num(0.125)));
+assert_eq!(Fix::from_num(1).checked_div_i128(i128::MAX), Some(Fix::from_num(0)));
+assert_eq!(Fix::from_num(1).checked_div_i128(0), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(6).checked_div_i128(-4), Some(Fix::from_num(-1.5)));
+assert_eq!(Fix::min_value().checked_div_i128(-1), None);
+",
+                },
+                if_unsigned_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(6).checked_div_i128(-4), None);
+// the quotient rounds to zero, which is representable
+assert_eq!(Fix::from_bits(1).checked_div_i128(-4), Some(Fix::from_num(0)));
+",
+                },
+                "```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`checked_div_int`]: #method.checked_div_int
+[`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+";
+                #[inline]
+                pub fn checked_div_i128(self, rhs: i128) -> Option<$Fixed<Frac>> {
+                    if rhs == 0 {
+                        return None;
+                    }
+                    let bits = self.to_bits();
+                    let abs = if_signed_unsigned! {
+                        $Signedness,
+                        bits.unsigned_abs() as u128,
+                        bits as u128,
+                    };
+                    let quot = helpers::shl_div_round_abs(abs, 0, rhs.unsigned_abs())?;
+                    if_signed_unsigned! {
+                        $Signedness,
+                        {
+                            let neg = (bits < 0) != (rhs < 0);
+                            let max_abs = if neg {
+                                <$Inner>::min_value().unsigned_abs() as u128
+                            } else {
+                                <$Inner>::max_value() as u128
+                            };
+                            if quot > max_abs {
+                                None
+                            } else if neg {
+                                Some(Self::from_bits((quot as $Inner).wrapping_neg()))
+                            } else {
+                                Some(Self::from_bits(quot as $Inner))
+                            }
+                        },
+                        if rhs < 0 && quot != 0 {
+                            None
+                        } else {
+                            Some(Self::from_bits(quot as $Inner))
+                        },
+                    }
+                }
+            }
+
             comment! {
                 "Checked remainder for Euclidean division. Returns the
 remainder, or [`None`] if the divisor is zero.
@@ -714,6 +1275,10 @@ assert_eq!(num.checked_rem_euclid(Fix::from_num(0)), None);
                 "Checked shift left. Returns the shifted number,
 or [`None`] if `rhs` ≥ ", $s_nbits, ".
 
+The underlying bits are shifted, so the binary point does not move
+and the value is multiplied by 2<sup>`rhs`</sup>. As with the
+primitive integers, bits shifted out of the number are lost.
+
 # Examples
 
 ```rust
@@ -735,6 +1300,10 @@ assert_eq!((Fix::from_num(1) / 2).checked_shl(", $s_nbits, "), None);
                 "Checked shift right. Returns the shifted number,
 or [`None`] if `rhs` ≥ ", $s_nbits, ".
 
+The underlying bits are shifted, so the binary point does not move
+and the value is divided by 2<sup>`rhs`</sup>, rounding towards
+−∞.
+
 # Examples
 
 ```rust
@@ -936,6 +1505,43 @@ assert_eq!(Fix::max_value().saturating_mul_int(2), Fix::max_value());
                 }
             }
 
+            comment! {
+                "Saturating multiplication by an [`i128`] integer.
+Returns the product, saturating on overflow.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(3).saturating_mul_i128(2), Fix::from_num(6));
+assert_eq!(Fix::from_num(2).saturating_mul_i128(i128::MAX), Fix::max_value());
+assert_eq!(Fix::from_num(2).saturating_mul_i128(i128::MIN), Fix::min_value());
+```
+
+[`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+";
+                #[inline]
+                pub fn saturating_mul_i128(self, rhs: i128) -> $Fixed<Frac> {
+                    match self.overflowing_mul_i128(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => if_signed_unsigned! {
+                            $Signedness,
+                            if (self.to_bits() < 0) != (rhs < 0) {
+                                Self::min_value()
+                            } else {
+                                Self::max_value()
+                            },
+                            if rhs < 0 {
+                                Self::min_value()
+                            } else {
+                                Self::max_value()
+                            },
+                        },
+                    }
+                }
+            }
+
             if_signed! {
                 $Signedness;
                 comment! {
@@ -1063,6 +1669,28 @@ assert_eq!(Fix::max_value().wrapping_mul_int(4), wrapped);
                 }
             }
 
+            comment! {
+                "Wrapping multiplication by an [`i128`] integer. Returns
+the product, wrapping on overflow.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(3).wrapping_mul_i128(2), Fix::from_num(6));
+let wrapped = Fix::from_bits(!0 << 2);
+assert_eq!(Fix::max_value().wrapping_mul_i128(4), wrapped);
+```
+
+[`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+";
+                #[inline]
+                pub fn wrapping_mul_i128(self, rhs: i128) -> $Fixed<Frac> {
+                    self.overflowing_mul_i128(rhs).0
+                }
+            }
+
             comment! {
                 "Wrapping division by an integer. Returns the quotient",
                 if_signed_unsigned! {
@@ -1294,6 +1922,50 @@ assert_eq!(Fix::max_value().overflowing_mul_int(4), (wrapped, true));
                 }
             }
 
+            comment! {
+                "Overflowing multiplication by an [`i128`] integer.
+
+Returns a [tuple] of the product and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(3).overflowing_mul_i128(2), (Fix::from_num(6), false));
+let wrapped = Fix::from_bits(!0 << 2);
+assert_eq!(Fix::max_value().overflowing_mul_i128(4), (wrapped, true));
+```
+
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+[`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+[tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+";
+                #[inline]
+                pub fn overflowing_mul_i128(self, rhs: i128) -> ($Fixed<Frac>, bool) {
+                    let bits = self.to_bits();
+                    // the product modulo 2^128 truncates to the wrapped product
+                    let wrapped = (bits as i128).wrapping_mul(rhs) as $Inner;
+                    let overflow = if_signed_unsigned! {
+                        $Signedness,
+                        match (bits as i128).checked_mul(rhs) {
+                            Some(prod) => prod as $Inner as i128 != prod,
+                            None => true,
+                        },
+                        if rhs < 0 {
+                            bits != 0
+                        } else {
+                            match (bits as u128).checked_mul(rhs as u128) {
+                                Some(prod) => prod as $Inner as u128 != prod,
+                                None => true,
+                            }
+                        },
+                    };
+                    (Self::from_bits(wrapped), overflow)
+                }
+            }
+
             comment! {
                 "Overflowing division by an integer.
 
@@ -25,7 +25,9 @@ macro_rules! fixed_round {
                     "Note that since the numbers are stored in two’s
 complement, negative numbers with non-zero fractional parts will be
 rounded towards −∞, except in the case where there are no integer
-bits, that is `", $s_fixed, "<U", $s_nbits, ">`, where the return value is always zero.",
+bits, that is `", $s_fixed, "<U", $s_nbits, ">`, where the return value is always zero.
+This is because the fractional bits are simply cleared; to round
+towards zero use [`round_to_zero`] instead.",
                 ),
                 "Note that for unsigned numbers, this is equivalent to [`floor`].",
             },
@@ -48,15 +50,19 @@ assert_eq!(two_and_quarter.int(), two);
 let three = Fix::from_num(3);
 // 1101.1100
 assert_eq!((-two_and_quarter).int(), -three);
+assert_eq!((-two_and_quarter).round_to_zero(), -two);
 ",
             },
             "```
 ",
-            if_unsigned_else_empty_str! {
+            if_signed_unsigned! {
                 $Signedness,
                 "
+[`round_to_zero`]: #method.round_to_zero
+",
+                "
 [`floor`]: #method.floor
-"
+",
             };
             #[inline]
             pub fn int(self) -> $Fixed<Frac> {
@@ -105,6 +111,58 @@ assert_eq!((-two_and_quarter).frac(), three_quarters);
             }
         }
 
+        comment! {
+            "Wraps the number into the unit interval [0, 1), that is
+returns `self` mod 1 with a non-negative result.
+
+This is equivalent to [`rem_euclid`] with a divisor of one, but it only
+needs to mask out the integer bits, which makes it suitable for phase
+accumulators.
+
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "The result is non-negative for negative numbers too, so
+for example −0.25 is wrapped to 0.75.
+
+# Panics
+
+Panics if the result does not fit. This can only happen when there are
+no integer bits, that is for `", $s_fixed, "<U", $s_nbits, ">`, where
+the result for a negative number would be in [0.5, 1).
+
+",
+            },
+            "# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(5.25).wrap_unit(), Fix::from_num(0.25));
+assert_eq!(Fix::from_num(3).wrap_unit(), Fix::from_num(0));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::from_num(-0.25).wrap_unit(), Fix::from_num(0.75));
+assert_eq!(Fix::from_num(-5.25).wrap_unit(), Fix::from_num(0.75));
+",
+            },
+            "```
+
+[`rem_euclid`]: #method.rem_euclid
+";
+            #[inline]
+            pub fn wrap_unit(self) -> $Fixed<Frac> {
+                let bits = self.to_bits() & Self::FRAC_MASK;
+                if_signed! {
+                    $Signedness;
+                    // with no integer bits the mask keeps the sign bit
+                    assert!(bits >= 0, "overflow");
+                }
+                Self::from_bits(bits)
+            }
+        }
+
         comment! {
             "Rounds to the next integer towards 0.
 
@@ -560,6 +618,156 @@ assert_eq!(Fix::max_value().saturating_round_ties_to_even(), Fix::max_value());
             }
         }
 
+        // Returns the sign and the absolute value of the nearest
+        // integer, with ties rounded away from zero. This cannot
+        // overflow since the absolute value of the integer part has
+        // at most 127 bits when there are fractional bits.
+        #[inline]
+        fn neg_abs_round(self) -> (bool, u128) {
+            let (neg, abs) = self.to_bits().neg_abs();
+            let abs: u128 = abs.into();
+            if Self::FRAC_NBITS == 0 {
+                return (neg, abs);
+            }
+            let int = abs.checked_shr(Self::FRAC_NBITS).unwrap_or(0);
+            let round_up = (abs >> (Self::FRAC_NBITS - 1)) & 1;
+            (neg, int + round_up)
+        }
+
+        comment! {
+            "Rounds to the nearest integer, with ties rounded away
+from zero, and returns it as [`i32`], saturating if it
+does not fit.
+
+Unlike [`saturating_round`], which saturates to the bounds of the
+fixed-point type, this only saturates to [`i32::MIN`] or
+[`i32::MAX`], so for example the maximum value of a type with
+fractional bits still rounds up to the next integer.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.5).round_to_i32(), 3);
+assert_eq!(Fix::from_num(2.4375).round_to_i32(), 2);
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::from_num(-2.5).round_to_i32(), -3);
+",
+            },
+            "```
+
+The saturation only depends on the integer type:
+
+```rust
+",
+            if_signed_unsigned! {
+                $Signedness,
+                "use substrate_fixed::types::{I8F8, I64F64};
+// the maximum of I8F8 is just below 128
+assert_eq!(I8F8::max_value().round_to_i32(), 128);
+assert_eq!(I64F64::max_value().round_to_i32(), i32::MAX);
+assert_eq!(I64F64::min_value().round_to_i32(), i32::MIN);
+",
+                "use substrate_fixed::types::{U8F8, U64F64};
+// the maximum of U8F8 is just below 256
+assert_eq!(U8F8::max_value().round_to_i32(), 256);
+assert_eq!(U64F64::max_value().round_to_i32(), i32::MAX);
+",
+            },
+            "```
+
+[`i32::MAX`]: https://doc.rust-lang.org/nightly/std/primitive.i32.html#associatedconstant.MAX
+[`i32::MIN`]: https://doc.rust-lang.org/nightly/std/primitive.i32.html#associatedconstant.MIN
+[`i32`]: https://doc.rust-lang.org/nightly/std/primitive.i32.html
+[`saturating_round`]: #method.saturating_round
+";
+            #[inline]
+            pub fn round_to_i32(self) -> i32 {
+                let (neg, abs) = self.neg_abs_round();
+                if neg {
+                    if abs > u128::from(i32::MIN.unsigned_abs()) {
+                        i32::MIN
+                    } else {
+                        (abs as i32).wrapping_neg()
+                    }
+                } else if abs > i32::MAX as u128 {
+                    i32::MAX
+                } else {
+                    abs as i32
+                }
+            }
+        }
+
+        comment! {
+            "Rounds to the nearest integer, with ties rounded away
+from zero, and returns it as [`i64`], saturating if it
+does not fit.
+
+Unlike [`saturating_round`], which saturates to the bounds of the
+fixed-point type, this only saturates to [`i64::MIN`] or
+[`i64::MAX`], so for example the maximum value of a type with
+fractional bits still rounds up to the next integer.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.5).round_to_i64(), 3);
+assert_eq!(Fix::from_num(2.4375).round_to_i64(), 2);
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::from_num(-2.5).round_to_i64(), -3);
+",
+            },
+            "```
+
+The saturation only depends on the integer type:
+
+```rust
+",
+            if_signed_unsigned! {
+                $Signedness,
+                "use substrate_fixed::types::{I8F8, I96F32};
+// the maximum of I8F8 is just below 128
+assert_eq!(I8F8::max_value().round_to_i64(), 128);
+assert_eq!(I96F32::max_value().round_to_i64(), i64::MAX);
+assert_eq!(I96F32::min_value().round_to_i64(), i64::MIN);
+",
+                "use substrate_fixed::types::{U8F8, U96F32};
+// the maximum of U8F8 is just below 256
+assert_eq!(U8F8::max_value().round_to_i64(), 256);
+assert_eq!(U96F32::max_value().round_to_i64(), i64::MAX);
+",
+            },
+            "```
+
+[`i64::MAX`]: https://doc.rust-lang.org/nightly/std/primitive.i64.html#associatedconstant.MAX
+[`i64::MIN`]: https://doc.rust-lang.org/nightly/std/primitive.i64.html#associatedconstant.MIN
+[`i64`]: https://doc.rust-lang.org/nightly/std/primitive.i64.html
+[`saturating_round`]: #method.saturating_round
+";
+            #[inline]
+            pub fn round_to_i64(self) -> i64 {
+                let (neg, abs) = self.neg_abs_round();
+                if neg {
+                    if abs > u128::from(i64::MIN.unsigned_abs()) {
+                        i64::MIN
+                    } else {
+                        (abs as i64).wrapping_neg()
+                    }
+                } else if abs > i64::MAX as u128 {
+                    i64::MAX
+                } else {
+                    abs as i64
+                }
+            }
+        }
+
         comment! {
             "Wrapping ceil. Rounds to the next integer towards +∞,
 wrapping on overflow.
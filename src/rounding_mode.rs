@@ -0,0 +1,39 @@
+// Copyright © 2018–2019 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+/// The rounding mode used when a value cannot be represented exactly.
+///
+/// # Examples
+///
+/// ```rust
+/// use substrate_fixed::{types::I8F8, RoundingMode};
+/// // 1/1024 is smaller than the smallest positive I8F8
+/// let tiny = 1.0 / 1024.0;
+/// assert_eq!(I8F8::from_num_round(tiny, RoundingMode::Nearest), 0);
+/// assert_eq!(I8F8::from_num_round(tiny, RoundingMode::Ceil), I8F8::from_bits(1));
+/// assert_eq!(I8F8::from_num_round(-tiny, RoundingMode::Floor), I8F8::from_bits(-1));
+/// assert_eq!(I8F8::from_num_round(-tiny, RoundingMode::Zero), 0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest, with ties rounded to even.
+    Nearest,
+    /// Round towards −∞.
+    Floor,
+    /// Round towards +∞.
+    Ceil,
+    /// Round towards zero.
+    Zero,
+}
@@ -20,10 +20,11 @@ This module contains traits.
 use crate::{
     helpers::{FloatHelper, FloatKind, FromFloatHelper, IntHelper, Sealed, Widest},
     types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8, Unsigned},
-    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8, ParseFixedError,
+    ConversionError, FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16,
+    FixedU32, FixedU64, FixedU8, ParseFixedError,
 };
 use core::{
+    cmp::Ordering,
     fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex},
     hash::Hash,
     mem,
@@ -246,10 +247,73 @@ where
     /// [`frac_nbits`]: #tymethod.frac_nbits
     type Frac: Unsigned;
 
+    /// The signed fixed-point type with the same number of bits and
+    /// fractional bits.
+    ///
+    /// This is `Self` for signed types, so that for example
+    /// <code>[FixedU16][`FixedU16`]&lt;Frac&gt;::Signed</code> is
+    /// <code>[FixedI16][`FixedI16`]&lt;Frac&gt;</code>.
+    ///
+    /// [`FixedI16`]: ../struct.FixedI16.html
+    /// [`FixedU16`]: ../struct.FixedU16.html
+    type Signed: FixedSigned<Frac = Self::Frac>;
+
+    /// The unsigned fixed-point type with the same number of bits and
+    /// fractional bits.
+    ///
+    /// This is `Self` for unsigned types, so that for example
+    /// <code>[FixedI16][`FixedI16`]&lt;Frac&gt;::Unsigned</code> is
+    /// <code>[FixedU16][`FixedU16`]&lt;Frac&gt;</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{
+    ///     traits::Fixed,
+    ///     types::{I8F8, U8F8},
+    /// };
+    /// // clamps negative values to zero in the unsigned sibling type
+    /// fn non_negative<F: Fixed>(x: F) -> F::Unsigned {
+    ///     x.checked_to_unsigned().unwrap_or_else(|| F::Unsigned::from_num(0))
+    /// }
+    /// assert_eq!(non_negative(I8F8::from_num(2.5)), U8F8::from_num(2.5));
+    /// assert_eq!(non_negative(I8F8::from_num(-3)), U8F8::from_num(0));
+    /// ```
+    ///
+    /// [`FixedI16`]: ../struct.FixedI16.html
+    /// [`FixedU16`]: ../struct.FixedU16.html
+    type Unsigned: FixedUnsigned<Frac = Self::Frac>;
+
     /// Returns the smallest value that can be represented.
     fn min_value() -> Self;
 
     /// Returns the largest value that can be represented.
+    ///
+    /// Together with [`min_value`], this lets generic code find the
+    /// bounds of a fixed-point type without knowing the concrete type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{
+    ///     traits::Fixed,
+    ///     types::{I8F8, U8F8},
+    /// };
+    /// fn saturate<F: Fixed>(val: f64) -> F {
+    ///     F::checked_from_num(val).unwrap_or_else(|| {
+    ///         if val < 0.0 { F::min_value() } else { F::max_value() }
+    ///     })
+    /// }
+    /// fn bits<F: Fixed>() -> (F::Bits, F::Bits) {
+    ///     (F::min_value().to_bits(), F::max_value().to_bits())
+    /// }
+    /// assert_eq!(bits::<I8F8>(), (i16::min_value(), i16::max_value()));
+    /// assert_eq!(bits::<U8F8>(), (0, u16::max_value()));
+    /// assert_eq!(saturate::<I8F8>(-500.0), I8F8::min_value());
+    /// assert_eq!(saturate::<U8F8>(500.0), U8F8::max_value());
+    /// ```
+    ///
+    /// [`min_value`]: #tymethod.min_value
     fn max_value() -> Self;
 
     /// Returns the number of integer bits.
@@ -258,6 +322,29 @@ where
     /// Returns the number of fractional bits.
     fn frac_nbits() -> u32;
 
+    /// Returns the scaling factor 2<sup>[`frac_nbits`]</sup> as the
+    /// underlying integer type, or [`None`] if one cannot be
+    /// represented.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    /// [`frac_nbits`]: #tymethod.frac_nbits
+    fn scale() -> Option<Self::Bits>;
+
+    /// Returns the Q format of the number as a [tuple] of the number of
+    /// integer bits, the number of fractional bits, and whether the type
+    /// is signed.
+    ///
+    /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+    fn q_format(self) -> (u32, u32, bool);
+
+    /// Returns a number with all the integer bits set and all the
+    /// fractional bits cleared.
+    fn int_mask_value() -> Self;
+
+    /// Returns a number with all the fractional bits set and all the
+    /// integer bits cleared.
+    fn frac_mask_value() -> Self;
+
     /// Creates a fixed-point number that has a bitwise representation
     /// identical to the given integer.
     fn from_bits(bits: Self::Bits) -> Self;
@@ -322,6 +409,47 @@ where
     /// [`checked_from_fixed`]: trait.FromFixed.html#tymethod.checked_from_fixed
     fn checked_to_num<Dst: FromFixed>(self) -> Option<Dst>;
 
+    /// Converts to the [`Signed`] sibling type if the value fits,
+    /// otherwise returns [`None`].
+    ///
+    /// As both types have the same number of fractional bits, the bit
+    /// pattern is unchanged; the conversion fails only for unsigned
+    /// values with the most significant bit set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{traits::Fixed, types::{I8F8, U8F8}};
+    /// let half = U8F8::from_num(0.5);
+    /// assert_eq!(half.checked_to_signed(), Some(I8F8::from_num(0.5)));
+    /// assert_eq!(half.checked_to_signed().unwrap().to_bits(), 0x0080);
+    /// assert_eq!(U8F8::from_num(200).checked_to_signed(), None);
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    /// [`Signed`]: #associatedtype.Signed
+    fn checked_to_signed(self) -> Option<Self::Signed>;
+
+    /// Converts to the [`Unsigned`] sibling type if the value fits,
+    /// otherwise returns [`None`].
+    ///
+    /// As both types have the same number of fractional bits, the bit
+    /// pattern is unchanged; the conversion fails only for negative
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{traits::Fixed, types::{I8F8, U8F8}};
+    /// let half = I8F8::from_num(0.5);
+    /// assert_eq!(half.checked_to_unsigned(), Some(U8F8::from_num(0.5)));
+    /// assert_eq!((-half).checked_to_unsigned(), None);
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    /// [`Unsigned`]: #associatedtype.Unsigned
+    fn checked_to_unsigned(self) -> Option<Self::Unsigned>;
+
     /// Creates a fixed-point number from another number, saturating the
     /// value if it does not fit.
     ///
@@ -489,6 +617,15 @@ where
     /// Returns the fractional part.
     fn frac(self) -> Self;
 
+    /// Wraps the number into the unit interval [0, 1), that is returns
+    /// `self` mod 1 with a non-negative result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit, which can only happen for
+    /// negative numbers of signed types with no integer bits.
+    fn wrap_unit(self) -> Self;
+
     /// Rounds to the next integer towards 0.
     fn round_to_zero(self) -> Self;
 
@@ -620,6 +757,17 @@ where
     /// Shifts to the right by `n` bits, wrapping the truncated bits to the left end.
     fn rotate_right(self, n: u32) -> Self;
 
+    /// Returns the ordering between `self` and `other`.
+    ///
+    /// This is a total order, the same as the one given by [`Ord`].
+    ///
+    /// [`Ord`]: https://doc.rust-lang.org/nightly/core/cmp/trait.Ord.html
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Returns zero if the absolute value is less than `threshold`,
+    /// otherwise returns `self` unchanged.
+    fn flush_to_zero(self, threshold: Self) -> Self;
+
     /// Euclidean division by an integer.
     ///
     /// # Panics
@@ -668,6 +816,13 @@ where
     /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
     fn checked_mul(self, rhs: Self) -> Option<Self>;
 
+    /// Checked square. Returns `self` × `self`, or [`None`] on overflow.
+    ///
+    /// The square is rounded to the nearest, with ties rounded to even.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    fn checked_square(self) -> Option<Self>;
+
     /// Checked division. Returns the quotient, or [`None`] if the
     /// divisor is zero or on overflow.
     ///
@@ -699,6 +854,13 @@ where
     /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
     fn checked_mul_int(self, rhs: Self::Bits) -> Option<Self>;
 
+    /// Checked multiplication by an [`i128`] integer. Returns the
+    /// product, or [`None`] on overflow.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    /// [`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+    fn checked_mul_i128(self, rhs: i128) -> Option<Self>;
+
     /// Checked division by an integer. Returns the quotient, or
     /// [`None`] if the divisor is zero or if the division results in
     /// overflow.
@@ -706,6 +868,14 @@ where
     /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
     fn checked_div_int(self, rhs: Self::Bits) -> Option<Self>;
 
+    /// Checked division by an [`i128`] integer. Returns the quotient
+    /// rounded to the nearest, with ties rounded to even, or [`None`]
+    /// if the divisor is zero or if the quotient overflows.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    /// [`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+    fn checked_div_i128(self, rhs: i128) -> Option<Self>;
+
     /// Checked fixed-point remainder for division by an integer.
     /// Returns the remainder, or [`None`] if the divisor is zero or
     /// if the division results in overflow.
@@ -727,6 +897,12 @@ where
     /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
     fn checked_rem_euclid_int(self, rhs: Self::Bits) -> Option<Self>;
 
+    /// Checked addition of an integer. Returns the sum, or [`None`] on
+    /// overflow.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    fn checked_add_int(self, rhs: i128) -> Option<Self>;
+
     /// Checked shift left. Returns the shifted number, or [`None`] if
     /// `rhs` ≥ the number of bits.
     ///
@@ -751,6 +927,11 @@ where
     /// Saturating multiplication. Returns the product, saturating on overflow.
     fn saturating_mul(self, rhs: Self) -> Self;
 
+    /// Saturating square. Returns `self` × `self`, saturating on overflow.
+    ///
+    /// The square is rounded to the nearest, with ties rounded to even.
+    fn saturating_square(self) -> Self;
+
     /// Saturating division. Returns the quotient, saturating on overflow.
     ///
     /// # Panics
@@ -765,9 +946,19 @@ where
     /// Panics if the divisor is zero.
     fn saturating_div_euclid(self, rhs: Self) -> Self;
 
+    /// Saturating addition of an integer. Returns the sum, saturating
+    /// on overflow.
+    fn saturating_add_int(self, rhs: i128) -> Self;
+
     /// Saturating multiplication by an integer. Returns the product, saturating on overflow.
     fn saturating_mul_int(self, rhs: Self::Bits) -> Self;
 
+    /// Saturating multiplication by an [`i128`] integer. Returns the
+    /// product, saturating on overflow.
+    ///
+    /// [`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+    fn saturating_mul_i128(self, rhs: i128) -> Self;
+
     /// Wrapping negation. Returns the negated value, wrapping on overflow.
     fn wrapping_neg(self) -> Self;
 
@@ -797,6 +988,12 @@ where
     /// Wrapping multiplication by an integer. Returns the product, wrapping on overflow.
     fn wrapping_mul_int(self, rhs: Self::Bits) -> Self;
 
+    /// Wrapping multiplication by an [`i128`] integer. Returns the
+    /// product, wrapping on overflow.
+    ///
+    /// [`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+    fn wrapping_mul_i128(self, rhs: i128) -> Self;
+
     /// Wrapping division by an integer. Returns the quotient, wrapping on overflow.
     ///
     /// Overflow can only occur when dividing the minimum value by −1.
@@ -824,6 +1021,10 @@ where
     /// Panics if the divisor is zero.
     fn wrapping_rem_euclid_int(self, rhs: Self::Bits) -> Self;
 
+    /// Wrapping addition of an integer. Returns the sum, wrapping on
+    /// overflow.
+    fn wrapping_add_int(self, rhs: i128) -> Self;
+
     /// Wrapping shift left. Wraps `rhs` if `rhs` ≥ the number of
     /// bits, then shifts and returns the number.
     fn wrapping_shl(self, rhs: u32) -> Self;
@@ -910,6 +1111,17 @@ where
     /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
     fn overflowing_mul_int(self, rhs: Self::Bits) -> (Self, bool);
 
+    /// Overflowing multiplication by an [`i128`] integer.
+    ///
+    /// Returns a [tuple] of the product and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
+    ///
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    /// [`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+    /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+    fn overflowing_mul_i128(self, rhs: i128) -> (Self, bool);
+
     /// Overflowing division by an integer.
     ///
     /// Returns a [tuple] of the quotient and a [`bool`], indicating
@@ -952,6 +1164,16 @@ where
     /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
     fn overflowing_rem_euclid_int(self, rhs: Self::Bits) -> (Self, bool);
 
+    /// Overflowing addition of an integer.
+    ///
+    /// Returns a [tuple] of the sum and a [`bool`], indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+    fn overflowing_add_int(self, rhs: i128) -> (Self, bool);
+
     /// Overflowing shift left.
     ///
     /// Returns a [tuple] of the shifted value and a [`bool`],
@@ -1071,6 +1293,21 @@ pub trait FixedSigned: Fixed + Neg<Output = Self> {
     /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
     /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
     fn overflowing_abs(self) -> (Self, bool);
+
+    /// Returns the absolute value as the unsigned fixed-point number
+    /// with the same layout.
+    ///
+    /// This cannot overflow, as the absolute value of the minimum value
+    /// fits in the unsigned type.
+    fn unsigned_abs(self) -> Self::Unsigned;
+
+    /// Reinterprets the bits as an unsigned fixed-point number with
+    /// the same layout.
+    ///
+    /// Negative numbers wrap around, so −1 becomes the unsigned
+    /// number 2<sup><i>n</i></sup> − 1 for an <i>n</i>-bit number
+    /// with no fractional bits.
+    fn reinterpret_sign(self) -> Self::Unsigned;
 }
 
 /// This trait provides methods common to all unsigned fixed-point numbers.
@@ -1103,6 +1340,13 @@ pub trait FixedUnsigned: Fixed {
     ///
     /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
     fn checked_next_power_of_two(self) -> Option<Self>;
+
+    /// Reinterprets the bits as a signed fixed-point number with the
+    /// same layout.
+    ///
+    /// Numbers with the most significant bit set wrap around to
+    /// negative numbers.
+    fn reinterpret_sign(self) -> Self::Signed;
 }
 
 /// This trait provides infallible conversions that might be lossy.
@@ -1160,6 +1404,105 @@ where
     }
 }
 
+/// This trait chains conversions between fixed-point types, stopping at
+/// the first failure.
+///
+/// It is implemented for fixed-point numbers, for [`Option`] of
+/// fixed-point numbers as returned by checked operations, and for
+/// [`Result`] of fixed-point numbers with an error that can be
+/// converted into [`ConversionError`], such as the result of parsing.
+/// Each call to [`and_then_to`] converts the value into another
+/// fixed-point type as [`checked_from_fixed`] does, so a multi-stage
+/// pipeline reads as a single chain with a single error type.
+///
+/// # Examples
+///
+/// ```rust
+/// use substrate_fixed::{traits::FixedConvert, types::*, ConversionError};
+/// // parse, widen for intermediate work, then narrow for storage
+/// let stored = "1.5"
+///     .parse::<I16F16>()
+///     .and_then_to::<I32F32>()
+///     .map(|x| x * 3)
+///     .and_then_to::<U8F8>();
+/// assert_eq!(stored, Ok(U8F8::from_num(4.5)));
+/// let negative = "-1.5".parse::<I16F16>().and_then_to::<U8F8>();
+/// assert_eq!(negative, Err(ConversionError::Overflow));
+/// ```
+///
+/// [`ConversionError`]: ../enum.ConversionError.html
+/// [`Option`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html
+/// [`Result`]: https://doc.rust-lang.org/nightly/core/result/enum.Result.html
+/// [`and_then_to`]: #tymethod.and_then_to
+/// [`checked_from_fixed`]: trait.FromFixed.html#tymethod.checked_from_fixed
+pub trait FixedConvert {
+    /// Converts the value into the fixed-point type `Dst`.
+    ///
+    /// Returns [`ConversionError::Overflow`] if there is no value or
+    /// if the value does not fit, and passes on any earlier error.
+    /// Any extra fractional bits are truncated.
+    ///
+    /// [`ConversionError::Overflow`]: ../enum.ConversionError.html#variant.Overflow
+    fn and_then_to<Dst: Fixed>(self) -> Result<Dst, ConversionError>;
+}
+
+impl<F: Fixed, E: Into<ConversionError>> FixedConvert for Result<F, E> {
+    #[inline]
+    fn and_then_to<Dst: Fixed>(self) -> Result<Dst, ConversionError> {
+        let src = self.map_err(Into::into)?;
+        Dst::checked_from_fixed(src).ok_or(ConversionError::Overflow)
+    }
+}
+
+impl<F: Fixed> FixedConvert for Option<F> {
+    #[inline]
+    fn and_then_to<Dst: Fixed>(self) -> Result<Dst, ConversionError> {
+        let src = self.ok_or(ConversionError::Overflow)?;
+        Dst::checked_from_fixed(src).ok_or(ConversionError::Overflow)
+    }
+}
+
+/// This trait is implemented for pairs of fixed-point numbers that have
+/// the same layout, that is the same number of bits and the same number
+/// of fractional bits, but possibly different signedness.
+///
+/// Since the layouts match, the bits can be reinterpreted without any
+/// shifting, so this can be used as a bound for generic code that needs
+/// a zero-cost conversion. Every fixed-point number has the same layout
+/// as itself.
+///
+/// This trait is sealed and cannot be implemented for more types.
+///
+/// # Examples
+///
+/// ```rust
+/// use substrate_fixed::traits::SameLayout;
+/// use substrate_fixed::types::{I8F8, U8F8};
+/// fn reinterpret<Src: SameLayout<Dst>, Dst>(src: Src) -> Dst {
+///     src.reinterpret()
+/// }
+/// let dst: U8F8 = reinterpret(I8F8::from_num(-1));
+/// assert_eq!(dst, U8F8::from_num(255));
+/// let dst: I8F8 = reinterpret(U8F8::from_num(1.5));
+/// assert_eq!(dst, I8F8::from_num(1.5));
+/// ```
+///
+/// Types with the same width but a different number of fractional bits
+/// do not have the same layout.
+///
+/// ```compile_fail
+/// use substrate_fixed::traits::SameLayout;
+/// use substrate_fixed::types::{I16F16, U8F24};
+/// fn reinterpret<Src: SameLayout<Dst>, Dst>(src: Src) -> Dst {
+///     src.reinterpret()
+/// }
+/// let _: I16F16 = reinterpret(U8F24::from_num(1));
+/// ```
+pub trait SameLayout<Dst>: Sealed {
+    /// Reinterprets the bits as a fixed-point number of type `Dst`.
+    fn reinterpret(self) -> Dst;
+}
+
 /// This trait provides checked conversions from fixed-point numbers.
 ///
 /// This trait is implemented for conversions between integer
@@ -1181,6 +1524,28 @@ where
 /// assert_eq!(i8::wrapping_from_fixed(f), 0x87u8 as i8);
 /// assert_eq!(i8::overflowing_from_fixed(f), (0x87u8 as i8, true));
 /// ```
+///
+/// Since every fixed-point number implements this trait, generic code
+/// written from the point of view of the destination can convert from
+/// any fixed-point type. This is the counterpart of [`ToFixed`].
+///
+/// ```rust
+/// use substrate_fixed::traits::{Fixed, FromFixed};
+/// use substrate_fixed::types::{I16F16, I4F4, I8F24, U8F8};
+/// fn convert<Dst: Fixed, Src: Fixed>(src: Src) -> Option<Dst> {
+///     Dst::checked_from_fixed(src)
+/// }
+/// let x = I8F24::from_num(-3.3);
+/// // the value fits, and extra fractional bits are truncated
+/// assert_eq!(convert::<I16F16, _>(x), Some(I16F16::from_num(x)));
+/// assert_eq!(convert::<I4F4, _>(x), Some(I4F4::from_num(-3.3125)));
+/// // the value does not fit
+/// assert_eq!(convert::<I4F4, _>(I8F24::from_num(8)), None);
+/// assert_eq!(U8F8::saturating_from_fixed(x), 0);
+/// assert_eq!(I16F16::from_fixed(I4F4::from_num(1.5)), 1.5);
+/// ```
+///
+/// [`ToFixed`]: trait.ToFixed.html
 pub trait FromFixed {
     /// Converts from a fixed-point number.
     ///
@@ -1254,6 +1619,25 @@ pub trait FromFixed {
 /// let overflowing: (U8F8, bool) = too_large.overflowing_to_num();
 /// assert_eq!(overflowing, (U8F8::from_bits(0x3456), true));
 /// ```
+///
+/// Since every fixed-point number implements this trait, generic code
+/// can convert between any two fixed-point types.
+///
+/// ```rust
+/// use substrate_fixed::traits::{Fixed, ToFixed};
+/// use substrate_fixed::types::{I16F16, I4F4, I8F24};
+/// fn convert<Src: Fixed, Dst: Fixed>(src: Src) -> Option<Dst> {
+///     src.checked_to_fixed()
+/// }
+/// let x = I8F24::from_num(-3.3);
+/// // the value fits, and extra fractional bits are truncated
+/// assert_eq!(convert::<_, I16F16>(x), Some(I16F16::from_num(x)));
+/// assert_eq!(convert::<_, I4F4>(x), Some(I4F4::from_num(-3.3125)));
+/// // the value does not fit
+/// assert_eq!(convert::<_, I4F4>(I8F24::from_num(8)), None);
+/// let y: I16F16 = I4F4::from_num(1.5).to_fixed();
+/// assert_eq!(y, 1.5);
+/// ```
 pub trait ToFixed {
     /// Converts to a fixed-point number.
     ///
@@ -1717,17 +2101,28 @@ macro_rules! trait_delegate {
 }
 
 macro_rules! impl_fixed {
-    ($Fixed:ident, $LeEqU:ident, $Bits:ident, $Signedness:tt) => {
+    (
+        $Fixed:ident[$IFixed:ident, $UFixed:ident],
+        $LeEqU:ident,
+        $Bits:ident,
+        $Signedness:tt
+    ) => {
         impl<Frac: $LeEqU> FixedOptionalFeatures for $Fixed<Frac> {}
 
         impl<Frac: $LeEqU> Fixed for $Fixed<Frac> {
             type Bits = $Bits;
             type Bytes = [u8; mem::size_of::<$Bits>()];
             type Frac = Frac;
+            type Signed = $IFixed<Frac>;
+            type Unsigned = $UFixed<Frac>;
             trait_delegate! { fn min_value() -> Self }
             trait_delegate! { fn max_value() -> Self }
             trait_delegate! { fn int_nbits() -> u32 }
+            trait_delegate! { fn q_format(self) -> (u32, u32, bool) }
             trait_delegate! { fn frac_nbits() -> u32 }
+            trait_delegate! { fn scale() -> Option<Self::Bits> }
+            trait_delegate! { fn int_mask_value() -> Self }
+            trait_delegate! { fn frac_mask_value() -> Self }
             trait_delegate! { fn from_bits(bits: Self::Bits) -> Self }
             trait_delegate! { fn to_bits(self) -> Self::Bits }
             trait_delegate! { fn from_be_bytes(bits: Self::Bytes) -> Self }
@@ -1740,6 +2135,14 @@ macro_rules! impl_fixed {
             trait_delegate! { fn to_num<Dst: FromFixed>(self) -> Dst }
             trait_delegate! { fn checked_from_num<Src: ToFixed>(val: Src) -> Option<Self> }
             trait_delegate! { fn checked_to_num<Dst: FromFixed>(self) -> Option<Dst> }
+            #[inline]
+            fn checked_to_signed(self) -> Option<Self::Signed> {
+                self.checked_to_num()
+            }
+            #[inline]
+            fn checked_to_unsigned(self) -> Option<Self::Unsigned> {
+                self.checked_to_num()
+            }
             trait_delegate! { fn saturating_from_num<Src: ToFixed>(val: Src) -> Self }
             trait_delegate! { fn saturating_to_num<Dst: FromFixed>(self) -> Dst }
             trait_delegate! { fn wrapping_from_num<Src: ToFixed>(val: Src) -> Self }
@@ -1787,6 +2190,7 @@ macro_rules! impl_fixed {
             }
             trait_delegate! { fn int(self) -> Self }
             trait_delegate! { fn frac(self) -> Self }
+            trait_delegate! { fn wrap_unit(self) -> Self }
             trait_delegate! { fn ceil(self) -> Self }
             trait_delegate! { fn floor(self) -> Self }
             trait_delegate! { fn round_to_zero(self) -> Self }
@@ -1814,6 +2218,11 @@ macro_rules! impl_fixed {
             trait_delegate! { fn trailing_zeros(self) -> u32 }
             trait_delegate! { fn rotate_left(self, n: u32) -> Self }
             trait_delegate! { fn rotate_right(self, n: u32) -> Self }
+            #[inline]
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                $Fixed::total_cmp(self, other)
+            }
+            trait_delegate! { fn flush_to_zero(self, threshold: Self) -> Self }
             trait_delegate! { fn div_euclid(self, rhs: Self) -> Self }
             trait_delegate! { fn rem_euclid(self, rhs: Self) -> Self }
             trait_delegate! { fn div_euclid_int(self, rhs: Self::Bits) -> Self }
@@ -1822,24 +2231,31 @@ macro_rules! impl_fixed {
             trait_delegate! { fn checked_add(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_sub(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_mul(self, rhs: Self) -> Option<Self> }
+            trait_delegate! { fn checked_square(self) -> Option<Self> }
             trait_delegate! { fn checked_div(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_rem(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_div_euclid(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_rem_euclid(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_mul_int(self, rhs: Self::Bits) -> Option<Self> }
+            trait_delegate! { fn checked_mul_i128(self, rhs: i128) -> Option<Self> }
             trait_delegate! { fn checked_div_int(self, rhs: Self::Bits) -> Option<Self> }
+            trait_delegate! { fn checked_div_i128(self, rhs: i128) -> Option<Self> }
             trait_delegate! { fn checked_rem_int(self, rhs: Self::Bits) -> Option<Self> }
             trait_delegate! { fn checked_div_euclid_int(self, rhs: Self::Bits) -> Option<Self> }
             trait_delegate! { fn checked_rem_euclid_int(self, rhs: Self::Bits) -> Option<Self> }
+            trait_delegate! { fn checked_add_int(self, rhs: i128) -> Option<Self> }
             trait_delegate! { fn checked_shl(self, rhs: u32) -> Option<Self> }
             trait_delegate! { fn checked_shr(self, rhs: u32) -> Option<Self> }
             trait_delegate! { fn saturating_neg(self) -> Self }
             trait_delegate! { fn saturating_add(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_sub(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_mul(self, rhs: Self) -> Self }
+            trait_delegate! { fn saturating_square(self) -> Self }
             trait_delegate! { fn saturating_div(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_div_euclid(self, rhs: Self) -> Self }
+            trait_delegate! { fn saturating_add_int(self, rhs: i128) -> Self }
             trait_delegate! { fn saturating_mul_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn saturating_mul_i128(self, rhs: i128) -> Self }
             trait_delegate! { fn wrapping_neg(self) -> Self }
             trait_delegate! { fn wrapping_add(self, rhs: Self) -> Self }
             trait_delegate! { fn wrapping_sub(self, rhs: Self) -> Self }
@@ -1847,9 +2263,11 @@ macro_rules! impl_fixed {
             trait_delegate! { fn wrapping_div(self, rhs: Self) -> Self }
             trait_delegate! { fn wrapping_div_euclid(self, rhs: Self) -> Self }
             trait_delegate! { fn wrapping_mul_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn wrapping_mul_i128(self, rhs: i128) -> Self }
             trait_delegate! { fn wrapping_div_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn wrapping_div_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn wrapping_rem_euclid_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn wrapping_add_int(self, rhs: i128) -> Self }
             trait_delegate! { fn wrapping_shl(self, rhs: u32) -> Self }
             trait_delegate! { fn wrapping_shr(self, rhs: u32) -> Self }
             trait_delegate! { fn overflowing_neg(self) -> (Self, bool) }
@@ -1859,9 +2277,11 @@ macro_rules! impl_fixed {
             trait_delegate! { fn overflowing_div(self, rhs: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_div_euclid(self, rhs: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_mul_int(self, rhs: Self::Bits) -> (Self, bool) }
+            trait_delegate! { fn overflowing_mul_i128(self, rhs: i128) -> (Self, bool) }
             trait_delegate! { fn overflowing_div_int(self, rhs: Self::Bits) -> (Self, bool) }
             trait_delegate! { fn overflowing_div_euclid_int(self, rhs: Self::Bits) -> (Self, bool) }
             trait_delegate! { fn overflowing_rem_euclid_int(self, rhs: Self::Bits) -> (Self, bool) }
+            trait_delegate! { fn overflowing_add_int(self, rhs: i128) -> (Self, bool) }
             trait_delegate! { fn overflowing_shl(self, rhs: u32) -> (Self, bool) }
             trait_delegate! { fn overflowing_shr(self, rhs: u32) -> (Self, bool) }
         }
@@ -2022,6 +2442,27 @@ macro_rules! impl_fixed {
             }
         }
 
+        impl<Frac: $LeEqU> FixedConvert for $Fixed<Frac> {
+            #[inline]
+            fn and_then_to<Dst: Fixed>(self) -> Result<Dst, ConversionError> {
+                Dst::checked_from_fixed(self).ok_or(ConversionError::Overflow)
+            }
+        }
+
+        impl<Frac: $LeEqU> SameLayout<$IFixed<Frac>> for $Fixed<Frac> {
+            #[inline]
+            fn reinterpret(self) -> $IFixed<Frac> {
+                $IFixed::from_bits(self.to_bits() as _)
+            }
+        }
+
+        impl<Frac: $LeEqU> SameLayout<$UFixed<Frac>> for $Fixed<Frac> {
+            #[inline]
+            fn reinterpret(self) -> $UFixed<Frac> {
+                $UFixed::from_bits(self.to_bits() as _)
+            }
+        }
+
         if_signed! {
             $Signedness;
             impl<Frac: $LeEqU> FixedSigned for $Fixed<Frac> {
@@ -2031,8 +2472,13 @@ macro_rules! impl_fixed {
                 trait_delegate! { fn saturating_abs(self) -> Self }
                 trait_delegate! { fn wrapping_abs(self) -> Self }
                 trait_delegate! { fn overflowing_abs(self) -> (Self, bool) }
+                trait_delegate! { fn unsigned_abs(self) -> Self::Unsigned }
                 trait_delegate! { fn is_positive(self) -> bool }
                 trait_delegate! { fn is_negative(self) -> bool }
+                #[inline]
+                fn reinterpret_sign(self) -> Self::Unsigned {
+                    SameLayout::reinterpret(self)
+                }
             }
         }
 
@@ -2042,18 +2488,22 @@ macro_rules! impl_fixed {
                 trait_delegate! { fn is_power_of_two(self) -> bool }
                 trait_delegate! { fn next_power_of_two(self) -> Self }
                 trait_delegate! { fn checked_next_power_of_two(self) -> Option<Self> }
+                #[inline]
+                fn reinterpret_sign(self) -> Self::Signed {
+                    SameLayout::reinterpret(self)
+                }
             }
         }
     };
 }
 
-impl_fixed! { FixedI8, LeEqU8, i8, Signed }
-impl_fixed! { FixedI16, LeEqU16, i16, Signed }
-impl_fixed! { FixedI32, LeEqU32, i32, Signed }
-impl_fixed! { FixedI64, LeEqU64, i64, Signed }
-impl_fixed! { FixedI128, LeEqU128, i128, Signed }
-impl_fixed! { FixedU8, LeEqU8, u8, Unsigned }
-impl_fixed! { FixedU16, LeEqU16, u16, Unsigned }
-impl_fixed! { FixedU32, LeEqU32, u32, Unsigned }
-impl_fixed! { FixedU64, LeEqU64, u64, Unsigned }
-impl_fixed! { FixedU128, LeEqU128, u128, Unsigned }
+impl_fixed! { FixedI8[FixedI8, FixedU8], LeEqU8, i8, Signed }
+impl_fixed! { FixedI16[FixedI16, FixedU16], LeEqU16, i16, Signed }
+impl_fixed! { FixedI32[FixedI32, FixedU32], LeEqU32, i32, Signed }
+impl_fixed! { FixedI64[FixedI64, FixedU64], LeEqU64, i64, Signed }
+impl_fixed! { FixedI128[FixedI128, FixedU128], LeEqU128, i128, Signed }
+impl_fixed! { FixedU8[FixedI8, FixedU8], LeEqU8, u8, Unsigned }
+impl_fixed! { FixedU16[FixedI16, FixedU16], LeEqU16, u16, Unsigned }
+impl_fixed! { FixedU32[FixedI32, FixedU32], LeEqU32, u32, Unsigned }
+impl_fixed! { FixedU64[FixedI64, FixedU64], LeEqU64, u64, Unsigned }
+impl_fixed! { FixedU128[FixedI128, FixedU128], LeEqU128, u128, Unsigned }
@@ -16,6 +16,7 @@
 This module contains transcendental functions.
 */
 use crate::consts;
+use crate::helpers;
 use crate::traits::{Fixed, FixedSigned, LossyFrom, ToFixed};
 use crate::types::{I9F23, I9F55, U0F128};
 use core::ops::{AddAssign, BitOrAssign, ShlAssign};
@@ -226,6 +227,54 @@ where
     Ok(log2::<S, D>(operand)? / D::from(LOG2_E))
 }
 
+/// logarithm in an arbitrary base, or `None` if the operand or the base
+/// is not positive, if the base is one, or on overflow
+///
+/// The result is computed as log2(operand) / log2(base), which is the
+/// same ratio as ln(operand) / ln(base) without the two conversions from
+/// base 2. Each of the two logarithms has an absolute error of a few
+/// units in the last place, and the division scales both errors by
+/// 1 / |log2(base)|, with the error of the base further multiplied by
+/// |result|. So the result is least accurate for bases close to one and
+/// for large results. `None` is also returned if the type cannot
+/// represent one.
+pub fn log<T>(operand: T, base: T) -> Option<T>
+where
+    T: FixedSigned + PartialOrd<ConstType>,
+    T::Bits: Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign,
+{
+    let log2_operand = checked_log2(operand)?;
+    let log2_base = checked_log2(base)?;
+    // fails for a base of one
+    log2_operand.checked_div(log2_base)
+}
+
+/// base 2 logarithm, or `None` if the operand is not positive or if the
+/// result does not fit
+///
+/// Unlike [`log2`], operands smaller than one are shifted into [1, 2)
+/// instead of being inverted, so that tiny operands cannot overflow.
+///
+/// [`log2`]: fn.log2.html
+fn checked_log2<T>(operand: T) -> Option<T>
+where
+    T: FixedSigned + PartialOrd<ConstType>,
+    T::Bits: Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign,
+{
+    let one = T::checked_from_num(1)?;
+    if operand <= T::from_num(0) {
+        return None;
+    };
+    if operand >= one {
+        return Some(log2_inner::<T, T>(operand));
+    };
+    // operand < 1, so the shift brings its most significant bit to the
+    // position of the one bit and the shifted value is in [1, 2)
+    let shift = operand.leading_zeros() - one.leading_zeros();
+    let shifted = operand.checked_shl(shift)?;
+    log2_inner::<T, T>(shifted).checked_sub(T::checked_from_num(shift)?)
+}
+
 /// exponential function e^(operand)
 pub fn exp<S, D>(mut operand: S) -> Result<D, ()>
 where
@@ -316,6 +365,72 @@ where
     Ok(result)
 }
 
+/// power with a fixed-point exponent, or `None` if the operand is
+/// negative, if zero is raised to a negative power, or on overflow
+///
+/// Unlike [`pow`], the operand, exponent and result all have the same
+/// type. The result is computed as e^(exponent × ln(operand)), so the
+/// absolute error of the logarithm is multiplied by |exponent|, and the
+/// relative error of the result grows with |exponent × ln(operand)|.
+/// For integer exponents, [`powi`] uses repeated multiplication instead
+/// and avoids this error.
+///
+/// [`pow`]: fn.pow.html
+/// [`powi`]: fn.powi.html
+pub fn checked_powf<T>(operand: T, exponent: T) -> Option<T>
+where
+    T: FixedSigned + PartialOrd<ConstType> + From<ConstType>,
+    T::Bits: Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign,
+{
+    let zero = T::from_num(0);
+    if operand < zero {
+        return None;
+    };
+    if exponent == zero {
+        return Some(T::from_num(1));
+    };
+    if operand == zero {
+        return if exponent > zero { Some(zero) } else { None };
+    };
+    let product = ln::<T, T>(operand).ok()?.checked_mul(exponent)?;
+    exp::<T, T>(product).ok()
+}
+
+/// power with a fixed-point exponent, saturating on overflow
+///
+/// This is like [`checked_powf`], but a result too large for the type
+/// saturates to the maximum value. A result so small that it cannot be
+/// computed, because the reciprocal overflows, saturates to zero.
+///
+/// # Panics
+///
+/// Panics if the operand is negative, or if zero is raised to a negative
+/// power.
+///
+/// [`checked_powf`]: fn.checked_powf.html
+pub fn saturating_powf<T>(operand: T, exponent: T) -> T
+where
+    T: FixedSigned + PartialOrd<ConstType> + From<ConstType>,
+    T::Bits: Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign,
+{
+    let zero = T::from_num(0);
+    assert!(operand >= zero, "negative operand");
+    assert!(
+        operand != zero || exponent >= zero,
+        "zero raised to a negative power"
+    );
+    if let Some(result) = checked_powf(operand, exponent) {
+        return result;
+    };
+    // ln(operand) is finite, so the product decides the direction
+    let ln = ln::<T, T>(operand).unwrap_or(zero);
+    if (ln < zero) == (exponent < zero) {
+        T::max_value()
+    } else {
+        zero
+    }
+}
+
 /// power with integer exponend
 pub fn powi<S,D>(operand: S, exponent: i32) -> Result<D, ()>
 where
@@ -352,6 +467,129 @@ where
     Ok(r)
 }
 
+/// geometric mean of positive values, computed in the log domain
+///
+/// Returns `None` if `values` is empty, if any value is not positive, or
+/// on overflow.
+pub fn geometric_mean<S, D>(values: &[S]) -> Option<D>
+where
+    S: FixedSigned + PartialOrd<ConstType>,
+    D: FixedSigned + PartialOrd<ConstType> + From<S> + From<ConstType>,
+    D::Bits: Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign,
+{
+    if values.is_empty() {
+        return None;
+    }
+    let mut sum = D::from_num(0);
+    for &value in values {
+        // fails for non-positive values
+        sum = sum.checked_add(ln::<S, D>(value).ok()?)?;
+    }
+    let mean = sum.checked_div(D::checked_from_num(values.len())?)?;
+    exp::<D, D>(mean).ok()
+}
+
+/// dot product of two slices
+///
+/// Returns `None` if the slices have different lengths, or if the result
+/// does not fit.
+///
+/// The products are summed exactly in a wide intermediate, and the sum is
+/// rounded to the nearest, with ties rounded to even, only once at the
+/// end. So unlike folding with multiplication and addition, intermediate
+/// products are not rounded, and partial sums can exceed the range of the
+/// type as long as the final result fits.
+pub fn dot<T>(a: &[T], b: &[T]) -> Option<T>
+where
+    T: Fixed,
+{
+    if a.len() != b.len() {
+        return None;
+    }
+    let products = a.iter().zip(b.iter()).map(|(&x, &y)| {
+        let (x, y) = (x.private_to_float_helper(), y.private_to_float_helper());
+        (x.neg != y.neg, x.abs, y.abs)
+    });
+    let (neg, abs) = helpers::sum_products_shr_round(products, T::frac_nbits())?;
+    T::private_checked_from_neg_abs(neg, abs)
+}
+
+/// two-dimensional vector scaled to unit length
+///
+/// Returns `None` for a zero-length vector, or if a component of the
+/// result cannot be represented, for example 1 for a type with no
+/// integer bits.
+///
+/// The length is computed in a wide intermediate, so components of any
+/// magnitude do not overflow while squaring. Each component is rounded
+/// to the nearest, though as the length itself is rounded, the result
+/// can be one unit in the last place off the correctly rounded value.
+pub fn normalize2<T>(v: [T; 2]) -> Option<[T; 2]>
+where
+    T: Fixed,
+{
+    let mut v = v;
+    normalize_slice(&mut v)?;
+    Some(v)
+}
+
+/// three-dimensional vector scaled to unit length
+///
+/// This is like [`normalize2`] but for three components.
+///
+/// [`normalize2`]: fn.normalize2.html
+pub fn normalize3<T>(v: [T; 3]) -> Option<[T; 3]>
+where
+    T: Fixed,
+{
+    let mut v = v;
+    normalize_slice(&mut v)?;
+    Some(v)
+}
+
+/// Normalizes up to three components in place, leaving them unchanged
+/// on failure.
+fn normalize_slice<T>(v: &mut [T]) -> Option<()>
+where
+    T: Fixed,
+{
+    debug_assert!(v.len() <= 3);
+    let mut parts = [(false, 0u128); 3];
+    for (part, &x) in parts.iter_mut().zip(v.iter()) {
+        let helper = x.private_to_float_helper();
+        *part = (helper.neg, helper.abs);
+    }
+    let max = parts.iter().map(|&(_, abs)| abs).max().unwrap_or(0);
+    if max == 0 {
+        return None;
+    }
+    // Scale so that the largest magnitude has its most significant bit
+    // at bit 125. Then every square is below 2^252, and the sum of three
+    // squares is below 2^254, so the length fits in 127 bits. The scale
+    // cancels out when dividing by the length.
+    let msb = 127 - max.leading_zeros();
+    let (mut hi, mut lo) = (0u128, 0u128);
+    for part in parts.iter_mut() {
+        part.1 = if msb <= 125 {
+            part.1 << (125 - msb)
+        } else {
+            part.1 >> (msb - 125)
+        };
+        let (sq_hi, sq_lo) = helpers::mul_hi_lo(part.1, part.1);
+        let (sum_lo, carry) = lo.overflowing_add(sq_lo);
+        hi += sq_hi + u128::from(carry);
+        lo = sum_lo;
+    }
+    let len = helpers::sqrt_hi_lo_round(hi, lo);
+    let mut result = [T::from_num(0); 3];
+    for (dst, &(neg, abs)) in result.iter_mut().zip(parts.iter()).take(v.len()) {
+        let abs = helpers::shl_div_round_abs(abs, T::frac_nbits(), len)?;
+        *dst = T::private_checked_from_neg_abs(neg, abs)?;
+    }
+    v.copy_from_slice(&result[..v.len()]);
+    Some(())
+}
+
 /// CORDIC in rotation mode.
 fn cordic_rotation<T>(mut x: T, mut y: T, mut z: T) -> (T, T)
 where
@@ -425,17 +663,78 @@ where
     sin(angle + T::lossy_from(FRAC_PI_2))
 }
 
+/// sine and cosine for tangent and cotangent, scaled by the same CORDIC gain
+///
+/// The angle is reduced to [-π/2, π/2], so the cosine is never negative.
+fn tan_parts<T>(mut angle: T) -> (T, T)
+where
+    T: FixedSigned + PartialOrd<ConstType> + LossyFrom<ConstType> + LossyFrom<U0F128>,
+{
+    //wraparound, the period of tan is π
+    while angle > FRAC_PI_2 {
+        angle -= T::lossy_from(PI);
+    }
+    while angle < -FRAC_PI_2 {
+        angle += T::lossy_from(PI);
+    }
+    // as in sin, start with 1/K so that the outputs do not exceed one
+    let x = T::lossy_from(U0F128::from_bits(0x9B74EDA8A01E20000000000000000000));
+    let (x, y) = cordic_rotation(x, T::from_num(0), angle);
+    (y, x)
+}
+
 /// tangent function in radians
-pub fn tan<T>(mut angle: T) -> T
+///
+/// The sine and cosine are computed by the same CORDIC rotation, so its
+/// gain cancels out. Near the poles at ±π/2 the cosine approaches zero, so
+/// the absolute error grows quickly, and the result overflows close to the
+/// poles, in which case this panics. Use [`checked_tan`] to handle the poles.
+///
+/// [`checked_tan`]: fn.checked_tan.html
+pub fn tan<T>(angle: T) -> T
 where
-    T: FixedSigned
-        + PartialOrd<ConstType>
-        + LossyFrom<ConstType>
-        + LossyFrom<I9F55>
-        + LossyFrom<U0F128>,
+    T: FixedSigned + PartialOrd<ConstType> + LossyFrom<ConstType> + LossyFrom<U0F128>,
 {
-    angle *= T::from_num(2);
-    sin(angle) / (T::from_num(1) + cos(angle))
+    checked_tan(angle).expect("overflow")
+}
+
+/// tangent function in radians, or `None` close to the poles at ±π/2
+/// where the result overflows
+pub fn checked_tan<T>(angle: T) -> Option<T>
+where
+    T: FixedSigned + PartialOrd<ConstType> + LossyFrom<ConstType> + LossyFrom<U0F128>,
+{
+    let (sin, cos) = tan_parts(angle);
+    if cos <= ZERO {
+        return None;
+    }
+    sin.checked_div(cos)
+}
+
+/// cotangent function in radians
+///
+/// The sine and cosine are computed by the same CORDIC rotation, so its
+/// gain cancels out. Near the poles at 0 and ±π the sine approaches zero,
+/// so the absolute error grows quickly, and the result overflows close to
+/// the poles, in which case this panics. Use [`checked_cot`] to handle the
+/// poles.
+///
+/// [`checked_cot`]: fn.checked_cot.html
+pub fn cot<T>(angle: T) -> T
+where
+    T: FixedSigned + PartialOrd<ConstType> + LossyFrom<ConstType> + LossyFrom<U0F128>,
+{
+    checked_cot(angle).expect("overflow")
+}
+
+/// cotangent function in radians, or `None` close to the poles at 0 and ±π
+/// where the result overflows
+pub fn checked_cot<T>(angle: T) -> Option<T>
+where
+    T: FixedSigned + PartialOrd<ConstType> + LossyFrom<ConstType> + LossyFrom<U0F128>,
+{
+    let (sin, cos) = tan_parts(angle);
+    cos.checked_div(sin)
 }
 
 /// arcsine function in radians
@@ -549,6 +848,45 @@ mod tests {
         assert_relative_eq!(result, -11.5129, epsilon = 1.0e-1);
     }
 
+    #[test]
+    fn log_works() {
+        type T = I32F32;
+        assert_eq!(log(T::from_num(0), T::from_num(2)), None);
+        assert_eq!(log(T::from_num(-8), T::from_num(2)), None);
+        assert_eq!(log(T::from_num(8), T::from_num(0)), None);
+        assert_eq!(log(T::from_num(8), T::from_num(-2)), None);
+        assert_eq!(log(T::from_num(8), T::from_num(1)), None);
+        assert_eq!(log(T::from_num(8), T::from_num(2)), Some(T::from_num(3)));
+        assert_eq!(log(T::from_num(1), T::from_num(7)), Some(T::from_num(0)));
+
+        let result: f64 = log(T::from_num(1000), T::from_num(10))
+            .unwrap()
+            .lossy_into();
+        assert_relative_eq!(result, 3.0, epsilon = 1.0e-6);
+        let result: f64 = log(T::from_num(0.001), T::from_num(10))
+            .unwrap()
+            .lossy_into();
+        assert_relative_eq!(result, -3.0, epsilon = 1.0e-6);
+        let result: f64 = log(T::from_num(81), T::from_num(1.0 / 3.0))
+            .unwrap()
+            .lossy_into();
+        assert_relative_eq!(result, -4.0, epsilon = 1.0e-6);
+        // the smallest positive value does not overflow when shifted
+        let result: f64 = log(T::from_bits(1), T::from_num(2)).unwrap().lossy_into();
+        assert_relative_eq!(result, -32.0, epsilon = 1.0e-9);
+
+        // log2(2^-23) = -23 does not fit in four integer bits
+        type Small = crate::types::I4F28;
+        assert_eq!(log(Small::from_bits(1 << 5), Small::from_num(2)), None);
+        assert_eq!(
+            log(Small::from_num(0.25), Small::from_num(2)),
+            Some(Small::from_num(-2))
+        );
+        // one cannot be represented
+        type Tiny = crate::types::I1F31;
+        assert_eq!(log(Tiny::from_num(0.5), Tiny::from_num(0.25)), None);
+    }
+
     #[test]
     fn exp_works() {
         type S = I9F23;
@@ -624,6 +962,231 @@ mod tests {
         assert_relative_eq!(result, 8.0, epsilon = 1.0e-3);
     }
 
+    #[test]
+    fn powf_works() {
+        type T = I32F32;
+
+        let result: f64 = checked_powf(T::from_num(2), T::from_num(0.5))
+            .unwrap()
+            .lossy_into();
+        assert_relative_eq!(result, 2f64.sqrt(), epsilon = 1.0e-3);
+        let result: f64 = checked_powf(T::from_num(2.9), T::from_num(3.1))
+            .unwrap()
+            .lossy_into();
+        assert_relative_eq!(result, 27.129, epsilon = 1.0e-2);
+        let result: f64 = checked_powf(T::from_num(4), T::from_num(-1.5))
+            .unwrap()
+            .lossy_into();
+        assert_relative_eq!(result, 0.125, epsilon = 1.0e-4);
+        let result: f64 = checked_powf(T::from_num(0.25), T::from_num(0.5))
+            .unwrap()
+            .lossy_into();
+        assert_relative_eq!(result, 0.5, epsilon = 1.0e-4);
+
+        assert_eq!(
+            checked_powf(T::from_num(0), T::from_num(2.5)),
+            Some(T::from_num(0))
+        );
+        assert_eq!(
+            checked_powf(T::from_num(0), T::from_num(0)),
+            Some(T::from_num(1))
+        );
+        assert_eq!(
+            checked_powf(T::from_num(3), T::from_num(0)),
+            Some(T::from_num(1))
+        );
+        assert_eq!(checked_powf(T::from_num(0), T::from_num(-1)), None);
+        assert_eq!(checked_powf(T::from_num(-2), T::from_num(2)), None);
+        // 10^12 does not fit
+        assert_eq!(checked_powf(T::from_num(10), T::from_num(12)), None);
+
+        let result = saturating_powf(T::from_num(10), T::from_num(12));
+        assert_eq!(result, T::max_value());
+        let result = saturating_powf(T::from_num(0.1), T::from_num(-12));
+        assert_eq!(result, T::max_value());
+        let result = saturating_powf(T::from_num(10), T::from_num(-12));
+        assert_eq!(result, T::from_num(0));
+        let result: f64 = saturating_powf(T::from_num(9), T::from_num(0.5)).lossy_into();
+        assert_relative_eq!(result, 3.0, epsilon = 1.0e-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "negative operand")]
+    fn saturating_powf_negative() {
+        type T = I32F32;
+        saturating_powf(T::from_num(-1), T::from_num(2));
+    }
+
+    #[test]
+    fn geometric_mean_works() {
+        type S = I9F23;
+        type D = I32F32;
+
+        let result: D = geometric_mean(&[TWO, S::from_num(8)]).unwrap();
+        let result: f64 = result.lossy_into();
+        assert_relative_eq!(result, 4.0, epsilon = 1.0e-3);
+
+        // growth factors of 10%, 20% and -5% average to about 7.9%
+        let factors = [S::from_num(1.1), S::from_num(1.2), S::from_num(0.95)];
+        let result: D = geometric_mean(&factors).unwrap();
+        let result: f64 = result.lossy_into();
+        assert_relative_eq!(result, 1.0792, epsilon = 1.0e-3);
+
+        let result: D = geometric_mean(&[S::from_num(0.001), S::from_num(100)]).unwrap();
+        let result: f64 = result.lossy_into();
+        assert_relative_eq!(result, 0.316228, epsilon = 1.0e-3);
+
+        let result: D = geometric_mean(&[S::from_num(3); 50]).unwrap();
+        let result: f64 = result.lossy_into();
+        assert_relative_eq!(result, 3.0, epsilon = 1.0e-3);
+
+        assert!(geometric_mean::<S, D>(&[]).is_none());
+        assert!(geometric_mean::<S, D>(&[TWO, ZERO]).is_none());
+        assert!(geometric_mean::<S, D>(&[TWO, S::from_num(-2)]).is_none());
+    }
+
+    #[test]
+    fn dot_works() {
+        use crate::types::{I0F128, I128F0, I8F8, U0F8, U128F0, U8F8};
+
+        let a = [
+            I32F32::from_num(1.5),
+            I32F32::from_num(-2),
+            I32F32::from_num(0.25),
+        ];
+        let b = [
+            I32F32::from_num(4),
+            I32F32::from_num(3),
+            I32F32::from_num(-8),
+        ];
+        assert_eq!(dot(&a, &b), Some(I32F32::from_num(-2)));
+        assert_eq!(dot::<I32F32>(&[], &[]), Some(I32F32::from_num(0)));
+        assert_eq!(dot(&a, &b[..2]), None);
+
+        // compare with an exact sum for small types
+        for i in -40i32..40 {
+            for j in -40i32..40 {
+                let a = [
+                    I8F8::from_bits((i * 97) as i16),
+                    I8F8::from_bits((j * 311) as i16),
+                ];
+                let b = [
+                    I8F8::from_bits((j * 89) as i16),
+                    I8F8::from_bits((i * 7) as i16),
+                ];
+                let exact: i64 = a
+                    .iter()
+                    .zip(b.iter())
+                    .map(|(x, y)| i64::from(x.to_bits()) * i64::from(y.to_bits()))
+                    .sum();
+                // round exact / 256 to nearest, ties to even
+                let (quot, rem) = (exact.div_euclid(256), exact.rem_euclid(256));
+                let rounded = if rem > 128 || (rem == 128 && quot % 2 != 0) {
+                    quot + 1
+                } else {
+                    quot
+                };
+                let expected = if rounded >= i64::from(i16::MIN) && rounded <= i64::from(i16::MAX) {
+                    Some(I8F8::from_bits(rounded as i16))
+                } else {
+                    None
+                };
+                assert_eq!(dot(&a, &b), expected, "{:?} {:?}", a, b);
+            }
+        }
+
+        // rounded only once: each product is below half the least
+        // significant bit, but the sum is not
+        let tiny = [U0F8::from_bits(1); 200];
+        assert_eq!(dot(&tiny, &tiny), Some(U0F8::from_bits(1)));
+        let tiny = [U8F8::from_bits(1); 300];
+        assert_eq!(dot(&tiny, &tiny), Some(U8F8::from_bits(1)));
+
+        // partial sums overflow, but the result fits
+        let a = [
+            I128F0::max_value(),
+            I128F0::max_value(),
+            I128F0::from_num(-1),
+        ];
+        let b = [
+            I128F0::max_value(),
+            -I128F0::max_value(),
+            I128F0::from_num(1),
+        ];
+        assert_eq!(dot(&a, &b), Some(I128F0::from_num(-1)));
+        let a = [U128F0::max_value(); 3];
+        let (zero, one) = (U128F0::from_num(0), U128F0::from_num(1));
+        assert_eq!(dot(&a, &[one, zero, zero]), Some(U128F0::max_value()));
+        assert_eq!(dot(&a, &[one; 3]), None);
+        let (min, max) = (I0F128::min_value(), I0F128::max_value());
+        assert_eq!(dot(&[min, min], &[min, min]), None);
+        // 0.25 + 0.25 − 2 × 0.5 × (0.5 − 2^−128) = 2^−128
+        assert_eq!(
+            dot(&[min; 4], &[min, min, max, max]),
+            Some(I0F128::from_bits(1))
+        );
+    }
+
+    #[test]
+    fn normalize_works() {
+        use crate::types::{I1F31, I8F8, U1F127, U8F8};
+
+        let [x, y] = normalize2([I32F32::from_num(3), I32F32::from_num(4)]).unwrap();
+        assert_eq!((x, y), (I32F32::from_num(0.6), I32F32::from_num(0.8)));
+        let [x, y] = normalize2([I32F32::from_num(-30), I32F32::from_num(40)]).unwrap();
+        assert_eq!((x, y), (I32F32::from_num(-0.6), I32F32::from_num(0.8)));
+        let v = normalize3([
+            I32F32::from_num(2),
+            I32F32::from_num(-1),
+            I32F32::from_num(2),
+        ]);
+        let (third, two_thirds) = (I32F32::from_num(1.0 / 3.0), I32F32::from_num(2.0 / 3.0));
+        assert_eq!(v, Some([two_thirds, -third, two_thirds]));
+
+        // each component is within one unit in the last place
+        let ulp = 1.0 / 256.0;
+        for x in (-128i32..128).step_by(3) {
+            for y in (-32768i32..32768).step_by(97) {
+                let v = [I8F8::from_num(x), I8F8::from_bits(y as i16)];
+                let [xf, yf]: [f64; 2] = [v[0].lossy_into(), v[1].lossy_into()];
+                let len = xf.hypot(yf);
+                match normalize2(v) {
+                    None => assert!(len == 0.0 || xf.abs() == len || yf.abs() == len),
+                    Some([nx, ny]) => {
+                        let (nx, ny): (f64, f64) = (nx.lossy_into(), ny.lossy_into());
+                        assert!((nx - xf / len).abs() <= ulp, "{:?}", v);
+                        assert!((ny - yf / len).abs() <= ulp, "{:?}", v);
+                    }
+                }
+            }
+        }
+
+        // no overflow for large components
+        let max = I64F64::max_value();
+        let [x, y] = normalize2([max, -max]).unwrap();
+        let x: f64 = x.lossy_into();
+        let y: f64 = y.lossy_into();
+        assert_relative_eq!(x, 0.5f64.sqrt(), epsilon = 1.0e-12);
+        assert_relative_eq!(y, -(0.5f64.sqrt()), epsilon = 1.0e-12);
+        let v = normalize3([
+            U64F64::max_value(),
+            U64F64::max_value(),
+            U64F64::from_num(0),
+        ]);
+        let x: f64 = v.unwrap()[0].lossy_into();
+        assert_relative_eq!(x, 0.5f64.sqrt(), epsilon = 1.0e-12);
+
+        // one can only be represented with an integer bit
+        let one = U1F127::from_num(1);
+        let zero = U1F127::from_num(0);
+        assert_eq!(normalize2([U1F127::from_bits(1), zero]), Some([one, zero]));
+        assert_eq!(normalize2([I1F31::from_num(0.5), I1F31::from_num(0)]), None);
+        assert!(normalize2([I1F31::from_num(0.5), I1F31::from_num(0.5)]).is_some());
+
+        assert_eq!(normalize2([I8F8::from_num(0); 2]), None);
+        assert_eq!(normalize3([U8F8::from_num(0); 3]), None);
+    }
+
     #[test]
     fn sin_works() {
         // for correction factor reference
@@ -670,6 +1233,48 @@ mod tests {
 
         let result: f64 = tan(ONE).lossy_into();
         assert_relative_eq!(result, 1.55741, epsilon = 1.0e-5);
+
+        let result: f64 = checked_tan(-ONE).unwrap().lossy_into();
+        assert_relative_eq!(result, -1.55741, epsilon = 1.0e-5);
+        let result: f64 = tan(I9F23::from_num(0.5)).lossy_into();
+        assert_relative_eq!(result, 0.546302, epsilon = 1.0e-5);
+        let result: f64 = tan(I9F23::from_num(-1.5)).lossy_into();
+        assert_relative_eq!(result, -14.1014, epsilon = 1.0e-3);
+        // period is π
+        let result: f64 = tan(ONE + PI).lossy_into();
+        assert_relative_eq!(result, 1.55741, epsilon = 1.0e-5);
+        let result: f64 = tan(ONE - TWO_PI).lossy_into();
+        assert_relative_eq!(result, 1.55741, epsilon = 1.0e-5);
+
+        // poles
+        assert_eq!(checked_tan(FRAC_PI_2), None);
+        assert_eq!(checked_tan(-FRAC_PI_2), None);
+        // tan(π/2 − 0.001) ≈ 1000 does not fit in I9F23
+        assert_eq!(checked_tan(FRAC_PI_2 - I9F23::from_num(0.001)), None);
+        let result: f64 = tan(I32F32::lossy_from(FRAC_PI_2) - I32F32::from_num(0.001)).lossy_into();
+        assert_relative_eq!(result, 1000.0, max_relative = 1.0e-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn tan_pole_panics() {
+        tan(FRAC_PI_2);
+    }
+
+    #[test]
+    fn cot_works() {
+        let result: f64 = cot(ONE).lossy_into();
+        assert_relative_eq!(result, 0.642093, epsilon = 1.0e-5);
+        let result: f64 = cot(-ONE).lossy_into();
+        assert_relative_eq!(result, -0.642093, epsilon = 1.0e-5);
+        let result: f64 = cot(FRAC_PI_2).lossy_into();
+        assert_relative_eq!(result, 0.0, epsilon = 1.0e-5);
+
+        // poles
+        assert_eq!(checked_cot(I9F23::from_num(0)), None);
+        assert_eq!(checked_cot(I9F23::from_num(0.001)), None);
+        let result: f64 = cot(I32F32::from_num(0.001)).lossy_into();
+        assert_relative_eq!(result, 1000.0, max_relative = 1.0e-3);
     }
 
     #[test]
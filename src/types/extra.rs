@@ -33,6 +33,9 @@ pub use typenum::{
     U90, U91, U92, U93, U94, U95, U96, U97, U98, U99,
 };
 
+#[cfg(feature = "fixed")]
+pub use crate::upstream::UpstreamFrac;
+
 /// Implemented for all [`Unsigned`](trait.Unsigned.html) integers ≤ 8.
 pub trait LeEqU8: Unsigned + IsLessOrEqual<U8, Output = True> {}
 impl<T: Unsigned + IsLessOrEqual<U8, Output = True>> LeEqU8 for T {}
@@ -0,0 +1,118 @@
+// Copyright © 2018–2019 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    types::extra::*, FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16,
+    FixedU32, FixedU64, FixedU8,
+};
+
+/// Maps a number of fractional bits to the same number as used by the
+/// upstream [*fixed* crate].
+///
+/// The upstream crate uses the [*typenum* crate] while this crate uses
+/// a fork of it, so the two crates name the same number of fractional
+/// bits with different types. This trait is implemented for [`U0`] to
+/// [`U128`].
+///
+/// This trait is only available when the `fixed` feature is enabled.
+///
+/// [*fixed* crate]: https://crates.io/crates/fixed
+/// [*typenum* crate]: https://crates.io/crates/typenum
+/// [`U0`]: type.U0.html
+/// [`U128`]: type.U128.html
+pub trait UpstreamFrac {
+    /// The upstream type for the same number of fractional bits.
+    type Upstream;
+}
+
+macro_rules! upstream_frac {
+    ($($U:ident)*) => { $(
+        impl UpstreamFrac for $U {
+            type Upstream = fixed::types::extra::$U;
+        }
+    )* };
+}
+
+upstream_frac! {
+    U0 U1 U2 U3 U4 U5 U6 U7 U8 U9 U10 U11 U12 U13 U14 U15 U16 U17 U18 U19 U20 U21 U22 U23 U24 U25
+    U26 U27 U28 U29 U30 U31 U32 U33 U34 U35 U36 U37 U38 U39 U40 U41 U42 U43 U44 U45 U46 U47 U48 U49
+    U50 U51 U52 U53 U54 U55 U56 U57 U58 U59 U60 U61 U62 U63 U64 U65 U66 U67 U68 U69 U70 U71 U72 U73
+    U74 U75 U76 U77 U78 U79 U80 U81 U82 U83 U84 U85 U86 U87 U88 U89 U90 U91 U92 U93 U94 U95 U96 U97
+    U98 U99 U100 U101 U102 U103 U104 U105 U106 U107 U108 U109 U110 U111 U112 U113 U114 U115 U116
+    U117 U118 U119 U120 U121 U122 U123 U124 U125 U126 U127 U128
+}
+
+macro_rules! convert {
+    ($($Fixed:ident)*) => { $(
+        impl<Frac: UpstreamFrac> From<$Fixed<Frac>> for fixed::$Fixed<Frac::Upstream> {
+            /// Converts to the upstream type with the same layout, bit for bit.
+            #[inline]
+            fn from(src: $Fixed<Frac>) -> fixed::$Fixed<Frac::Upstream> {
+                fixed::$Fixed::from_bits(src.to_bits())
+            }
+        }
+
+        impl<Frac: UpstreamFrac> From<fixed::$Fixed<Frac::Upstream>> for $Fixed<Frac> {
+            /// Converts from the upstream type with the same layout, bit for bit.
+            #[inline]
+            fn from(src: fixed::$Fixed<Frac::Upstream>) -> $Fixed<Frac> {
+                $Fixed::from_bits(src.to_bits())
+            }
+        }
+    )* };
+}
+
+convert! { FixedI8 FixedI16 FixedI32 FixedI64 FixedI128 }
+convert! { FixedU8 FixedU16 FixedU32 FixedU64 FixedU128 }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::*;
+    use std::string::ToString;
+
+    #[test]
+    fn round_trip() {
+        for bits in i8::MIN..=i8::MAX {
+            let x = I4F4::from_bits(bits);
+            let up = fixed::types::I4F4::from(x);
+            assert_eq!(up.to_bits(), bits);
+            assert_eq!(I4F4::from(up), x);
+            let u = U0F8::from_bits(bits as u8);
+            let up = fixed::types::U0F8::from(u);
+            assert_eq!(up.to_bits(), bits as u8);
+            assert_eq!(U0F8::from(up), u);
+        }
+        for &bits in &[i32::MIN, -1, 0, 1, 0x1234_5678, i32::MAX] {
+            let x = I16F16::from_bits(bits);
+            let up: fixed::types::I16F16 = x.into();
+            assert_eq!(up.to_bits(), bits);
+            assert_eq!(I16F16::from(up), x);
+            assert_eq!(up.to_string(), x.to_string());
+        }
+        let x = I64F64::from_bits(i128::MIN + 1);
+        assert_eq!(I64F64::from(fixed::types::I64F64::from(x)), x);
+        let x = U0F128::from_bits(u128::MAX);
+        assert_eq!(fixed::types::U0F128::from(x).to_bits(), u128::MAX);
+        let x = U128F0::from_bits(u128::MAX >> 1);
+        assert_eq!(U128F0::from(fixed::types::U128F0::from(x)), x);
+        let x = I32F32::from_num(-2.75);
+        assert_eq!(
+            fixed::types::I32F32::from(x),
+            fixed::types::I32F32::from_num(-2.75)
+        );
+        let x = U16F0::from_num(1234);
+        assert_eq!(fixed::types::U16F0::from(x), 1234);
+    }
+}